@@ -0,0 +1,73 @@
+// Measures full `update`+`digest` throughput over a mixed `FieldInput`
+// sequence (base field, scalar field, curve point, packed primitives) via
+// the ergonomic `Into<PallasInput>` API, rather than the base-field-only
+// sequences the other streaming benches (`simple_hash.rs`,
+// `rate_thresholds.rs`) use.
+
+use ark_ec::AffineRepr;
+use criterion::{criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion, Throughput};
+use poseidon_hash::PoseidonHasher;
+use poseidon_hash::*;
+
+// One absorb of each supported `FieldInput` variant, in rotation, so the
+// sequence exercises every field-conversion path rather than repeating one.
+fn absorb_mixed_element(h: &mut PallasHasher, i: u64) {
+    match i % 4 {
+        0 => h.update(ark_pallas::Fq::from(i)),
+        1 => h.update(ark_pallas::Fr::from(i)),
+        2 => h.update(ark_pallas::Affine::generator()),
+        _ => h.update(i),
+    }
+}
+
+fn bench_mixed_field_input_stream(c: &mut Criterion) {
+    let mut group = c.benchmark_group("mixed_field_input_stream");
+    for &n in &[128usize, 1024, 4096, 16384] {
+        group.throughput(Throughput::Elements(n as u64));
+        group.bench_with_input(
+            BenchmarkId::new("pallas_update_digest_mixed", n),
+            &n,
+            |b, &n| {
+                b.iter_batched(
+                    || PallasHasher::new_with_domain("MIXED"),
+                    |mut hasher| {
+                        for i in 0..n as u64 {
+                            absorb_mixed_element(&mut hasher, i);
+                        }
+                        let _ = hasher.digest();
+                    },
+                    BatchSize::SmallInput,
+                );
+            },
+        );
+    }
+    group.finish();
+}
+
+// Same mixed sequence, but absorbing a string primitive per element instead
+// of a fixed u64, to separately measure the packed-primitive encoding path.
+fn bench_mixed_field_input_string_primitive(c: &mut Criterion) {
+    let mut group = c.benchmark_group("mixed_field_input_string_primitive");
+    let n = 4096usize;
+    group.throughput(Throughput::Elements(n as u64));
+    group.bench_function("pallas_update_digest_strings", |b| {
+        b.iter_batched(
+            || PallasHasher::new_with_domain("MIXED_STR"),
+            |mut hasher| {
+                for i in 0..n {
+                    hasher.update(format!("element-{i}"));
+                }
+                let _ = hasher.digest();
+            },
+            BatchSize::SmallInput,
+        );
+    });
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    bench_mixed_field_input_stream,
+    bench_mixed_field_input_string_primitive
+);
+criterion_main!(benches);
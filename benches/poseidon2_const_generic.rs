@@ -0,0 +1,89 @@
+// Compares the `[F; T]`-backed `poseidon2_spec::Permutation` against the
+// `Vec<F>`-backed `Poseidon2Sponge::compress` (via the public
+// `PallasPoseidon2Compress*` wrappers) for the same Pallas T3/T4
+// geometries, to quantify the allocation/bounds-check savings of the
+// const-generic permutation core.
+
+use ark_ff::Zero;
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use poseidon_hash::poseidon2_spec::{PallasSpecT3, PallasSpecT4, Permutation};
+use poseidon_hash::{PallasPoseidon2Compress, PallasPoseidon2CompressT3};
+
+type F = ark_pallas::Fq;
+
+fn gen_inputs(n: usize) -> Vec<F> {
+    (0..n)
+        .map(|i| F::from((i as u64).wrapping_mul(0x9E3779B97F4A7C15)))
+        .collect()
+}
+
+fn bench_t3(c: &mut Criterion) {
+    let mut group = c.benchmark_group("poseidon2_permutation_t3");
+    let inputs = gen_inputs(1 << 14);
+    group.throughput(Throughput::Elements(inputs.len() as u64));
+
+    group.bench_function(BenchmarkId::new("array_backed", "t3"), |b| {
+        b.iter(|| {
+            let mut acc = F::zero();
+            for pair in inputs.chunks_exact(2) {
+                let mut perm =
+                    Permutation::<F, PallasSpecT3, 3, 2>::from_state([F::zero(), pair[0], pair[1]]);
+                perm.permute();
+                acc += perm.state()[0];
+            }
+            acc
+        })
+    });
+
+    group.bench_function(BenchmarkId::new("vec_backed", "t3"), |b| {
+        let compressor = PallasPoseidon2CompressT3::new();
+        b.iter(|| {
+            let mut acc = F::zero();
+            for pair in inputs.chunks_exact(2) {
+                acc += compressor.compress2(pair[0], pair[1]);
+            }
+            acc
+        })
+    });
+
+    group.finish();
+}
+
+fn bench_t4(c: &mut Criterion) {
+    let mut group = c.benchmark_group("poseidon2_permutation_t4");
+    let inputs = gen_inputs(1 << 14);
+    group.throughput(Throughput::Elements(inputs.len() as u64));
+
+    group.bench_function(BenchmarkId::new("array_backed", "t4"), |b| {
+        b.iter(|| {
+            let mut acc = F::zero();
+            for triple in inputs.chunks_exact(3) {
+                let mut perm = Permutation::<F, PallasSpecT4, 4, 3>::from_state([
+                    F::zero(),
+                    triple[0],
+                    triple[1],
+                    triple[2],
+                ]);
+                perm.permute();
+                acc += perm.state()[0];
+            }
+            acc
+        })
+    });
+
+    group.bench_function(BenchmarkId::new("vec_backed", "t4"), |b| {
+        let compressor = PallasPoseidon2Compress::new();
+        b.iter(|| {
+            let mut acc = F::zero();
+            for triple in inputs.chunks_exact(3) {
+                acc += compressor.compress3(triple[0], triple[1], triple[2]);
+            }
+            acc
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_t3, bench_t4);
+criterion_main!(benches);
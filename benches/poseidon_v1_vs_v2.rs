@@ -0,0 +1,117 @@
+// Head-to-head Poseidon v1 (streaming sponge, via `update`+`digest`) vs
+// Poseidon2 (single-permutation `compress2`/`compress3`) for the same
+// 2-to-1 and 3-to-1 arities, across Pallas and BN254, in one harness. The
+// existing `rate_thresholds.rs` sweeps v1 variants alone and
+// `poseidon2_const_generic.rs` sweeps v2 alone; neither puts the two
+// versions side by side for the same curve and arity.
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use poseidon_hash::{
+    BN254Hasher, BN254Poseidon2Compress, BN254Poseidon2CompressT3, PallasHasher,
+    PallasPoseidon2Compress, PallasPoseidon2CompressT3, PoseidonHasher,
+};
+
+fn bench_pallas_2to1(c: &mut Criterion) {
+    let mut group = c.benchmark_group("poseidon_v1_vs_v2_pallas_2to1");
+    group.throughput(Throughput::Elements(1));
+    let a = ark_pallas::Fq::from(1u64);
+    let b = ark_pallas::Fq::from(2u64);
+
+    group.bench_function(BenchmarkId::new("v1_stream", "pallas"), |bch| {
+        bch.iter(|| {
+            let mut h = PallasHasher::new_with_domain("V1V2");
+            h.update(a);
+            h.update(b);
+            h.finalize()
+        });
+    });
+
+    let compressor = PallasPoseidon2CompressT3::new();
+    group.bench_function(BenchmarkId::new("v2_compress2", "pallas"), |bch| {
+        bch.iter(|| compressor.compress2(a, b));
+    });
+
+    group.finish();
+}
+
+fn bench_pallas_3to1(c: &mut Criterion) {
+    let mut group = c.benchmark_group("poseidon_v1_vs_v2_pallas_3to1");
+    group.throughput(Throughput::Elements(1));
+    let a = ark_pallas::Fq::from(1u64);
+    let b = ark_pallas::Fq::from(2u64);
+    let d = ark_pallas::Fq::from(3u64);
+
+    group.bench_function(BenchmarkId::new("v1_stream", "pallas"), |bch| {
+        bch.iter(|| {
+            let mut h = PallasHasher::new_with_domain("V1V2");
+            h.update(a);
+            h.update(b);
+            h.update(d);
+            h.finalize()
+        });
+    });
+
+    let compressor = PallasPoseidon2Compress::new();
+    group.bench_function(BenchmarkId::new("v2_compress3", "pallas"), |bch| {
+        bch.iter(|| compressor.compress3(a, b, d));
+    });
+
+    group.finish();
+}
+
+fn bench_bn254_2to1(c: &mut Criterion) {
+    let mut group = c.benchmark_group("poseidon_v1_vs_v2_bn254_2to1");
+    group.throughput(Throughput::Elements(1));
+    let a = ark_bn254::Fq::from(1u64);
+    let b = ark_bn254::Fq::from(2u64);
+
+    group.bench_function(BenchmarkId::new("v1_stream", "bn254"), |bch| {
+        bch.iter(|| {
+            let mut h = BN254Hasher::new_with_domain("V1V2");
+            h.update(a);
+            h.update(b);
+            h.finalize()
+        });
+    });
+
+    let compressor = BN254Poseidon2CompressT3::new();
+    group.bench_function(BenchmarkId::new("v2_compress2", "bn254"), |bch| {
+        bch.iter(|| compressor.compress2(a, b));
+    });
+
+    group.finish();
+}
+
+fn bench_bn254_3to1(c: &mut Criterion) {
+    let mut group = c.benchmark_group("poseidon_v1_vs_v2_bn254_3to1");
+    group.throughput(Throughput::Elements(1));
+    let a = ark_bn254::Fq::from(1u64);
+    let b = ark_bn254::Fq::from(2u64);
+    let d = ark_bn254::Fq::from(3u64);
+
+    group.bench_function(BenchmarkId::new("v1_stream", "bn254"), |bch| {
+        bch.iter(|| {
+            let mut h = BN254Hasher::new_with_domain("V1V2");
+            h.update(a);
+            h.update(b);
+            h.update(d);
+            h.finalize()
+        });
+    });
+
+    let compressor = BN254Poseidon2Compress::new();
+    group.bench_function(BenchmarkId::new("v2_compress3", "bn254"), |bch| {
+        bch.iter(|| compressor.compress3(a, b, d));
+    });
+
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    bench_pallas_2to1,
+    bench_pallas_3to1,
+    bench_bn254_2to1,
+    bench_bn254_3to1
+);
+criterion_main!(benches);
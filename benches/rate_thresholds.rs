@@ -1,52 +1,52 @@
-use criterion::{BatchSize, BenchmarkId, Criterion, Throughput, criterion_group, criterion_main};
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use criterion::BatchSize;
+use poseidon_hash::parameters::pallas::PallasVariant;
 use poseidon_hash::PoseidonHasher;
 use poseidon_hash::*;
 
+/// All `PallasVariant` widths, paired with a label for the benchmark group
+/// name. Exercising every width (rather than only the default t=3) gives a
+/// baseline for picking the smallest-cost variant for a given number of
+/// inputs, e.g. rate=3 on `T4` for exactly 3 elements.
+const VARIANTS: &[(PallasVariant, &str)] = &[
+    (PallasVariant::T3, "t3_rate2"),
+    (PallasVariant::T4, "t4_rate3"),
+    (PallasVariant::T5, "t5_rate4"),
+    (PallasVariant::T9, "t9_rate8"),
+    (PallasVariant::T12, "t12_rate11"),
+];
+
 fn bench_rate_thresholds(c: &mut Criterion) {
-    // Current params: t=3, rate=2. We sweep m=1..12 inputs to provide a
-    // baseline for future larger-t comparisons while still using the crate API.
-    let mut group = c.benchmark_group("rate_thresholds_pallas");
-    let inputs: Vec<ark_pallas::Fq> = (1u64..=12).map(|i| ark_pallas::Fq::from(i)).collect();
+    // Sweep every `PallasVariant` width and m=1..12 inputs, reporting
+    // elements/sec throughput per variant so users can empirically pick the
+    // smallest-cost width for a given number of inputs.
+    let inputs: Vec<ark_pallas::Fq> = (1u64..=12).map(ark_pallas::Fq::from).collect();
 
-    for m in 1..=12usize {
-        group.throughput(Throughput::Elements(m as u64));
-        // Tagged path
-        group.bench_with_input(
-            BenchmarkId::new("tagged_absorb_m_digest", m),
-            &m,
-            |bch, &mm| {
-                bch.iter_batched(
-                    || PallasHasher::new_with_domain("RATE"),
-                    |mut h| {
-                        for i in 0..mm {
-                            h.update(inputs[i]);
-                        }
-                        let _ = h.digest();
-                    },
-                    BatchSize::SmallInput,
-                );
-            },
-        );
-        // DiR path
-        group.bench_with_input(
-            BenchmarkId::new("dir_absorb_m_digest", m),
-            &m,
-            |bch, &mm| {
-                bch.iter_batched(
-                    || PallasHasher::new_with_domain_dir("RATE"),
-                    |mut h| {
-                        for i in 0..mm {
-                            h.update(inputs[i]);
-                        }
-                        let _ = h.digest();
-                    },
-                    BatchSize::SmallInput,
-                );
-            },
-        );
-    }
+    for &(variant, label) in VARIANTS {
+        let mut group = c.benchmark_group(format!("rate_thresholds_pallas_{label}"));
 
-    group.finish();
+        for m in 1..=12usize {
+            group.throughput(Throughput::Elements(m as u64));
+            group.bench_with_input(
+                BenchmarkId::new("absorb_m_digest", m),
+                &m,
+                |bch, &mm| {
+                    bch.iter_batched(
+                        || PallasHasher::new_with_domain_variant("RATE", variant),
+                        |mut h| {
+                            for i in 0..mm {
+                                h.update(inputs[i]);
+                            }
+                            let _ = h.digest();
+                        },
+                        BatchSize::SmallInput,
+                    );
+                },
+            );
+        }
+
+        group.finish();
+    }
 }
 
 criterion_group!(benches, bench_rate_thresholds);
@@ -0,0 +1,133 @@
+// Throughput benchmark suite, separate from the dudect-style leakage
+// detection in `tests/side_channel_tests.rs`/`tests/sidechannel.rs` (which
+// measure whether timing *varies* across inputs, not how fast hashing is).
+// Sweeps the same input sizes `test_timing_consistency_input_sizes` uses
+// (1, 10, 100, 1000, 10000 bytes) across Pallas, BN254, and BLS12-381, and
+// compares the `digest_batch` entry point (see `src/batch_hash.rs`) against
+// hashing the same lanes one at a time — `digest_batch` is a fixed-arity
+// convenience API, not a performance optimization (a real vectorized kernel
+// is out of scope for this generic-over-`PrimeField` crate; see
+// `src/batch_hash.rs`'s module doc), and this group is what keeps that claim
+// honest; a release that regresses either path's bytes/sec or hashes/sec
+// also shows up here under `cargo bench`.
+
+use criterion::{criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion, Throughput};
+use poseidon_hash::{BLS12_381Hasher, BN254Hasher, PackingConfig, PallasHasher, PoseidonHasher};
+
+const INPUT_SIZES: [usize; 5] = [1, 10, 100, 1000, 10000];
+
+fn bench_cross_curve_throughput(c: &mut Criterion) {
+    let mut group = c.benchmark_group("throughput_cross_curve");
+    for &size in &INPUT_SIZES {
+        let data = vec![0x42u8; size];
+        group.throughput(Throughput::Bytes(size as u64));
+
+        group.bench_with_input(BenchmarkId::new("pallas", size), &data, |b, data| {
+            b.iter_batched(
+                PallasHasher::new,
+                |mut hasher| {
+                    hasher.update(data.as_slice());
+                    hasher.digest()
+                },
+                BatchSize::SmallInput,
+            );
+        });
+
+        group.bench_with_input(BenchmarkId::new("bn254", size), &data, |b, data| {
+            b.iter_batched(
+                BN254Hasher::new,
+                |mut hasher| {
+                    hasher.update(data.as_slice());
+                    hasher.digest()
+                },
+                BatchSize::SmallInput,
+            );
+        });
+
+        group.bench_with_input(BenchmarkId::new("bls12_381", size), &data, |b, data| {
+            b.iter_batched(
+                BLS12_381Hasher::new,
+                |mut hasher| {
+                    hasher.update(data.as_slice());
+                    hasher.digest()
+                },
+                BatchSize::SmallInput,
+            );
+        });
+    }
+    group.finish();
+}
+
+fn bench_hashes_per_second(c: &mut Criterion) {
+    let mut group = c.benchmark_group("throughput_hashes_per_sec");
+    group.throughput(Throughput::Elements(1));
+    let data = vec![0x42u8; 100];
+
+    group.bench_function("pallas_100b", |b| {
+        b.iter_batched(
+            PallasHasher::new,
+            |mut hasher| {
+                hasher.update(data.as_slice());
+                hasher.digest()
+            },
+            BatchSize::SmallInput,
+        );
+    });
+
+    group.bench_function("bn254_100b", |b| {
+        b.iter_batched(
+            BN254Hasher::new,
+            |mut hasher| {
+                hasher.update(data.as_slice());
+                hasher.digest()
+            },
+            BatchSize::SmallInput,
+        );
+    });
+
+    group.bench_function("bls12_381_100b", |b| {
+        b.iter_batched(
+            BLS12_381Hasher::new,
+            |mut hasher| {
+                hasher.update(data.as_slice());
+                hasher.digest()
+            },
+            BatchSize::SmallInput,
+        );
+    });
+
+    group.finish();
+}
+
+fn bench_batch_vs_scalar(c: &mut Criterion) {
+    const LANES: usize = 4;
+    let mut group = c.benchmark_group("throughput_batch_vs_scalar");
+    for &size in &INPUT_SIZES {
+        let lanes: [Vec<u8>; LANES] = std::array::from_fn(|i| vec![(0x10 + i) as u8; size]);
+        let refs: [&[u8]; LANES] = std::array::from_fn(|i| lanes[i].as_slice());
+        group.throughput(Throughput::Bytes((size * LANES) as u64));
+
+        group.bench_with_input(BenchmarkId::new("scalar_loop", size), &refs, |b, refs| {
+            b.iter(|| {
+                for data in refs.iter() {
+                    let mut hasher = PallasHasher::new();
+                    hasher.update(*data);
+                    let _ = hasher.digest();
+                }
+            });
+        });
+
+        group.bench_with_input(BenchmarkId::new("digest_batch", size), &refs, |b, refs| {
+            b.iter(|| PallasHasher::digest_batch(*refs, PackingConfig::default()));
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    bench_cross_curve_throughput,
+    bench_hashes_per_second,
+    bench_batch_vs_scalar
+);
+criterion_main!(benches);
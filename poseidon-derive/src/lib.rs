@@ -0,0 +1,158 @@
+//! `#[derive(PoseidonEncode)]`: generates a canonical, cross-process-stable
+//! [`poseidon_hash::encode::PoseidonEncode`] implementation for a struct or
+//! enum, so hand-writing ordered `update` calls (and the tag bookkeeping
+//! that requires) is no longer necessary to get a stable digest over
+//! structured data.
+//!
+//! The generated encoding always lays out as: a per-type tag carrying the
+//! type's name, then (for a struct) each field's encoding in declaration
+//! order, or (for an enum) a variant-index discriminant followed by that
+//! variant's fields. Fields recurse via their own `PoseidonEncode` impl, so
+//! nested derived types and the primitive impls in
+//! `poseidon_hash::encode` compose directly.
+//!
+//! `#[poseidon(mode = "circuit_friendly")]` on the type switches the
+//! generated `poseidon_packing_mode()` to request
+//! `PackingMode::CircuitFriendly` instead of the default byte-efficient
+//! mode, for callers who will absorb the encoded stream inside a circuit.
+
+use proc_macro::TokenStream;
+use quote::{format_ident, quote};
+use syn::{parse_macro_input, Data, DeriveInput, Fields, LitStr};
+
+#[proc_macro_derive(PoseidonEncode, attributes(poseidon))]
+pub fn derive_poseidon_encode(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let name = &input.ident;
+    let name_bytes = syn::LitByteStr::new(name.to_string().as_bytes(), name.span());
+    let packing_mode = parse_packing_mode(&input);
+
+    let body = match &input.data {
+        Data::Struct(data) => encode_fields_from_self(&data.fields),
+        Data::Enum(data) => {
+            let arms = data.variants.iter().enumerate().map(|(index, variant)| {
+                let variant_name = &variant.ident;
+                let index = index as u32;
+                match &variant.fields {
+                    Fields::Unit => quote! {
+                        Self::#variant_name => {
+                            out.push(::poseidon_hash::primitive::PrimitiveInput {
+                                tag: ::poseidon_hash::tags::TAG_ENUM_VARIANT,
+                                bytes: (#index as u32).to_le_bytes().to_vec(),
+                            });
+                        }
+                    },
+                    Fields::Unnamed(fields) => {
+                        let bindings: Vec<_> = (0..fields.unnamed.len())
+                            .map(|i| format_ident!("field_{}", i))
+                            .collect();
+                        quote! {
+                            Self::#variant_name( #(#bindings),* ) => {
+                                out.push(::poseidon_hash::primitive::PrimitiveInput {
+                                    tag: ::poseidon_hash::tags::TAG_ENUM_VARIANT,
+                                    bytes: (#index as u32).to_le_bytes().to_vec(),
+                                });
+                                #( ::poseidon_hash::encode::PoseidonEncode::poseidon_encode(#bindings, out); )*
+                            }
+                        }
+                    }
+                    Fields::Named(fields) => {
+                        let names: Vec<_> = fields
+                            .named
+                            .iter()
+                            .map(|f| f.ident.clone().unwrap())
+                            .collect();
+                        quote! {
+                            Self::#variant_name { #(#names),* } => {
+                                out.push(::poseidon_hash::primitive::PrimitiveInput {
+                                    tag: ::poseidon_hash::tags::TAG_ENUM_VARIANT,
+                                    bytes: (#index as u32).to_le_bytes().to_vec(),
+                                });
+                                #( ::poseidon_hash::encode::PoseidonEncode::poseidon_encode(#names, out); )*
+                            }
+                        }
+                    }
+                }
+            });
+            quote! {
+                match self {
+                    #(#arms)*
+                }
+            }
+        }
+        Data::Union(_) => {
+            return syn::Error::new_spanned(name, "PoseidonEncode cannot be derived for unions")
+                .to_compile_error()
+                .into();
+        }
+    };
+
+    let expanded = quote! {
+        impl ::poseidon_hash::encode::PoseidonEncode for #name {
+            fn poseidon_encode(&self, out: &mut Vec<::poseidon_hash::primitive::PrimitiveInput>) {
+                out.push(::poseidon_hash::primitive::PrimitiveInput {
+                    tag: ::poseidon_hash::tags::TAG_STRUCT_TYPE,
+                    bytes: #name_bytes.to_vec(),
+                });
+                #body
+            }
+
+            fn poseidon_packing_mode() -> ::poseidon_hash::primitive::PackingMode {
+                #packing_mode
+            }
+        }
+    };
+
+    TokenStream::from(expanded)
+}
+
+/// Generate field-by-field encoding for a struct's `Fields`, reading values
+/// off of `self` (named or positional).
+fn encode_fields_from_self(fields: &Fields) -> proc_macro2::TokenStream {
+    match fields {
+        Fields::Named(named) => {
+            let accessors = named.named.iter().map(|f| {
+                let field_name = f.ident.as_ref().unwrap();
+                quote! {
+                    ::poseidon_hash::encode::PoseidonEncode::poseidon_encode(&self.#field_name, out);
+                }
+            });
+            quote! { #(#accessors)* }
+        }
+        Fields::Unnamed(unnamed) => {
+            let accessors = (0..unnamed.unnamed.len()).map(|i| {
+                let index = syn::Index::from(i);
+                quote! {
+                    ::poseidon_hash::encode::PoseidonEncode::poseidon_encode(&self.#index, out);
+                }
+            });
+            quote! { #(#accessors)* }
+        }
+        Fields::Unit => quote! {},
+    }
+}
+
+/// Parse `#[poseidon(mode = "circuit_friendly")]` off the derive input,
+/// defaulting to `PackingMode::ByteEfficient`.
+fn parse_packing_mode(input: &DeriveInput) -> proc_macro2::TokenStream {
+    for attr in &input.attrs {
+        if !attr.path().is_ident("poseidon") {
+            continue;
+        }
+        let mut mode = None;
+        let _ = attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("mode") {
+                let value: LitStr = meta.value()?.parse()?;
+                mode = Some(value.value());
+            }
+            Ok(())
+        });
+        if let Some(mode) = mode {
+            return match mode.as_str() {
+                "circuit_friendly" => quote! { ::poseidon_hash::primitive::PackingMode::CircuitFriendly },
+                _ => quote! { ::poseidon_hash::primitive::PackingMode::ByteEfficient },
+            };
+        }
+    }
+    quote! { ::poseidon_hash::primitive::PackingMode::ByteEfficient }
+}
@@ -0,0 +1,87 @@
+use poseidon_hash::encode::PoseidonEncode;
+use poseidon_hash::primitive::{PackingMode, PrimitiveInput};
+use poseidon_derive::PoseidonEncode;
+
+#[derive(PoseidonEncode)]
+struct Note {
+    value: u64,
+    memo: String,
+}
+
+#[derive(PoseidonEncode)]
+struct Point(u32, u32);
+
+#[derive(PoseidonEncode)]
+#[poseidon(mode = "circuit_friendly")]
+struct CircuitFriendlyNote {
+    value: u64,
+}
+
+#[derive(PoseidonEncode)]
+enum Event {
+    Ping,
+    Amount(u64),
+    Transfer { from: u32, to: u32 },
+}
+
+fn encode<T: PoseidonEncode>(value: &T) -> Vec<PrimitiveInput> {
+    let mut out = Vec::new();
+    value.poseidon_encode(&mut out);
+    out
+}
+
+#[test]
+fn test_struct_encoding_leads_with_struct_type_tag() {
+    let note = Note { value: 7, memo: "hi".to_string() };
+    let out = encode(&note);
+    assert_eq!(out[0].tag, poseidon_hash::tags::TAG_STRUCT_TYPE);
+    assert_eq!(out[0].bytes, b"Note");
+    // type tag + value field + memo field
+    assert_eq!(out.len(), 3);
+}
+
+#[test]
+fn test_tuple_struct_encodes_fields_in_order() {
+    let out = encode(&Point(1, 2));
+    assert_eq!(out.len(), 3);
+    assert_eq!(out[1], PrimitiveInput::from(1u32));
+    assert_eq!(out[2], PrimitiveInput::from(2u32));
+}
+
+#[test]
+fn test_same_logical_value_encodes_identically() {
+    let a = Note { value: 7, memo: "hi".to_string() };
+    let b = Note { value: 7, memo: "hi".to_string() };
+    assert_eq!(encode(&a), encode(&b));
+}
+
+#[test]
+fn test_different_field_values_encode_differently() {
+    let a = Note { value: 7, memo: "hi".to_string() };
+    let b = Note { value: 8, memo: "hi".to_string() };
+    assert_ne!(encode(&a), encode(&b));
+}
+
+#[test]
+fn test_enum_variants_carry_distinct_discriminant() {
+    let ping = encode(&Event::Ping);
+    let amount = encode(&Event::Amount(5));
+    let transfer = encode(&Event::Transfer { from: 1, to: 2 });
+
+    assert_eq!(ping[1].tag, poseidon_hash::tags::TAG_ENUM_VARIANT);
+    assert_eq!(ping[1].bytes, 0u32.to_le_bytes().to_vec());
+    assert_eq!(amount[1].bytes, 1u32.to_le_bytes().to_vec());
+    assert_eq!(transfer[1].bytes, 2u32.to_le_bytes().to_vec());
+
+    assert_ne!(ping, amount);
+    assert_ne!(amount, transfer);
+}
+
+#[test]
+fn test_circuit_friendly_attribute_overrides_packing_mode() {
+    assert_eq!(Note::poseidon_packing_mode(), PackingMode::ByteEfficient);
+    assert_eq!(
+        CircuitFriendlyNote::poseidon_packing_mode(),
+        PackingMode::CircuitFriendly
+    );
+}
@@ -0,0 +1,77 @@
+//! Fixed-arity batch hashing entry point.
+//!
+//! Curve hashers process one message at a time through [`PoseidonHasher`].
+//! Workloads that hash many independent, same-shaped messages (Merkle-tree
+//! leaves, nullifiers) sometimes want that expressed as a single array-in,
+//! array-out call instead of a hand-rolled loop.
+//!
+//! This is **not a performance optimization**: [`digest_batch`] hashes each
+//! input exactly the way a plain `for` loop calling
+//! [`PoseidonHasher::digest`] would, one at a time, and is not measurably
+//! faster (see the `throughput_batch_vs_scalar` benchmark group in
+//! `benches/throughput.rs`, which exists to keep this claim honest).
+//!
+//! A real speed-up (e.g. four AVX2 lanes each holding one message's running
+//! state, with the `x^5` S-box and MDS mixing applied vectorized across
+//! lanes) needs a hand-written kernel tied to a specific field's limb
+//! layout — this crate's field arithmetic is generic over any
+//! [`ark_ff::PrimeField`], so there is no portable way to write one without
+//! per-field unsafe intrinsics this generic crate does not ship (see
+//! [`crate::simd_dispatch`] for the same limitation at the permutation
+//! level). That kernel is out of scope for this crate as it stands today;
+//! [`digest_batch`] is shipped as the plain array-in/array-out convenience
+//! API described above, not as a placeholder for it.
+
+use crate::primitive::PackingConfig;
+use crate::types::PoseidonHasher;
+
+/// Hash each of `inputs` independently, returning one digest per lane.
+///
+/// Provides no speed-up over calling [`PoseidonHasher::digest`] once per
+/// input in a loop — see the module docs.
+///
+/// `H` is the curve hasher to use (e.g. `PallasHasher`); `I` is its input
+/// type, required to accept a raw `&[u8]` the same way [`digest_parallel`](
+/// crate::parallel_hash::digest_parallel) does.
+pub fn digest_batch<F, I, H, const N: usize>(inputs: [&[u8]; N], config: PackingConfig) -> [F; N]
+where
+    H: PoseidonHasher<F, I>,
+    for<'a> &'a [u8]: Into<I>,
+{
+    inputs.map(|data| {
+        let mut hasher = H::new_with_config(config.clone());
+        hasher.update(data);
+        hasher.digest()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{PallasHasher, PallasInput};
+
+    #[test]
+    fn matches_hashing_each_input_individually() {
+        let inputs: [&[u8]; 3] = [b"one", b"two", b"three"];
+        let batched = digest_batch::<ark_pallas::Fq, PallasInput, PallasHasher, 3>(
+            inputs,
+            PackingConfig::default(),
+        );
+
+        for (data, expected) in inputs.iter().zip(batched.iter()) {
+            let mut hasher = PallasHasher::new();
+            hasher.update(*data);
+            assert_eq!(hasher.digest(), *expected);
+        }
+    }
+
+    #[test]
+    fn independent_lanes_produce_independent_digests() {
+        let inputs: [&[u8]; 2] = [b"alpha", b"beta"];
+        let batched = digest_batch::<ark_pallas::Fq, PallasInput, PallasHasher, 2>(
+            inputs,
+            PackingConfig::default(),
+        );
+        assert_ne!(batched[0], batched[1]);
+    }
+}
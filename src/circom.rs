@@ -0,0 +1,68 @@
+//! Circom/light-poseidon–compatible fixed-width BN254 hashing.
+//!
+//! [`poseidon_circom`] absorbs `1..=12` base-field elements into a single
+//! permutation (capacity = 1, no domain padding — the width `t = n + 1`
+//! itself fixes the input length), using the round-count table in
+//! [`crate::parameters::circom`]. This matches the round-count parameter
+//! convention Circom circuits and the Solana `sol_poseidon` syscall use;
+//! the digest itself has not been checked against a vendored third-party
+//! known-answer vector the way [`crate::poseidon2`]'s Pallas parameters are
+//! (see `poseidon2_pallas_kats` there), so treat this as
+//! round-count-compatible rather than a verified drop-in replacement for
+//! either until such a vector is added here.
+//!
+//! ```rust
+//! use poseidon_hash::circom::poseidon_circom;
+//!
+//! let a = ark_bn254::Fq::from(1u64);
+//! let b = ark_bn254::Fq::from(2u64);
+//! let hash = poseidon_circom(&[a, b]);
+//! assert_eq!(hash, poseidon_circom(&[a, b]));
+//! ```
+
+use ark_crypto_primitives::sponge::{CryptographicSponge, FieldBasedCryptographicSponge};
+
+use crate::ark_poseidon::ArkPoseidonSponge;
+use crate::parameters::circom::circom_params_for;
+
+/// Hash `1..=12` BN254 base-field elements in one Circom-compatible
+/// permutation. Panics if `inputs` is empty or longer than
+/// [`crate::parameters::circom::MAX_INPUTS`].
+pub fn poseidon_circom(inputs: &[ark_bn254::Fq]) -> ark_bn254::Fq {
+    let params = circom_params_for(inputs.len());
+    let mut sponge = ArkPoseidonSponge::new(params);
+    sponge.absorb(&inputs.to_vec());
+    sponge.squeeze_native_field_elements(1)[0]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deterministic() {
+        let inputs = [ark_bn254::Fq::from(1u64), ark_bn254::Fq::from(2u64)];
+        assert_eq!(poseidon_circom(&inputs), poseidon_circom(&inputs));
+    }
+
+    #[test]
+    fn test_different_inputs_hash_differently() {
+        let a = [ark_bn254::Fq::from(1u64), ark_bn254::Fq::from(2u64)];
+        let b = [ark_bn254::Fq::from(1u64), ark_bn254::Fq::from(3u64)];
+        assert_ne!(poseidon_circom(&a), poseidon_circom(&b));
+    }
+
+    #[test]
+    fn test_every_supported_width_runs() {
+        for n in 1..=crate::parameters::circom::MAX_INPUTS {
+            let inputs: Vec<_> = (0..n as u64).map(ark_bn254::Fq::from).collect();
+            let _ = poseidon_circom(&inputs);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "supports 1..=12")]
+    fn test_empty_input_panics() {
+        poseidon_circom(&[]);
+    }
+}
@@ -0,0 +1,91 @@
+//! Constant-time digest comparison.
+//!
+//! Ordinary `==` on a field element can short-circuit at the first
+//! differing limb, making comparison time depend on where two digests
+//! first diverge. [`ct_eq`] instead walks the full byte encoding
+//! unconditionally via volatile reads, XOR-accumulating every byte into one
+//! running value with no data-dependent branching — matching the
+//! zeroization-oriented security posture the rest of the crate already
+//! takes with sensitive state (see [`crate::hasher::volatile_zero`]).
+//!
+//! ```rust
+//! use poseidon_hash::ct_eq::ct_eq;
+//!
+//! let a = ark_pallas::Fq::from(42u64);
+//! let b = ark_pallas::Fq::from(42u64);
+//! let c = ark_pallas::Fq::from(43u64);
+//! assert!(ct_eq(&a, &b));
+//! assert!(!ct_eq(&a, &c));
+//! ```
+
+use ark_ff::{BigInteger, PrimeField};
+
+/// Compare two field elements for equality in constant time.
+///
+/// Both elements are encoded to their canonical little-endian byte
+/// representation (always the same fixed length for a given `F`) and
+/// compared via [`ct_eq_bytes`].
+pub fn ct_eq<F: PrimeField>(a: &F, b: &F) -> bool {
+    let a_bytes = a.into_bigint().to_bytes_le();
+    let b_bytes = b.into_bigint().to_bytes_le();
+    ct_eq_bytes(&a_bytes, &b_bytes)
+}
+
+/// Compare two byte slices for equality in constant time.
+///
+/// If the lengths differ this returns `false` immediately without walking
+/// either slice — lengths are not treated as secret here, since every
+/// caller in this crate compares fixed-length field-element encodings.
+/// When the lengths match, every byte of both slices is read via
+/// [`std::ptr::read_volatile`] and folded into one XOR accumulator, so the
+/// full length is always walked regardless of where (or whether) the
+/// slices first differ.
+pub fn ct_eq_bytes(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    let mut diff: u8 = 0;
+    for i in 0..a.len() {
+        let av = unsafe { std::ptr::read_volatile(&a[i]) };
+        let bv = unsafe { std::ptr::read_volatile(&b[i]) };
+        diff |= av ^ bv;
+    }
+    std::sync::atomic::compiler_fence(std::sync::atomic::Ordering::SeqCst);
+    diff == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_equal_digests_compare_equal() {
+        let a = ark_pallas::Fq::from(42u64);
+        let b = ark_pallas::Fq::from(42u64);
+        assert!(ct_eq(&a, &b));
+    }
+
+    #[test]
+    fn test_single_bit_difference_compares_unequal() {
+        let a = ark_pallas::Fq::from(42u64);
+        let b = ark_pallas::Fq::from(43u64);
+        assert!(!ct_eq(&a, &b));
+    }
+
+    #[test]
+    fn test_all_ones_vs_all_zeros_compares_unequal() {
+        let zeros = vec![0u8; 32];
+        let ones = vec![0xFFu8; 32];
+        assert!(!ct_eq_bytes(&zeros, &ones));
+    }
+
+    #[test]
+    fn test_mismatched_lengths_compare_unequal() {
+        assert!(!ct_eq_bytes(&[1, 2, 3], &[1, 2, 3, 4]));
+    }
+
+    #[test]
+    fn test_empty_slices_compare_equal() {
+        assert!(ct_eq_bytes(&[], &[]));
+    }
+}
@@ -0,0 +1,102 @@
+//! RustCrypto [`digest::Digest`] adapter over this crate's streaming hashers.
+//!
+//! Gated behind the `digest` cargo feature (off by default — it pulls in the
+//! `digest`/`generic-array` crates, needed only by consumers bridging this
+//! crate into the RustCrypto hashing ecosystem, e.g. `hmac` or `pbkdf2`).
+//!
+//! Bytes passed to [`Update::update`] go through the same `TAG_BYTES`
+//! primitive-packing path as `PallasHasher::update(some_byte_slice)` already
+//! uses; [`FixedOutput::finalize_into`] calls [`PoseidonHasher::digest`] (via
+//! each curve hasher's `digest_to_repr`) to produce the fixed-size byte
+//! output `digest::Digest` requires.
+//!
+//! ```rust
+//! # #[cfg(feature = "digest")]
+//! # {
+//! use digest::Digest;
+//! use poseidon_hash::digest_adapter::PallasDigest;
+//!
+//! let mut hasher = PallasDigest::default();
+//! hasher.update(b"hello world");
+//! let output = hasher.finalize();
+//! assert_eq!(output.len(), 32);
+//! # }
+//! ```
+
+use digest::generic_array::GenericArray;
+use digest::{consts::U32, FixedOutput, HashMarker, OutputSizeUser, Reset, Update};
+
+use crate::types::PoseidonHasher;
+
+// Generates a `digest::Digest`-compatible newtype wrapper around one of this
+// crate's curve hashers, delegating every RustCrypto trait method to the
+// wrapped hasher's `PoseidonHasher` surface.
+macro_rules! define_digest_adapter {
+    ($Adapter:ident, $Hasher:ty, $OutputSize:ty) => {
+        /// RustCrypto-compatible digest wrapper; see the module docs.
+        #[derive(Default)]
+        pub struct $Adapter($Hasher);
+
+        impl HashMarker for $Adapter {}
+
+        impl Update for $Adapter {
+            fn update(&mut self, data: &[u8]) {
+                PoseidonHasher::update(&mut self.0, data);
+            }
+        }
+
+        impl OutputSizeUser for $Adapter {
+            type OutputSize = $OutputSize;
+        }
+
+        impl FixedOutput for $Adapter {
+            fn finalize_into(mut self, out: &mut GenericArray<u8, Self::OutputSize>) {
+                out.copy_from_slice(&self.0.digest_to_repr());
+            }
+        }
+
+        impl Reset for $Adapter {
+            fn reset(&mut self) {
+                PoseidonHasher::reset(&mut self.0);
+            }
+        }
+    };
+}
+
+define_digest_adapter!(PallasDigest, crate::PallasHasher, U32);
+define_digest_adapter!(BN254Digest, crate::BN254Hasher, U32);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::PallasHasher;
+    use digest::Digest;
+
+    #[test]
+    fn test_digest_trait_matches_native_digest_to_repr() {
+        let mut native = PallasHasher::new();
+        native.update(b"hello world".as_slice());
+        let expected = native.digest_to_repr();
+
+        let mut adapter = PallasDigest::default();
+        adapter.update(b"hello world");
+        let output = adapter.finalize();
+
+        assert_eq!(output.as_slice(), &expected);
+    }
+
+    #[test]
+    fn test_reset_matches_a_fresh_instance() {
+        let mut a = PallasDigest::default();
+        a.update(b"some input");
+        Reset::reset(&mut a);
+        a.update(b"other input");
+        let reset_output = a.finalize();
+
+        let mut b = PallasDigest::default();
+        b.update(b"other input");
+        let fresh_output = b.finalize();
+
+        assert_eq!(reset_output, fresh_output);
+    }
+}
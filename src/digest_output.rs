@@ -0,0 +1,158 @@
+//! Ergonomic digest output type wrapping the raw field element
+//! [`crate::types::PoseidonHasher::digest`] returns.
+//!
+//! Comparing hashes via `to_string()` (as earlier tests throughout this
+//! crate do) or via `==` on the raw field element both short-circuit at the
+//! first differing byte/limb, so any downstream signature or commitment
+//! check built directly on `F` leaks timing. [`PoseidonDigest`] carries the
+//! canonical byte views alongside the field element and a [`Self::ct_eq`]
+//! built on [`crate::ct_eq::ct_eq`], plus [`Self::to_hex`]/[`Self::from_hex`]
+//! for the stringly-typed comparisons this replaces.
+//!
+//! This is additive — [`crate::types::PoseidonHasher::digest`] still returns
+//! a raw `F`, so existing call sites are unaffected.
+//! [`crate::types::PoseidonHasher::digest_wrapped`] is the opt-in entry point
+//! into this type.
+//!
+//! ```rust
+//! use poseidon_hash::{PallasHasher, PoseidonHasher};
+//!
+//! let mut hasher = PallasHasher::new();
+//! hasher.update(42u64);
+//! let digest = hasher.digest_wrapped();
+//!
+//! let hex = digest.to_hex();
+//! let round_tripped = poseidon_hash::digest_output::PoseidonDigest::from_hex(&hex).unwrap();
+//! assert!(digest.ct_eq(&round_tripped));
+//! ```
+
+use ark_ff::{BigInteger, PrimeField};
+
+use crate::hasher::{decode_canonical_field, HasherError, HasherResult};
+
+/// A Poseidon digest: the raw field element plus its canonical byte
+/// encodings. See the module docs.
+#[derive(Debug, Clone, Copy)]
+pub struct PoseidonDigest<F: PrimeField> {
+    value: F,
+}
+
+impl<F: PrimeField> PoseidonDigest<F> {
+    /// Wrap an existing digest field element.
+    pub fn from_field(value: F) -> Self {
+        Self { value }
+    }
+
+    /// The underlying field element.
+    pub fn value(&self) -> F {
+        self.value
+    }
+
+    /// Canonical little-endian byte encoding.
+    pub fn to_bytes_le(&self) -> Vec<u8> {
+        self.value.into_bigint().to_bytes_le()
+    }
+
+    /// Canonical big-endian byte encoding.
+    pub fn to_bytes_be(&self) -> Vec<u8> {
+        self.value.into_bigint().to_bytes_be()
+    }
+
+    /// Alias for [`Self::to_bytes_le`], this crate's default byte order.
+    pub fn as_bytes(&self) -> Vec<u8> {
+        self.to_bytes_le()
+    }
+
+    /// Lowercase hex encoding of [`Self::to_bytes_le`].
+    pub fn to_hex(&self) -> String {
+        hex::encode(self.to_bytes_le())
+    }
+
+    /// Parse a hex string produced by [`Self::to_hex`], rejecting
+    /// non-canonical (`>= modulus`) encodings.
+    pub fn from_hex(s: &str) -> HasherResult<Self> {
+        let bytes = hex::decode(s).map_err(|e| HasherError::NumericConversionFailed {
+            reason: format!("invalid hex digest: {e}"),
+        })?;
+        Self::try_from(bytes.as_slice())
+    }
+
+    /// Constant-time equality, via [`crate::ct_eq::ct_eq`].
+    pub fn ct_eq(&self, other: &Self) -> bool {
+        crate::ct_eq::ct_eq(&self.value, &other.value)
+    }
+}
+
+impl<F: PrimeField> TryFrom<&[u8]> for PoseidonDigest<F> {
+    type Error = HasherError;
+
+    /// Decode the canonical little-endian encoding of a digest, rejecting
+    /// any encoding `>= modulus` rather than silently reducing it.
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        let value = decode_canonical_field::<F>(bytes)?;
+        Ok(Self { value })
+    }
+}
+
+impl<F: PrimeField> std::fmt::Display for PoseidonDigest<F> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.to_hex())
+    }
+}
+
+/// `==`/`!=` route through [`Self::ct_eq`] rather than deriving a plain
+/// field-element comparison, which would short-circuit at the first
+/// differing limb and silently reintroduce the exact timing leak this type
+/// exists to prevent.
+impl<F: PrimeField> PartialEq for PoseidonDigest<F> {
+    fn eq(&self, other: &Self) -> bool {
+        self.ct_eq(other)
+    }
+}
+
+impl<F: PrimeField> Eq for PoseidonDigest<F> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hex_round_trips() {
+        let digest = PoseidonDigest::from_field(ark_pallas::Fq::from(42u64));
+        let hex = digest.to_hex();
+        let round_tripped = PoseidonDigest::from_hex(&hex).unwrap();
+        assert_eq!(digest, round_tripped);
+    }
+
+    #[test]
+    fn test_bytes_round_trip_via_try_from() {
+        let digest = PoseidonDigest::from_field(ark_pallas::Fq::from(123u64));
+        let bytes = digest.as_bytes();
+        let round_tripped = PoseidonDigest::<ark_pallas::Fq>::try_from(bytes.as_slice()).unwrap();
+        assert_eq!(digest, round_tripped);
+    }
+
+    #[test]
+    fn test_non_canonical_encoding_is_rejected() {
+        let modulus_bytes = <ark_pallas::Fq as PrimeField>::MODULUS.to_bytes_le();
+        let result = PoseidonDigest::<ark_pallas::Fq>::try_from(modulus_bytes.as_slice());
+        assert!(matches!(result, Err(HasherError::NonCanonicalEncoding)));
+    }
+
+    #[test]
+    fn test_ct_eq_matches_partial_eq() {
+        let a = PoseidonDigest::from_field(ark_pallas::Fq::from(7u64));
+        let b = PoseidonDigest::from_field(ark_pallas::Fq::from(7u64));
+        let c = PoseidonDigest::from_field(ark_pallas::Fq::from(8u64));
+        assert!(a.ct_eq(&b));
+        assert!(!a.ct_eq(&c));
+        assert_eq!(a.ct_eq(&b), a == b);
+        assert_eq!(a.ct_eq(&c), a == c);
+    }
+
+    #[test]
+    fn test_display_matches_to_hex() {
+        let digest = PoseidonDigest::from_field(ark_pallas::Fq::from(99u64));
+        assert_eq!(digest.to_string(), digest.to_hex());
+    }
+}
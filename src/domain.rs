@@ -0,0 +1,128 @@
+//! Domain-separated fixed-length hashing over [`Poseidon2Sponge`].
+//!
+//! The raw sponge has no notion of "this message is exactly `L` elements
+//! long" — two differently-shaped preimages that pad to the same block
+//! sequence can collide. [`Domain`] fixes this by initializing the
+//! capacity lane to a domain-specific constant before absorbing, mirroring
+//! the `Domain` abstraction in the halo2 Poseidon primitive.
+
+use ark_crypto_primitives::sponge::{CryptographicSponge, FieldBasedCryptographicSponge};
+use ark_ff::PrimeField;
+
+use crate::poseidon2::{Poseidon2Sponge, PoseidonConfig};
+
+/// Supplies a domain-specific capacity-lane initializer and padding rule,
+/// so that two differently-shaped inputs cannot collide after padding.
+pub trait Domain<F: PrimeField> {
+    /// Value written into the capacity lane before absorbing. Assumes
+    /// `capacity == 1`, as throughout this crate.
+    fn initial_capacity_element() -> F;
+
+    /// Pad `input` out to a whole number of `rate`-sized blocks, so the
+    /// final block is always fully and deterministically determined by the
+    /// message rather than left ambiguous.
+    fn pad(input: &[F], rate: usize) -> Vec<F>;
+}
+
+/// Fixed-length input domain: absorbs exactly `L` field elements, with the
+/// capacity lane initialized to a constant that encodes `L`. Two messages
+/// of different `L` therefore always start from distinct capacity states,
+/// even if one happens to be a zero-padded prefix of the other.
+pub struct ConstantLength<const L: usize>;
+
+impl<F: PrimeField, const L: usize> Domain<F> for ConstantLength<L> {
+    fn initial_capacity_element() -> F {
+        F::from(L as u64)
+    }
+
+    fn pad(input: &[F], rate: usize) -> Vec<F> {
+        assert_eq!(
+            input.len(),
+            L,
+            "ConstantLength<{L}> requires exactly {L} elements, got {}",
+            input.len()
+        );
+        let mut padded = input.to_vec();
+        let remainder = padded.len() % rate;
+        if remainder != 0 {
+            padded.resize(padded.len() + (rate - remainder), F::zero());
+        }
+        padded
+    }
+}
+
+/// One-shot fixed-length hash: absorb exactly `L` field elements under the
+/// given [`Domain`] and squeeze a single field element.
+///
+/// `parameters` must have `capacity == 1`.
+pub fn hash<F, D, const L: usize>(parameters: &PoseidonConfig<F>, input: [F; L]) -> F
+where
+    F: PrimeField,
+    D: Domain<F>,
+{
+    assert_eq!(
+        parameters.capacity, 1,
+        "domain::hash assumes a single capacity lane"
+    );
+
+    let mut sponge = Poseidon2Sponge::new(parameters);
+    sponge.state[0] = D::initial_capacity_element();
+
+    let padded = D::pad(&input, parameters.rate);
+    for block in padded.chunks(parameters.rate) {
+        sponge.absorb(&block.to_vec());
+    }
+
+    sponge.squeeze_native_field_elements(1)[0]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parameters::poseidon2_pallas::{PALLAS_POSEIDON2_PARAMS, PALLAS_POSEIDON2_PARAMS_T4};
+
+    type F = ark_pallas::Fq;
+
+    #[test]
+    fn hash_is_deterministic() {
+        let input = [F::from(1u64), F::from(2u64)];
+        let a = hash::<F, ConstantLength<2>, 2>(&PALLAS_POSEIDON2_PARAMS, input);
+        let b = hash::<F, ConstantLength<2>, 2>(&PALLAS_POSEIDON2_PARAMS, input);
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn different_lengths_do_not_collide_despite_zero_padding() {
+        // [1] under ConstantLength<1> pads to the same rate block [1, 0] as
+        // [1, 0] absorbed directly under ConstantLength<2>, but the two
+        // must still differ because the capacity lane encodes the length.
+        let one = hash::<F, ConstantLength<1>, 1>(&PALLAS_POSEIDON2_PARAMS, [F::from(1u64)]);
+        let two = hash::<F, ConstantLength<2>, 2>(
+            &PALLAS_POSEIDON2_PARAMS,
+            [F::from(1u64), F::from(0u64)],
+        );
+        assert_ne!(one, two);
+    }
+
+    #[test]
+    fn different_inputs_of_the_same_length_hash_differently() {
+        let a = hash::<F, ConstantLength<3>, 3>(
+            &PALLAS_POSEIDON2_PARAMS_T4,
+            [F::from(1u64), F::from(2u64), F::from(3u64)],
+        );
+        let b = hash::<F, ConstantLength<3>, 3>(
+            &PALLAS_POSEIDON2_PARAMS_T4,
+            [F::from(1u64), F::from(2u64), F::from(4u64)],
+        );
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    #[should_panic(expected = "requires exactly")]
+    fn wrong_length_input_panics() {
+        // `ConstantLength<2>::pad` asserts the slice length; constructing
+        // this call requires passing a 2-element array, so simulate a
+        // mismatched domain directly via `pad`.
+        ConstantLength::<2>::pad(&[F::from(1u64)], PALLAS_POSEIDON2_PARAMS.rate);
+    }
+}
@@ -0,0 +1,63 @@
+//! EdDSA signature verification parameterized over the Poseidon hasher.
+//!
+//! This mirrors the Baby-JubJub/Zcash-style "EdDSA over a SNARK-friendly
+//! curve" construction: the signature lives on a twisted-Edwards curve whose
+//! base field is the *scalar* field of whatever curve a zk circuit is built
+//! over, and the Fiat–Shamir challenge is derived with Poseidon (rather than
+//! SHA-512 as in classic Ed25519) so the whole check is circuit-friendly.
+//!
+//! [`eddsa_verify`] is generic over which concrete [`PoseidonHasher`]
+//! computes the challenge, so callers pick the Poseidon instance matching
+//! their curve's scalar field without this module depending on any one
+//! concrete twisted-Edwards curve.
+
+use ark_ec::twisted_edwards::{Affine, TECurveConfig};
+use ark_ec::{AffineRepr, CurveGroup};
+use ark_ff::PrimeField;
+
+use crate::types::PoseidonHasher;
+
+/// Verify an EdDSA-over-Poseidon signature: checks `s*B == R8 + h*A`, where
+/// `B` is `P`'s generator, `A = (pk_x, pk_y)` is the public key, `R8 =
+/// (r8_x, r8_y)` is the signature's nonce point, and the challenge `h =
+/// hasher.hash(&[r8_x, r8_y, pk_x, pk_y, msg])` binds all three together.
+///
+/// `h` is a base-field (`F`) element but curve scalar multiplication needs
+/// an exponent in `P::ScalarField`; it is reduced into the scalar field via
+/// [`PrimeField::from_le_bytes_mod_order`] of `h`'s little-endian bytes.
+///
+/// Returns `false` — rather than panicking — if `pk` or `r8` fail to
+/// decode to a point on `P` in the prime-order subgroup, so a malformed or
+/// adversarial signature can never be mistaken for a valid one.
+pub fn eddsa_verify<F, P, H, I>(
+    pk_x: F,
+    pk_y: F,
+    s: P::ScalarField,
+    r8_x: F,
+    r8_y: F,
+    msg: F,
+    hasher: &H,
+) -> bool
+where
+    F: PrimeField,
+    P: TECurveConfig<BaseField = F>,
+    H: PoseidonHasher<F, I>,
+    F: Into<I>,
+{
+    let pk = Affine::<P>::new_unchecked(pk_x, pk_y);
+    let r8 = Affine::<P>::new_unchecked(r8_x, r8_y);
+    if !pk.is_on_curve() || !pk.is_in_correct_subgroup_assuming_on_curve() {
+        return false;
+    }
+    if !r8.is_on_curve() || !r8.is_in_correct_subgroup_assuming_on_curve() {
+        return false;
+    }
+
+    let h = hasher.hash(&[r8_x, r8_y, pk_x, pk_y, msg]);
+    let h_scalar = P::ScalarField::from_le_bytes_mod_order(&h.into_bigint().to_bytes_le());
+
+    let b = Affine::<P>::generator();
+    let lhs = b.into_group() * s;
+    let rhs = r8.into_group() + pk.into_group() * h_scalar;
+    lhs.into_affine() == rhs.into_affine()
+}
@@ -0,0 +1,130 @@
+//! Canonical, cross-process-stable encoding of structured values into a
+//! [`PrimitiveInput`] stream, via `#[derive(PoseidonEncode)]` (in the sibling
+//! `poseidon-derive` crate).
+//!
+//! Hand-writing `update` calls for a struct's fields is error-prone (field
+//! order, forgotten fields, accidental reuse of a primitive's tag for two
+//! unrelated purposes) and gives no stable wire format across processes. A
+//! derived `poseidon_encode` instead always emits: a per-type tag carrying
+//! the type's name ([`crate::tags::TAG_STRUCT_TYPE`]), then either the
+//! struct's fields in declaration order, or (for an enum) a variant-index
+//! discriminant ([`crate::tags::TAG_ENUM_VARIANT`]) followed by that
+//! variant's fields — so two processes encoding the same logical value
+//! always agree on the resulting [`PrimitiveInput`] stream, and therefore on
+//! the digest.
+//!
+//! ```rust,ignore
+//! use poseidon_hash::encode::PoseidonEncode;
+//! use poseidon_derive::PoseidonEncode;
+//!
+//! #[derive(PoseidonEncode)]
+//! struct Note {
+//!     value: u64,
+//!     memo: String,
+//! }
+//! ```
+
+use crate::primitive::{PackingMode, PrimitiveInput};
+
+/// Implemented by `#[derive(PoseidonEncode)]` and by the primitive types it
+/// recurses into, to append a value's canonical encoding onto a
+/// [`PrimitiveInput`] stream.
+pub trait PoseidonEncode {
+    /// Append this value's canonical encoding onto `out`.
+    fn poseidon_encode(&self, out: &mut Vec<PrimitiveInput>);
+
+    /// Packing mode a derived encoder requests when the resulting
+    /// [`PrimitiveInput`] stream is later packed into field elements.
+    /// Defaults to [`PackingMode::ByteEfficient`]; overridden by
+    /// `#[poseidon(mode = "circuit_friendly")]` on the derived type.
+    fn poseidon_packing_mode() -> PackingMode
+    where
+        Self: Sized,
+    {
+        PackingMode::ByteEfficient
+    }
+}
+
+macro_rules! impl_poseidon_encode_for_copy_primitive {
+    ( $( $t:ty ),* $(,)? ) => {
+        $(
+            impl PoseidonEncode for $t {
+                fn poseidon_encode(&self, out: &mut Vec<PrimitiveInput>) {
+                    out.push(PrimitiveInput::from(*self));
+                }
+            }
+        )*
+    };
+}
+
+impl_poseidon_encode_for_copy_primitive! {
+    bool, u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize,
+}
+
+impl PoseidonEncode for String {
+    fn poseidon_encode(&self, out: &mut Vec<PrimitiveInput>) {
+        out.push(PrimitiveInput::from(self.clone()));
+    }
+}
+
+impl PoseidonEncode for str {
+    fn poseidon_encode(&self, out: &mut Vec<PrimitiveInput>) {
+        out.push(PrimitiveInput::from(self));
+    }
+}
+
+impl PoseidonEncode for Vec<u8> {
+    fn poseidon_encode(&self, out: &mut Vec<PrimitiveInput>) {
+        out.push(PrimitiveInput::from(self.clone()));
+    }
+}
+
+impl<T: PoseidonEncode> PoseidonEncode for Vec<T> {
+    fn poseidon_encode(&self, out: &mut Vec<PrimitiveInput>) {
+        out.push(PrimitiveInput::from(self.len() as u64));
+        for item in self {
+            item.poseidon_encode(out);
+        }
+    }
+}
+
+impl<T: PoseidonEncode> PoseidonEncode for Option<T> {
+    fn poseidon_encode(&self, out: &mut Vec<PrimitiveInput>) {
+        match self {
+            None => out.push(PrimitiveInput::from(false)),
+            Some(value) => {
+                out.push(PrimitiveInput::from(true));
+                value.poseidon_encode(out);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_vec_encoding_includes_length_prefix() {
+        let mut out = Vec::new();
+        vec![1u64, 2, 3].poseidon_encode(&mut out);
+        // One element for the length, one per item.
+        assert_eq!(out.len(), 4);
+    }
+
+    #[test]
+    fn test_option_none_and_some_differ() {
+        let mut none_out = Vec::new();
+        None::<u64>.poseidon_encode(&mut none_out);
+
+        let mut some_out = Vec::new();
+        Some(0u64).poseidon_encode(&mut some_out);
+
+        assert_ne!(none_out.len(), some_out.len());
+    }
+
+    #[test]
+    fn test_default_packing_mode_is_byte_efficient() {
+        assert_eq!(u64::poseidon_packing_mode(), PackingMode::ByteEfficient);
+    }
+}
@@ -0,0 +1,241 @@
+//! C-ABI layer exposing the 32-byte-field-element curve hashers (Pallas,
+//! Vesta, BN254; see [`CurveId`]) and Pallas Merkle-proof verification as
+//! `extern "C"` entry points, so the crate is consumable from C, Go, or any
+//! other language without a Rust dependency — e.g. for storage-proof or
+//! nullifier computation inside a non-Rust node client.
+//!
+//! Every entry point returns a [`PoseidonFfiStatus`] code and writes results
+//! through out-params rather than panicking or returning Rust-specific types
+//! across the boundary. Field elements cross the boundary as fixed 32-byte
+//! little-endian buffers and are validated as canonical (`< modulus`) before
+//! use, the same check [`crate::hasher::MultiFieldHasher::update_bytes`]
+//! already performs for in-process callers — a non-canonical encoding is
+//! rejected with [`PoseidonFfiStatus::NonCanonicalEncoding`] rather than
+//! silently reduced.
+//!
+//! BLS12-381/BLS12-377 are not exposed here since their base field doesn't
+//! fit the fixed 32-byte encoding this layer commits to; reach for them from
+//! Rust directly via [`crate::BLS12_381Hasher`]/[`crate::BLS12_377Hasher`].
+
+use std::ffi::c_void;
+use std::slice;
+
+use ark_ff::{BigInteger, PrimeField};
+
+use crate::tree::MerkleProof;
+use crate::types::PoseidonHasher;
+use crate::{BN254Hasher, PallasHasher, VestaHasher};
+
+/// Which curve's base field a [`poseidon_ffi_new`] handle hashes over.
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CurveId {
+    Pallas = 0,
+    Vesta = 1,
+    Bn254 = 2,
+}
+
+impl CurveId {
+    fn from_u32(value: u32) -> Option<Self> {
+        match value {
+            0 => Some(CurveId::Pallas),
+            1 => Some(CurveId::Vesta),
+            2 => Some(CurveId::Bn254),
+            _ => None,
+        }
+    }
+}
+
+/// Status codes returned by every `poseidon_ffi_*` entry point.
+#[repr(i32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PoseidonFfiStatus {
+    Ok = 0,
+    /// A required pointer argument was null.
+    NullPointer = -1,
+    /// `curve` did not match a [`CurveId`] variant.
+    UnknownCurve = -2,
+    /// A 32-byte input buffer decoded to a value `>= modulus`.
+    NonCanonicalEncoding = -3,
+    /// A buffer's length wasn't the expected multiple of 32 bytes.
+    InvalidLength = -4,
+}
+
+enum FfiHasher {
+    Pallas(PallasHasher),
+    Vesta(VestaHasher),
+    Bn254(BN254Hasher),
+}
+
+impl FfiHasher {
+    fn new(curve: CurveId) -> Self {
+        match curve {
+            CurveId::Pallas => FfiHasher::Pallas(PallasHasher::new()),
+            CurveId::Vesta => FfiHasher::Vesta(VestaHasher::new()),
+            CurveId::Bn254 => FfiHasher::Bn254(BN254Hasher::new()),
+        }
+    }
+
+    fn absorb_32(&mut self, block: &[u8]) -> Result<(), ()> {
+        match self {
+            FfiHasher::Pallas(h) => h.update_bytes(block).map_err(|_| ()),
+            FfiHasher::Vesta(h) => h.update_bytes(block).map_err(|_| ()),
+            FfiHasher::Bn254(h) => h.update_bytes(block).map_err(|_| ()),
+        }
+    }
+
+    fn squeeze_32(&mut self) -> [u8; 32] {
+        let bytes = match self {
+            FfiHasher::Pallas(h) => h.squeeze(1)[0].into_bigint().to_bytes_le(),
+            FfiHasher::Vesta(h) => h.squeeze(1)[0].into_bigint().to_bytes_le(),
+            FfiHasher::Bn254(h) => h.squeeze(1)[0].into_bigint().to_bytes_le(),
+        };
+        let mut out = [0u8; 32];
+        let n = bytes.len().min(32);
+        out[..n].copy_from_slice(&bytes[..n]);
+        out
+    }
+}
+
+/// Allocate a new hasher for `curve`. Returns null if `curve` doesn't match
+/// a [`CurveId`] variant; the handle must be released with
+/// [`poseidon_ffi_free`].
+#[no_mangle]
+pub extern "C" fn poseidon_ffi_new(curve: u32) -> *mut c_void {
+    match CurveId::from_u32(curve) {
+        Some(curve) => Box::into_raw(Box::new(FfiHasher::new(curve))) as *mut c_void,
+        None => std::ptr::null_mut(),
+    }
+}
+
+/// Free a handle returned by [`poseidon_ffi_new`].
+///
+/// # Safety
+///
+/// `handle` must either be null or a value previously returned by
+/// [`poseidon_ffi_new`] that has not already been freed.
+#[no_mangle]
+pub unsafe extern "C" fn poseidon_ffi_free(handle: *mut c_void) {
+    if !handle.is_null() {
+        drop(Box::from_raw(handle as *mut FfiHasher));
+    }
+}
+
+/// Absorb `len` bytes from `data`, interpreted as a sequence of 32-byte
+/// little-endian field element encodings (`len` must be a multiple of 32).
+/// Each block is validated as canonical (`< modulus`) before absorbing;
+/// absorption stops at the first non-canonical block, leaving the hasher's
+/// state as of the last successfully absorbed block.
+///
+/// # Safety
+///
+/// `handle` must be a live value from [`poseidon_ffi_new`]; `data` must
+/// point to at least `len` readable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn poseidon_ffi_absorb(
+    handle: *mut c_void,
+    data: *const u8,
+    len: usize,
+) -> i32 {
+    if handle.is_null() || data.is_null() {
+        return PoseidonFfiStatus::NullPointer as i32;
+    }
+    if len % 32 != 0 {
+        return PoseidonFfiStatus::InvalidLength as i32;
+    }
+
+    let hasher = &mut *(handle as *mut FfiHasher);
+    let bytes = slice::from_raw_parts(data, len);
+    for block in bytes.chunks(32) {
+        if hasher.absorb_32(block).is_err() {
+            return PoseidonFfiStatus::NonCanonicalEncoding as i32;
+        }
+    }
+    PoseidonFfiStatus::Ok as i32
+}
+
+/// Squeeze one field element, writing its 32-byte little-endian encoding
+/// into `out`.
+///
+/// # Safety
+///
+/// `handle` must be a live value from [`poseidon_ffi_new`]; `out` must
+/// point to at least 32 writable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn poseidon_ffi_squeeze(handle: *mut c_void, out: *mut u8) -> i32 {
+    if handle.is_null() || out.is_null() {
+        return PoseidonFfiStatus::NullPointer as i32;
+    }
+
+    let hasher = &mut *(handle as *mut FfiHasher);
+    let bytes = hasher.squeeze_32();
+    std::ptr::copy_nonoverlapping(bytes.as_ptr(), out, 32);
+    PoseidonFfiStatus::Ok as i32
+}
+
+fn decode_32(ptr: *const u8) -> Result<ark_pallas::Fq, ()> {
+    let bytes = unsafe { slice::from_raw_parts(ptr, 32) };
+    let repr = <ark_pallas::Fq as PrimeField>::BigInt::from_bits_le(
+        &bytes
+            .iter()
+            .flat_map(|b| (0..8).map(move |i| (b >> i) & 1 == 1))
+            .collect::<Vec<_>>(),
+    );
+    ark_pallas::Fq::from_bigint(repr).ok_or(())
+}
+
+/// Verify a Pallas Merkle proof: `root`, `leaf`, and every entry of
+/// `siblings` are 32-byte little-endian field element encodings, `siblings`
+/// holds `num_siblings` consecutive 32-byte blocks ordered leaf-to-root, and
+/// `leaf_index` is the leaf's position (its bits select, from the
+/// least-significant up, which side of each level's pair the running hash
+/// occupies — see [`crate::tree::MerkleProof`]). Writes `1` to `*out_valid`
+/// if the proof verifies against `root`, `0` otherwise.
+///
+/// # Safety
+///
+/// `root`, `leaf`, and `out_valid` must be valid non-null pointers; `siblings`
+/// must point to at least `32 * num_siblings` readable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn poseidon_ffi_merkle_verify_pallas(
+    root: *const u8,
+    leaf: *const u8,
+    leaf_index: u64,
+    siblings: *const u8,
+    num_siblings: usize,
+    out_valid: *mut i32,
+) -> i32 {
+    if root.is_null() || leaf.is_null() || out_valid.is_null() {
+        return PoseidonFfiStatus::NullPointer as i32;
+    }
+    if num_siblings > 0 && siblings.is_null() {
+        return PoseidonFfiStatus::NullPointer as i32;
+    }
+
+    let root = match decode_32(root) {
+        Ok(v) => v,
+        Err(()) => return PoseidonFfiStatus::NonCanonicalEncoding as i32,
+    };
+    let leaf = match decode_32(leaf) {
+        Ok(v) => v,
+        Err(()) => return PoseidonFfiStatus::NonCanonicalEncoding as i32,
+    };
+
+    let mut sibling_values = Vec::with_capacity(num_siblings);
+    for i in 0..num_siblings {
+        match decode_32(siblings.add(i * 32)) {
+            Ok(v) => sibling_values.push(v),
+            Err(()) => return PoseidonFfiStatus::NonCanonicalEncoding as i32,
+        }
+    }
+
+    let proof = MerkleProof {
+        leaf_index: leaf_index as usize,
+        siblings: sibling_values,
+    };
+    let params =
+        crate::parameters::clone_parameters(&*crate::parameters::pallas::PALLAS_PARAMS);
+    let valid = crate::tree::verify(&params, root, leaf, &proof);
+    *out_valid = if valid { 1 } else { 0 };
+    PoseidonFfiStatus::Ok as i32
+}
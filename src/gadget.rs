@@ -0,0 +1,210 @@
+//! R1CS constraint-system gadget mirroring the native [`crate::types::PallasHasher`].
+//!
+//! This gadget runs the same Poseidon permutation (via `ark-crypto-primitives`'s
+//! own `PoseidonSpongeVar`, driven by the crate's existing [`ArkPoseidonConfig`]
+//! constants) and the same per-class Domain-in-Rate lane tagging used natively
+//! in [`crate::hasher::MultiFieldHasher::update_base_field`], so a circuit can
+//! prove `digest(inputs) == expected` against a witness identical to the
+//! out-of-circuit digest. Only base-field ([`FieldInput::BaseField`]) absorption
+//! is mirrored; scalar/curve/primitive inputs are out of scope for this gadget
+//! and should be reduced to base field elements before absorption.
+//!
+//! Gated behind the `r1cs` feature, which pulls in `ark-r1cs-std`/`ark-relations`.
+
+use crate::ark_poseidon::ArkPoseidonConfig;
+use crate::hasher::derive_lane_constants;
+use ark_crypto_primitives::sponge::constraints::CryptographicSpongeVar;
+use ark_crypto_primitives::sponge::poseidon::constraints::PoseidonSpongeVar;
+use ark_ff::PrimeField;
+use ark_r1cs_std::fields::fp::FpVar;
+use ark_r1cs_std::prelude::*;
+use ark_relations::r1cs::{ConstraintSystemRef, SynthesisError};
+
+/// In-circuit counterpart of [`crate::hasher::MultiFieldHasher`]'s base-field
+/// absorption path.
+///
+/// Reproduces the same per-lane `"DIR|BASE"` tweak the native hasher adds
+/// before absorbing base-field elements, so `digest()` on this gadget and
+/// `PallasHasher::digest()` agree on the same sequence of [`ark_pallas::Fq`]
+/// inputs.
+pub struct PoseidonHasherGadget<F: PrimeField> {
+    sponge: PoseidonSpongeVar<F>,
+    rate: usize,
+    lane_cursor: usize,
+    base_lane_consts: Vec<F>,
+}
+
+impl<F: PrimeField> PoseidonHasherGadget<F> {
+    /// Create a new gadget over `cs`, using the same sponge parameters as the
+    /// native hasher it must agree with.
+    pub fn new(cs: ConstraintSystemRef<F>, params: &ArkPoseidonConfig<F>) -> Self {
+        let rate = params.rate;
+        let base_lane_consts = derive_lane_constants::<F>("DIR|BASE", rate)[..rate].to_vec();
+        Self {
+            sponge: PoseidonSpongeVar::new(cs, params),
+            rate,
+            lane_cursor: 0,
+            base_lane_consts,
+        }
+    }
+
+    /// Absorb a base-field witness, applying the same Domain-in-Rate lane
+    /// tweak the native hasher applies to [`crate::hasher::FieldInput::BaseField`].
+    pub fn update_base_field(&mut self, element: &FpVar<F>) -> Result<(), SynthesisError> {
+        let lane = self.lane_cursor % self.rate;
+        let tweak = FpVar::constant(self.base_lane_consts[lane]);
+        let tagged = element + tweak;
+        self.sponge.absorb(&tagged)?;
+        self.lane_cursor = (self.lane_cursor + 1) % self.rate;
+        Ok(())
+    }
+
+    /// Squeeze the final digest, matching the native hasher's single-element
+    /// `squeeze_native_field_elements(1)`.
+    pub fn digest(&mut self) -> Result<FpVar<F>, SynthesisError> {
+        let squeezed = self.sponge.squeeze_field_elements(1)?;
+        Ok(squeezed[0].clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hasher::MultiFieldHasherV1;
+    use ark_r1cs_std::alloc::AllocVar;
+    use ark_relations::r1cs::ConstraintSystem;
+
+    #[test]
+    fn test_in_circuit_digest_matches_native() {
+        type F = ark_pallas::Fq;
+
+        let params =
+            crate::parameters::clone_parameters(&*crate::parameters::pallas::PALLAS_PARAMS);
+
+        let inputs = [F::from(1u64), F::from(2u64), F::from(3u64)];
+
+        // Native digest.
+        let mut native: MultiFieldHasherV1<F, ark_pallas::Fr, ark_pallas::Affine> =
+            MultiFieldHasherV1::new_from_ref(&params);
+        for &x in &inputs {
+            native.update_base_field(x);
+        }
+        let expected = native.digest();
+
+        // In-circuit digest.
+        let cs = ConstraintSystem::<F>::new_ref();
+        let mut gadget = PoseidonHasherGadget::new(cs.clone(), &params);
+        for &x in &inputs {
+            let var = FpVar::new_witness(cs.clone(), || Ok(x)).unwrap();
+            gadget.update_base_field(&var).unwrap();
+        }
+        let digest_var = gadget.digest().unwrap();
+
+        assert_eq!(digest_var.value().unwrap(), expected);
+        assert!(cs.is_satisfied().unwrap());
+    }
+
+    /// Hashes `inputs` both natively and in the mock prover under `params`,
+    /// and asserts the two digests — and the constraint system — agree.
+    /// Shared by the per-curve `test_poseidon_compatibility_*` tests below.
+    fn assert_native_matches_in_circuit<F, S, G>(params: &ArkPoseidonConfig<F>, inputs: &[F])
+    where
+        F: PrimeField,
+        G: ark_ec::AffineRepr,
+    {
+        let mut native: MultiFieldHasherV1<F, S, G> = MultiFieldHasherV1::new_from_ref(params);
+        for &x in inputs {
+            native.update_base_field(x);
+        }
+        let expected = native.digest();
+
+        let cs = ConstraintSystem::<F>::new_ref();
+        let mut gadget = PoseidonHasherGadget::new(cs.clone(), params);
+        for &x in inputs {
+            let var = FpVar::new_witness(cs.clone(), || Ok(x)).unwrap();
+            gadget.update_base_field(&var).unwrap();
+        }
+        let digest_var = gadget.digest().unwrap();
+
+        assert_eq!(digest_var.value().unwrap(), expected);
+        assert!(cs.is_satisfied().unwrap());
+    }
+
+    /// Cross-curve compatibility sweep: for every curve this crate ships
+    /// embedded parameters for, the in-circuit gadget's digest must match
+    /// `PallasHasher`/`BN254Hasher`/`BLS12_381Hasher`/`BLS12_377Hasher`/
+    /// `VestaHasher`'s native base-field absorption byte-for-byte (mirrored
+    /// here via the same `MultiFieldHasherV1::update_base_field`/`digest`
+    /// path those hashers delegate to).
+    #[test]
+    fn test_poseidon_compatibility_pallas() {
+        let params =
+            crate::parameters::clone_parameters(&*crate::parameters::pallas::PALLAS_PARAMS);
+        let inputs = [
+            ark_pallas::Fq::from(1u64),
+            ark_pallas::Fq::from(2u64),
+            ark_pallas::Fq::from(3u64),
+        ];
+        assert_native_matches_in_circuit::<ark_pallas::Fq, ark_pallas::Fr, ark_pallas::Affine>(
+            &params, &inputs,
+        );
+    }
+
+    #[test]
+    fn test_poseidon_compatibility_bn254() {
+        let params = crate::parameters::clone_parameters(&*crate::parameters::bn254::BN254_PARAMS);
+        let inputs = [
+            ark_bn254::Fq::from(1u64),
+            ark_bn254::Fq::from(2u64),
+            ark_bn254::Fq::from(3u64),
+        ];
+        assert_native_matches_in_circuit::<ark_bn254::Fq, ark_bn254::Fr, ark_bn254::G1Affine>(
+            &params, &inputs,
+        );
+    }
+
+    #[test]
+    fn test_poseidon_compatibility_bls12_381() {
+        let params =
+            crate::parameters::clone_parameters(&*crate::parameters::bls12_381::BLS12_381_PARAMS);
+        let inputs = [
+            ark_bls12_381::Fq::from(1u64),
+            ark_bls12_381::Fq::from(2u64),
+            ark_bls12_381::Fq::from(3u64),
+        ];
+        assert_native_matches_in_circuit::<
+            ark_bls12_381::Fq,
+            ark_bls12_381::Fr,
+            ark_bls12_381::G1Affine,
+        >(&params, &inputs);
+    }
+
+    #[test]
+    fn test_poseidon_compatibility_bls12_377() {
+        let params =
+            crate::parameters::clone_parameters(&*crate::parameters::bls12_377::BLS12_377_PARAMS);
+        let inputs = [
+            ark_bls12_377::Fq::from(1u64),
+            ark_bls12_377::Fq::from(2u64),
+            ark_bls12_377::Fq::from(3u64),
+        ];
+        assert_native_matches_in_circuit::<
+            ark_bls12_377::Fq,
+            ark_bls12_377::Fr,
+            ark_bls12_377::G1Affine,
+        >(&params, &inputs);
+    }
+
+    #[test]
+    fn test_poseidon_compatibility_vesta() {
+        let params = crate::parameters::clone_parameters(&*crate::parameters::vesta::VESTA_PARAMS);
+        let inputs = [
+            ark_vesta::Fq::from(1u64),
+            ark_vesta::Fq::from(2u64),
+            ark_vesta::Fq::from(3u64),
+        ];
+        assert_native_matches_in_circuit::<ark_vesta::Fq, ark_vesta::Fr, ark_vesta::Affine>(
+            &params, &inputs,
+        );
+    }
+}
@@ -0,0 +1,183 @@
+//! Grain LFSR, used to deterministically derive Poseidon/Poseidon2 round
+//! constants and the Poseidon2 internal diagonal `mu` from a field/width/round
+//! description, following the generation procedure from the Poseidon reference
+//! scripts (<https://extgit.iaik.tugraz.at/krypto/hadeshash>).
+//!
+//! This is a from-scratch, crate-local implementation (not re-exported), used
+//! by [`crate::poseidon2::find_poseidon2_ark_and_mu`] and the dynamic parameter
+//! constructors in [`crate::parameters`].
+
+use ark_ff::{BigInteger, PrimeField};
+
+/// Grain-128 style LFSR seeded from the Poseidon parameter description
+/// (field size, state width, number of full/partial rounds).
+pub struct PoseidonGrainLFSR {
+    /// Bit-width of the field modulus.
+    pub prime_num_bits: u64,
+    /// Internal 80-bit shift register state.
+    pub state: [bool; 80],
+    /// Read cursor (kept for clarity; the register is shifted, not indexed).
+    pub head: usize,
+}
+
+impl PoseidonGrainLFSR {
+    /// Initialize and warm up the LFSR from the Poseidon parameter description.
+    pub fn new(
+        is_sbox_an_inverse: bool,
+        prime_num_bits: u64,
+        state_len: u64,
+        num_full_rounds: u64,
+        num_partial_rounds: u64,
+    ) -> Self {
+        let mut state = [false; 80];
+
+        // b0, b1: field type (prime field = 0b01)
+        state[0] = false;
+        state[1] = true;
+
+        // b2..b6: S-box descriptor
+        if is_sbox_an_inverse {
+            state[2] = true;
+            state[3] = false;
+            state[4] = false;
+            state[5] = true;
+            state[6] = true;
+        } else {
+            state[2] = false;
+            state[3] = false;
+            state[4] = true;
+            state[5] = false;
+            state[6] = true;
+        }
+
+        Self::write_bits_be(&mut state, 7, 12, prime_num_bits);
+        Self::write_bits_be(&mut state, 19, 12, state_len);
+        Self::write_bits_be(&mut state, 31, 10, num_full_rounds);
+        Self::write_bits_be(&mut state, 41, 10, num_partial_rounds);
+
+        // Remaining bits are padded with ones.
+        for bit in state.iter_mut().skip(51) {
+            *bit = true;
+        }
+
+        let mut lfsr = Self {
+            prime_num_bits,
+            state,
+            head: 0,
+        };
+        // Discard the first 160 output bits, as specified by the reference
+        // construction, before any constants are drawn from the stream.
+        for _ in 0..160 {
+            lfsr.next_bit();
+        }
+        lfsr
+    }
+
+    fn write_bits_be(state: &mut [bool; 80], offset: usize, width: usize, value: u64) {
+        for i in 0..width {
+            state[offset + i] = (value >> (width - 1 - i)) & 1 == 1;
+        }
+    }
+
+    /// Advance the register by one bit (Grain-style feedback) and return the
+    /// bit shifted out.
+    fn next_bit(&mut self) -> bool {
+        let new_bit = self.state[0]
+            ^ self.state[13]
+            ^ self.state[23]
+            ^ self.state[38]
+            ^ self.state[51]
+            ^ self.state[62];
+        for i in 0..79 {
+            self.state[i] = self.state[i + 1];
+        }
+        self.state[79] = new_bit;
+        new_bit
+    }
+
+    /// Draw `num_bits` output bits, using the standard "every other output bit
+    /// counts" Grain decimation (two raw bits are consumed per output bit; the
+    /// first is discarded).
+    pub fn get_bits(&mut self, num_bits: usize) -> Vec<bool> {
+        let mut bits = Vec::with_capacity(num_bits);
+        while bits.len() < num_bits {
+            let _ = self.next_bit();
+            let bit = self.next_bit();
+            bits.push(bit);
+        }
+        bits
+    }
+
+    /// Draw `num_elems` field elements, rejecting any bit-string that would
+    /// exceed the field modulus (rejection sampling on the raw bit-width).
+    pub fn get_field_elements_rejection_sampling<F: PrimeField>(
+        &mut self,
+        num_elems: usize,
+    ) -> Vec<F> {
+        let mut result = Vec::with_capacity(num_elems);
+        while result.len() < num_elems {
+            let bits = self.get_bits(self.prime_num_bits as usize);
+            if let Some(elem) = Self::bits_to_field_canonical::<F>(&bits) {
+                result.push(elem);
+            }
+        }
+        result
+    }
+
+    /// Draw `num_elems` field elements by reducing each raw bit-string modulo
+    /// the field order (used where canonical/uniform sampling is not required,
+    /// e.g. the Poseidon2 internal diagonal `mu`).
+    pub fn get_field_elements_mod_p<F: PrimeField>(&mut self, num_elems: usize) -> Vec<F> {
+        let mut result = Vec::with_capacity(num_elems);
+        for _ in 0..num_elems {
+            let bits = self.get_bits(self.prime_num_bits as usize);
+            let bytes = Self::bits_to_le_bytes(&bits);
+            result.push(F::from_le_bytes_mod_order(&bytes));
+        }
+        result
+    }
+
+    fn bits_to_le_bytes(bits: &[bool]) -> Vec<u8> {
+        let mut bytes = vec![0u8; bits.len().div_ceil(8)];
+        for (i, bit) in bits.iter().enumerate() {
+            if *bit {
+                bytes[i / 8] |= 1 << (i % 8);
+            }
+        }
+        bytes
+    }
+
+    fn bits_to_field_canonical<F: PrimeField>(bits: &[bool]) -> Option<F> {
+        let bytes = Self::bits_to_le_bytes(bits);
+        let big = F::BigInt::from_bits_le(
+            &bytes
+                .iter()
+                .flat_map(|b| (0..8).map(move |i| (b >> i) & 1 == 1))
+                .collect::<Vec<_>>(),
+        );
+        F::from_bigint(big)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deterministic_output() {
+        let mut a = PoseidonGrainLFSR::new(false, 255, 3, 8, 56);
+        let mut b = PoseidonGrainLFSR::new(false, 255, 3, 8, 56);
+        let e1 = a.get_field_elements_rejection_sampling::<ark_pallas::Fq>(4);
+        let e2 = b.get_field_elements_rejection_sampling::<ark_pallas::Fq>(4);
+        assert_eq!(e1, e2);
+    }
+
+    #[test]
+    fn test_different_parameters_diverge() {
+        let mut a = PoseidonGrainLFSR::new(false, 255, 3, 8, 56);
+        let mut b = PoseidonGrainLFSR::new(false, 255, 4, 8, 56);
+        let e1 = a.get_field_elements_rejection_sampling::<ark_pallas::Fq>(4);
+        let e2 = b.get_field_elements_rejection_sampling::<ark_pallas::Fq>(4);
+        assert_ne!(e1, e2);
+    }
+}
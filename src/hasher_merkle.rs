@@ -0,0 +1,258 @@
+//! Merkle tree layered on the [`PoseidonHasher`] streaming API, rather than
+//! directly on a raw sponge/`ArkPoseidonConfig` as [`crate::tree`] and
+//! [`crate::merkle`] are.
+//!
+//! [`HasherMerkleTree::digest`] first folds the leaf slice down by streaming
+//! each fixed-size chunk through a fresh `H` (`update` every leaf in the
+//! chunk, then `digest`) into one element per chunk; [`HasherMerkleTree::new`]
+//! then pairwise-hashes those chunk digests up a binary tree to a single
+//! root. Unlike [`crate::merkle::MerkleTree`] (which promotes a lone trailing
+//! node unchanged) this pads an odd level with a fixed domain constant, so
+//! every level's folding is a real 2-to-1 hash.
+//!
+//! ```rust
+//! use poseidon_hash::hasher_merkle::PallasHasherMerkleTree;
+//!
+//! let leaves = vec![ark_pallas::Fq::from(1u64), ark_pallas::Fq::from(2u64), ark_pallas::Fq::from(3u64)];
+//! let tree = PallasHasherMerkleTree::new(leaves.clone(), 1);
+//! let root = tree.root();
+//!
+//! let proof = tree.prove(1);
+//! assert!(tree.verify(root, leaves[1], &proof));
+//! ```
+
+use ark_ff::PrimeField;
+use std::marker::PhantomData;
+
+use crate::types::PoseidonHasher;
+
+/// Domain constant padding a level with an odd number of nodes, so the
+/// trailing node is always compressed with something rather than promoted
+/// unchanged (see the module docs for why that differs from
+/// [`crate::merkle::MerkleTree`]'s choice).
+fn pad_constant<F: PrimeField>() -> F {
+    F::from_le_bytes_mod_order(b"HASHER_MERKLE_TREE|PAD")
+}
+
+fn fold_chunk<F, I, H>(chunk: &[F]) -> F
+where
+    F: PrimeField + Into<I>,
+    H: PoseidonHasher<F, I>,
+{
+    let mut hasher = H::new();
+    for &leaf in chunk {
+        hasher.update(leaf);
+    }
+    hasher.digest()
+}
+
+fn compress2<F, I, H>(left: F, right: F) -> F
+where
+    F: PrimeField + Into<I>,
+    H: PoseidonHasher<F, I>,
+{
+    let mut hasher = H::new();
+    hasher.update(left);
+    hasher.update(right);
+    hasher.digest()
+}
+
+/// A membership proof for a [`HasherMerkleTree`]: the sibling path from the
+/// folded leaf up to the root, plus the leaf's index in the folded layer
+/// (which determines each step's left/right order).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MerkleProof<F> {
+    /// Index of the leaf's chunk in the folded (chunk-digest) layer.
+    pub chunk_index: usize,
+    /// Sibling hashes from the folded layer up to the root.
+    pub siblings: Vec<F>,
+}
+
+/// Merkle tree over an arbitrary-length, non-empty leaf `Vec`, built on top
+/// of a [`PoseidonHasher`] impl `H` rather than a raw sponge. See the module
+/// docs for the fold-then-merkelize construction.
+pub struct HasherMerkleTree<F, I, H> {
+    chunk_size: usize,
+    /// `layers[0]` is the folded chunk digests; `layers[last]` is `[root]`.
+    layers: Vec<Vec<F>>,
+    _marker: PhantomData<(I, H)>,
+}
+
+impl<F, I, H> HasherMerkleTree<F, I, H>
+where
+    F: PrimeField + Into<I>,
+    H: PoseidonHasher<F, I>,
+{
+    /// Fold `leaves` into one digest per `chunk_size`-sized chunk. The final
+    /// chunk may be shorter than `chunk_size` if `leaves.len()` doesn't
+    /// divide evenly.
+    pub fn digest(leaves: &[F], chunk_size: usize) -> Vec<F> {
+        assert!(chunk_size > 0, "chunk_size must be nonzero");
+        leaves
+            .chunks(chunk_size)
+            .map(fold_chunk::<F, I, H>)
+            .collect()
+    }
+
+    /// Fold `leaves` (via [`Self::digest`]) and merkelize the resulting
+    /// chunk digests into a binary tree.
+    ///
+    /// Panics if `leaves` is empty.
+    pub fn new(leaves: Vec<F>, chunk_size: usize) -> Self {
+        assert!(!leaves.is_empty(), "merkle tree needs at least one leaf");
+        let folded = Self::digest(&leaves, chunk_size);
+        let mut layers = vec![folded];
+        while layers[layers.len() - 1].len() > 1 {
+            let prev = layers.last().unwrap();
+            let mut next = Vec::with_capacity(prev.len().div_ceil(2));
+            let mut i = 0;
+            while i < prev.len() {
+                let left = prev[i];
+                let right = if i + 1 < prev.len() {
+                    prev[i + 1]
+                } else {
+                    pad_constant::<F>()
+                };
+                next.push(compress2::<F, I, H>(left, right));
+                i += 2;
+            }
+            layers.push(next);
+        }
+        Self {
+            chunk_size,
+            layers,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Chunk size leaves were folded with.
+    pub fn chunk_size(&self) -> usize {
+        self.chunk_size
+    }
+
+    /// Number of folded chunk digests (the tree's leaf-level width).
+    pub fn leaf_count(&self) -> usize {
+        self.layers[0].len()
+    }
+
+    /// The current Merkle root.
+    pub fn root(&self) -> F {
+        self.layers[self.layers.len() - 1][0]
+    }
+
+    /// Build a membership proof that the folded chunk digest at
+    /// `chunk_index` is included under [`Self::root`].
+    pub fn prove(&self, chunk_index: usize) -> MerkleProof<F> {
+        assert!(chunk_index < self.leaf_count(), "chunk index out of range");
+        let mut siblings = Vec::new();
+        let mut idx = chunk_index;
+        for level in &self.layers[..self.layers.len() - 1] {
+            let sibling_idx = idx ^ 1;
+            let sibling = if sibling_idx < level.len() {
+                level[sibling_idx]
+            } else {
+                pad_constant::<F>()
+            };
+            siblings.push(sibling);
+            idx /= 2;
+        }
+        MerkleProof {
+            chunk_index,
+            siblings,
+        }
+    }
+
+    /// Verify that the chunk digest of the one-leaf chunk `[leaf]` is included in `proof`
+    /// under `root`. Since `HasherMerkleTree` folds raw leaves into chunk
+    /// digests before merkelizing, callers proving a single raw leaf should
+    /// pass a `chunk_size` of `1` to [`Self::new`] so each "chunk" is one leaf.
+    pub fn verify(&self, root: F, leaf: F, proof: &MerkleProof<F>) -> bool {
+        let folded = fold_chunk::<F, I, H>(&[leaf]);
+        let mut current = folded;
+        let mut idx = proof.chunk_index;
+        for &sibling in &proof.siblings {
+            current = if idx % 2 == 0 {
+                compress2::<F, I, H>(current, sibling)
+            } else {
+                compress2::<F, I, H>(sibling, current)
+            };
+            idx /= 2;
+        }
+        current == root
+    }
+}
+
+/// [`HasherMerkleTree`] over [`crate::PallasHasher`].
+pub type PallasHasherMerkleTree =
+    HasherMerkleTree<ark_pallas::Fq, crate::types::PallasInput, crate::types::PallasHasher>;
+
+/// [`HasherMerkleTree`] over [`crate::BN254Hasher`].
+pub type BN254HasherMerkleTree =
+    HasherMerkleTree<ark_bn254::Fq, crate::types::BN254Input, crate::types::BN254Hasher>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn leaves(n: usize) -> Vec<ark_pallas::Fq> {
+        (0..n).map(|i| ark_pallas::Fq::from(i as u64)).collect()
+    }
+
+    #[test]
+    fn test_single_chunk_single_leaf_round_trip() {
+        let ls = leaves(1);
+        let tree = PallasHasherMerkleTree::new(ls.clone(), 1);
+        let root = tree.root();
+        let proof = tree.prove(0);
+        assert!(tree.verify(root, ls[0], &proof));
+    }
+
+    #[test]
+    fn test_chunk_size_one_round_trips_every_leaf() {
+        let ls = leaves(5);
+        let tree = PallasHasherMerkleTree::new(ls.clone(), 1);
+        let root = tree.root();
+        for (i, leaf) in ls.iter().enumerate() {
+            let proof = tree.prove(i);
+            assert!(tree.verify(root, *leaf, &proof));
+        }
+    }
+
+    #[test]
+    fn test_larger_chunk_size_folds_down_to_fewer_leaves() {
+        let ls = leaves(9);
+        let tree = PallasHasherMerkleTree::new(ls.clone(), 3);
+        assert_eq!(tree.leaf_count(), 3);
+    }
+
+    #[test]
+    fn test_tampered_leaf_fails_verification() {
+        let ls = leaves(4);
+        let tree = PallasHasherMerkleTree::new(ls.clone(), 1);
+        let root = tree.root();
+        let proof = tree.prove(2);
+        assert!(!tree.verify(root, ark_pallas::Fq::from(999u64), &proof));
+    }
+
+    #[test]
+    fn test_odd_width_folded_layer_pads_with_domain_constant() {
+        let ls = leaves(3);
+        let tree = PallasHasherMerkleTree::new(ls.clone(), 1);
+        let root = tree.root();
+        for (i, leaf) in ls.iter().enumerate() {
+            let proof = tree.prove(i);
+            assert!(tree.verify(root, *leaf, &proof));
+        }
+    }
+
+    #[test]
+    fn test_bn254_tree_round_trip() {
+        let ls: Vec<_> = (0..4u64).map(ark_bn254::Fq::from).collect();
+        let tree = BN254HasherMerkleTree::new(ls.clone(), 1);
+        let root = tree.root();
+        for (i, leaf) in ls.iter().enumerate() {
+            let proof = tree.prove(i);
+            assert!(tree.verify(root, *leaf, &proof));
+        }
+    }
+}
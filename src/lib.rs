@@ -69,24 +69,166 @@ bn254_hasher.update(ark_bn254::Fr::from(123u64));    // ✓ BN254 scalar
 // Re-export main types at crate root for convenience
 pub use hasher::{MultiFieldHasher, FieldInput, HasherError, HasherResult};
 pub use parameters::SECURITY_LEVEL;
-pub use primitive::{RustInput, PackingConfig, PackingMode, PaddingMode};
+pub use primitive::{FixLenBytes, PackingConfig, PackingMode, PaddingMode, RustInput, VarLenBytes};
 pub use types::PoseidonHasher;
 
 // Re-export curve-specific hashers and input types
 pub use types::{
     PallasHasher, PallasInput,
     BN254Hasher, BN254Input,
-    BLS12_381Hasher, BLS12_381Input,
+    BLS12_381Hasher, BLS12_381Input, BLS12_381FrHasher,
     BLS12_377Hasher, BLS12_377Input,
     VestaHasher, VestaInput,
 };
 
+// Re-export the permutation-backend-selectable hasher wrappers; see
+// `types::PoseidonPermutation` for why this needs a dedicated enum-dispatch
+// type rather than a flag on the curve hashers themselves.
+pub use types::{BN254AnyHasher, PallasAnyHasher, PoseidonPermutation};
+
+// Re-export the constant-time digest comparison helper.
+pub use ct_eq::ct_eq;
+
+// Re-export the RustCrypto `digest::Digest` adapters.
+#[cfg(feature = "digest")]
+pub use digest_adapter::{BN254Digest, PallasDigest};
+
+// Re-export Poseidon2 hashers (kept under their explicit-version names; see
+// `types::poseidon2`/`types::poseidon2_bn254` for the full submodule, e.g.
+// `PallasPoseidon2Variant`).
+pub use types::poseidon2::{PallasPoseidon2Compress, PallasPoseidon2CompressT3, PallasPoseidon2Hasher};
+pub use types::poseidon2_bn254::{BN254Poseidon2Compress, BN254Poseidon2CompressT3, BN254Poseidon2Hasher};
+pub use types::poseidon2_bls12_377::{BLS12_377Poseidon2Compress, BLS12_377Poseidon2CompressT3};
+pub use types::poseidon2_bls12_381::{BLS12_381Poseidon2Compress, BLS12_381Poseidon2CompressT3};
+pub use types::poseidon2_vesta::{VestaPoseidon2Compress, VestaPoseidon2CompressT3};
+
+// Re-export the typestate-enforced Poseidon2 sponge wrapper — the
+// recommended low-level entry point over the untyped `CryptographicSponge`
+// impl, since it makes absorbing after squeezing a compile error.
+pub use poseidon2::{Absorbing, Sponge, Squeezing};
+
+// Re-export the Noir `poseidon2.nr`-style variable-length streaming hash
+// (runtime length tag + `10*` padding); see `domain::hash` for the
+// compile-time-length equivalent.
+pub use poseidon2::streaming_hash;
+
+// Re-export the const-generic, allocation-free Poseidon2 permutation core.
+// See `poseidon2_spec` for the concrete `Spec` impls (`PallasSpecT2`, etc).
+pub use poseidon2_spec::{Permutation, Spec};
+
+// Re-export the domain-separated fixed-length hashing abstraction; see
+// `domain::hash` for the one-shot entry point.
+pub use domain::{ConstantLength, Domain};
+
+// Re-export the Poseidon2-compression-based Merkle tree; see `tree` for the
+// general streaming-hasher-based equivalent.
+pub use poseidon2_tree::{poseidon_merkle_root, Poseidon2MerkleTree};
+
+// Re-export the dynamic-size (non-power-of-arity) Merkle tree; unlike `tree`,
+// leaf counts need not be `arity^depth` (odd levels duplicate their last
+// node). Its own `MerkleProof` type is left under the module path since it
+// would otherwise collide with `tree::MerkleProof`'s re-export below.
+pub use poseidon_merkle_tree::{BN254MerkleTree, PallasMerkleTree};
+
+// Re-export the streaming reader/file hashing entry points.
+pub use streaming_io::{hash_file, hash_reader};
+
+// Re-export the Circom/light-poseidon-compatible fixed-width BN254 hash.
+pub use circom::poseidon_circom;
+
+// Re-export the hasher-layered Merkle tree; its own `MerkleProof` type is
+// left under the module path for the same reason as `poseidon_merkle_tree`'s.
+pub use hasher_merkle::{BN254HasherMerkleTree, PallasHasherMerkleTree};
+
+// Re-export the ergonomic digest output wrapper.
+pub use digest_output::PoseidonDigest;
+
+// Re-export the single-curve Poseidon2 Fiat–Shamir transcript; see
+// `transcript` for the dual-curve, Poseidon1-based equivalent used by the
+// existing accumulation-scheme integration.
+pub use poseidon2_transcript::Poseidon2Transcript;
+
+// Re-export the curve-hasher-generic Fiat–Shamir transcript; unlike either
+// transcript above, this one is generic over any `PoseidonHasher` impl.
+pub use poseidon_transcript::PoseidonTranscript;
+
 // Public modules
+// Fixed-arity batch hashing entry point backing `$Hasher::digest_batch`;
+// not a performance optimization today — see the module doc comment.
+pub mod batch_hash;
+// Circom/light-poseidon-compatible fixed-width BN254 hashing; see the
+// module doc comment for the parameter convention this interoperates with.
+pub mod circom;
+pub mod ct_eq;
+// RustCrypto `digest::Digest` adapter; see the module doc comment for why
+// this is feature-gated.
+#[cfg(feature = "digest")]
+pub mod digest_adapter;
+// Ergonomic digest output type; see `types::PoseidonHasher::digest_wrapped`
+// for the opt-in entry point into it.
+pub mod digest_output;
+pub mod domain;
+pub mod eddsa;
+pub mod encode;
+// Solidity code generation for on-chain Poseidon verification; see the
+// module doc comment for which hash this targets and why.
+#[cfg(feature = "evm")]
+pub mod evm;
+// `cdylib`/`staticlib` C-ABI entry points; see the module doc comment for
+// which curves are exposed and how field elements cross the boundary.
+#[cfg(feature = "ffi")]
+pub mod ffi;
+#[cfg(feature = "r1cs")]
+pub mod gadget;
 pub mod hasher;
+// Merkle tree layered on `PoseidonHasher` (fold-then-merkelize) rather than
+// directly on a raw sponge; see the module doc comment for how this differs
+// from `tree`/`merkle`/`poseidon_merkle_tree`.
+pub mod hasher_merkle;
+pub mod merkle;
+pub mod parallel_hash;
 pub mod parameters;
+#[cfg(feature = "r1cs")]
+pub mod poseidon2_gadget;
 pub mod primitive;
+pub mod simd_dispatch;
+pub mod std_hasher;
+pub mod streaming_io;
+pub mod transcript;
+pub mod tree;
 pub mod types;
-mod tags;
+mod ark_poseidon;
+mod grain_lfsr;
+mod poseidon2;
+// `pub` so callers can name a compile-time width/`Spec` impl directly (e.g.
+// `poseidon_hash::poseidon2_spec::PallasSpecT4`) alongside the root-level
+// `Spec`/`Permutation` re-export above.
+pub mod poseidon2_spec;
+pub mod poseidon2_sparse_tree;
+pub mod poseidon2_tree;
+pub mod poseidon2_transcript;
+pub mod poseidon_merkle_tree;
+pub mod poseidon_transcript;
+// `pub` so the generated code from `#[derive(PoseidonEncode)]` (in the
+// sibling `poseidon-derive` crate) can reference the structural tag
+// constants it needs.
+pub mod tags;
+
+// Re-export the Merkle tree subsystem for convenience
+pub use tree::{
+    verify, verify_path, BLS12_381Tree, BN254Tree, MerkleProof, PallasTree, Position, PoseidonTree,
+    VestaTree,
+};
+
+// Re-export the dual-curve transcript for folding/accumulation schemes
+pub use transcript::CycleTranscript;
+
+// Re-export the std::hash::Hasher bridge adapter
+pub use std_hasher::PoseidonStdHasher;
+
+// Re-export the canonical struct/enum encoding trait used by
+// `#[derive(PoseidonEncode)]` in the sibling `poseidon-derive` crate.
+pub use encode::PoseidonEncode;
 
 
 #[cfg(test)]
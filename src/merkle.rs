@@ -0,0 +1,262 @@
+//! Parallel, domain-separated binary Merkle tree over a leaf slice.
+//!
+//! Unlike [`crate::tree::PoseidonTree`], which is fixed-depth and requires
+//! exactly `arity^depth` leaves, [`MerkleTree::new`] builds directly from an
+//! arbitrary, non-empty leaf `Vec` of any length. A level with an odd number
+//! of nodes promotes its lone trailing node unchanged into the next level
+//! rather than duplicating it (as some Merkle constructions do to force an
+//! even count) — duplication would let an attacker pad the leaf set to make
+//! an unbalanced subtree's hash collide with a balanced one, so leaving the
+//! lone node unpaired avoids that ambiguity at the cost of a slightly
+//! asymmetric tree shape. Node hashing reuses the same domain-separated
+//! 2-to-1 compression idea as [`crate::tree`]: a tag derived from the node's
+//! level is absorbed ahead of its two children, so a node hash at one level
+//! can never be replayed as a node hash at another.
+//!
+//! With the `parallel` feature enabled, each level's node hashes are
+//! computed independently across threads via `rayon` (the same per-level
+//! `par_chunks` strategy [`crate::tree::PoseidonTree::new_from_leaves`]
+//! uses) — in spirit this is BLAKE3's independent-subtree hashing, just
+//! applied per tree level rather than via an explicit subtree-recursion
+//! step; the resulting root and proofs are identical either way, since the
+//! split is a performance detail, not a domain separator. Without the
+//! feature, the same chunking runs sequentially.
+//!
+//! ```rust
+//! use poseidon_hash::merkle::PallasMerkleTree;
+//!
+//! let leaves = vec![ark_pallas::Fq::from(1u64), ark_pallas::Fq::from(2u64), ark_pallas::Fq::from(3u64)];
+//! let tree = PallasMerkleTree::from_leaves(leaves.clone());
+//! let root = tree.root();
+//!
+//! let proof = tree.proof(1);
+//! assert!(proof.verify(tree.parameters(), leaves[1], root));
+//! ```
+
+use crate::ark_poseidon::ArkPoseidonConfig;
+use ark_crypto_primitives::sponge::{Absorb, CryptographicSponge};
+use ark_ff::PrimeField;
+
+/// Domain tag absorbed before a node's two children, separating this
+/// module's node hashing from [`crate::tree`]'s (and from node hashing at
+/// other levels — `level` counts up from the leaves).
+fn node_domain_tag<F: PrimeField>(level: usize) -> F {
+    F::from_le_bytes_mod_order(format!("POSEIDON_MERKLE|NODE|{}", level).as_bytes())
+}
+
+/// Domain-separated 2-to-1 compression of `left`/`right` at tree `level`.
+fn compress2<F: PrimeField + Absorb>(params: &ArkPoseidonConfig<F>, level: usize, left: F, right: F) -> F {
+    let mut sponge = crate::ark_poseidon::ArkPoseidonSponge::new(params);
+    sponge.absorb(&node_domain_tag::<F>(level));
+    sponge.absorb(&left);
+    sponge.absorb(&right);
+    sponge.squeeze_native_field_elements(1)[0]
+}
+
+/// One step of a [`MerkleProof`] path: the sibling hash at that level and
+/// which side of the pair it sits on (`is_left = true` means the sibling is
+/// the *left* child, i.e. the node being proven is the right child).
+///
+/// Also carries the absolute tree `level` the step was computed at
+/// (hidden — not part of the `(sibling, is_left)` pair the proof format
+/// exposes), since odd-node promotions can skip a level without consuming a
+/// proof step; without it, a verifier re-deriving the domain tag from the
+/// step's position in the path alone could tag it at the wrong level.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProofStep<F> {
+    /// The sibling hash at this level.
+    pub sibling: F,
+    /// Whether `sibling` is the left child (the proven node is the right one).
+    pub is_left: bool,
+    level: usize,
+}
+
+/// A Merkle inclusion proof for a [`MerkleTree`]: an ordered list of
+/// `(sibling, is_left)` steps from the leaf up to the root.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MerkleProof<F> {
+    /// Index of the leaf this proof is for.
+    pub leaf_index: usize,
+    /// Sibling path from the leaf level up to the root.
+    pub path: Vec<ProofStep<F>>,
+}
+
+impl<F: PrimeField + Absorb> MerkleProof<F> {
+    /// Verify that `leaf` is included at `self.leaf_index` under `root`,
+    /// using the same Poseidon `params` the tree was built with.
+    pub fn verify(&self, params: &ArkPoseidonConfig<F>, leaf: F, root: F) -> bool {
+        let mut current = leaf;
+        for step in &self.path {
+            current = if step.is_left {
+                compress2(params, step.level, step.sibling, current)
+            } else {
+                compress2(params, step.level, current, step.sibling)
+            };
+        }
+        current == root
+    }
+}
+
+/// Binary Merkle tree over an arbitrary-length, non-empty leaf `Vec`, with
+/// domain-separated Poseidon 2-to-1 node compression. See the module docs
+/// for the odd-node-promotion rule and parallel construction strategy.
+pub struct MerkleTree<F: PrimeField + Absorb> {
+    params: ArkPoseidonConfig<F>,
+    /// `layers[0]` is the leaves; `layers[last]` is `[root]`.
+    layers: Vec<Vec<F>>,
+}
+
+impl<F: PrimeField + Absorb> MerkleTree<F> {
+    /// Build a tree from `leaves` (in order, left to right) and `params`.
+    ///
+    /// Panics if `leaves` is empty.
+    pub fn new(leaves: Vec<F>, params: ArkPoseidonConfig<F>) -> Self {
+        assert!(!leaves.is_empty(), "merkle tree needs at least one leaf");
+        let mut layers = vec![leaves];
+        let mut level = 0;
+        while layers[level].len() > 1 {
+            let prev = &layers[level];
+            let m = prev.len();
+            let pairs = m / 2;
+            #[cfg(feature = "parallel")]
+            let mut next: Vec<F> = {
+                use rayon::prelude::*;
+                prev[..pairs * 2]
+                    .par_chunks(2)
+                    .map(|pair| compress2(&params, level, pair[0], pair[1]))
+                    .collect()
+            };
+            #[cfg(not(feature = "parallel"))]
+            let mut next: Vec<F> = prev[..pairs * 2]
+                .chunks(2)
+                .map(|pair| compress2(&params, level, pair[0], pair[1]))
+                .collect();
+            if m % 2 == 1 {
+                // Lone trailing node: promote unchanged rather than
+                // duplicating it (see module docs for the rationale).
+                next.push(prev[m - 1]);
+            }
+            layers.push(next);
+            level += 1;
+        }
+        Self { params, layers }
+    }
+
+    /// The Poseidon parameters this tree was built with.
+    pub fn parameters(&self) -> &ArkPoseidonConfig<F> {
+        &self.params
+    }
+
+    /// Number of leaves this tree was built from.
+    pub fn leaf_count(&self) -> usize {
+        self.layers[0].len()
+    }
+
+    /// The current Merkle root.
+    pub fn root(&self) -> F {
+        self.layers[self.layers.len() - 1][0]
+    }
+
+    /// Build a membership proof for the leaf at `index`.
+    pub fn proof(&self, index: usize) -> MerkleProof<F> {
+        assert!(index < self.leaf_count(), "leaf index out of range");
+        let mut path = Vec::new();
+        let mut idx = index;
+        for level in 0..self.layers.len() - 1 {
+            let m = self.layers[level].len();
+            if m % 2 == 1 && idx == m - 1 {
+                // This node was promoted unchanged; no sibling to record.
+                idx = m / 2;
+                continue;
+            }
+            let sibling_idx = idx ^ 1;
+            let is_left = idx % 2 == 1;
+            path.push(ProofStep {
+                sibling: self.layers[level][sibling_idx],
+                is_left,
+                level,
+            });
+            idx /= 2;
+        }
+        MerkleProof {
+            leaf_index: index,
+            path,
+        }
+    }
+}
+
+/// Poseidon Merkle tree over the Pallas base field, built from an
+/// arbitrary-length leaf `Vec`. See [`MerkleTree`] for the general,
+/// field-generic version.
+pub type PallasMerkleTree = MerkleTree<ark_pallas::Fq>;
+
+impl PallasMerkleTree {
+    /// Build a Pallas tree from `leaves`, using the crate's embedded Pallas
+    /// Poseidon parameters.
+    pub fn from_leaves(leaves: Vec<ark_pallas::Fq>) -> Self {
+        MerkleTree::new(
+            leaves,
+            crate::parameters::clone_parameters(&*crate::parameters::pallas::PALLAS_PARAMS),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_ff::Zero;
+
+    fn leaves(n: usize) -> Vec<ark_pallas::Fq> {
+        (0..n).map(|i| ark_pallas::Fq::from(i as u64)).collect()
+    }
+
+    #[test]
+    fn single_leaf_tree_root_is_the_leaf_itself() {
+        let tree = PallasMerkleTree::from_leaves(vec![ark_pallas::Fq::from(7u64)]);
+        assert_eq!(tree.root(), ark_pallas::Fq::from(7u64));
+        let proof = tree.proof(0);
+        assert!(proof.path.is_empty());
+        assert!(proof.verify(tree.parameters(), ark_pallas::Fq::from(7u64), tree.root()));
+    }
+
+    #[test]
+    fn proofs_round_trip_for_every_leaf_in_an_even_sized_tree() {
+        let ls = leaves(8);
+        let tree = PallasMerkleTree::from_leaves(ls.clone());
+        let root = tree.root();
+        for (i, leaf) in ls.iter().enumerate() {
+            let proof = tree.proof(i);
+            assert_eq!(proof.leaf_index, i);
+            assert!(proof.verify(tree.parameters(), *leaf, root));
+        }
+    }
+
+    #[test]
+    fn proofs_round_trip_for_every_leaf_in_an_odd_sized_tree() {
+        let ls = leaves(5);
+        let tree = PallasMerkleTree::from_leaves(ls.clone());
+        let root = tree.root();
+        for (i, leaf) in ls.iter().enumerate() {
+            let proof = tree.proof(i);
+            assert!(proof.verify(tree.parameters(), *leaf, root));
+        }
+    }
+
+    #[test]
+    fn a_wrong_leaf_fails_verification() {
+        let ls = leaves(6);
+        let tree = PallasMerkleTree::from_leaves(ls);
+        let proof = tree.proof(2);
+        assert!(!proof.verify(tree.parameters(), ark_pallas::Fq::zero(), tree.root()));
+    }
+
+    #[test]
+    fn odd_promotion_does_not_duplicate_the_lone_node() {
+        // 3 leaves: level 0 has [l0, l1, l2] (odd); l2 is promoted unchanged
+        // rather than compressed with a duplicate of itself.
+        let ls = leaves(3);
+        let tree = PallasMerkleTree::from_leaves(ls.clone());
+        assert_eq!(tree.layers[1].len(), 2);
+        assert_eq!(tree.layers[1][1], ls[2]);
+    }
+}
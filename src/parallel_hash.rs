@@ -0,0 +1,131 @@
+//! Opt-in parallel byte-stream hashing, behind the `parallel` feature.
+//!
+//! [`digest_parallel`] splits `data` into [`PackingConfig::parallel_chunk_bytes`]-sized
+//! chunks, hashes each chunk to one field element independently (across
+//! threads when the `parallel` feature is enabled, matching
+//! [`crate::merkle::MerkleTree`]'s per-level `rayon` strategy), then combines
+//! the per-chunk digests through a balanced binary reduction using the same
+//! hasher. The reduction pads an odd level with a fixed domain constant
+//! (rather than promoting a lone node, as [`crate::merkle`] does), so the
+//! tree shape — and therefore the result — is identical regardless of how
+//! many threads ran it.
+
+use ark_ff::PrimeField;
+
+use crate::primitive::PackingConfig;
+use crate::types::PoseidonHasher;
+
+/// Domain constant padding an odd reduction level, distinct from
+/// [`crate::hasher_merkle`]'s pad tag so the two reduction trees never
+/// collide.
+fn pad_constant<F: PrimeField>() -> F {
+    F::from_le_bytes_mod_order(b"PARALLEL_DIGEST|PAD")
+}
+
+fn hash_chunk<F, I, H>(chunk: &[u8], config: PackingConfig) -> F
+where
+    F: PrimeField,
+    H: PoseidonHasher<F, I>,
+{
+    let mut hasher = H::new_with_config(config);
+    hasher.update(chunk);
+    hasher.digest()
+}
+
+fn compress2<F, I, H>(left: F, right: F) -> F
+where
+    F: PrimeField + Into<I>,
+    H: PoseidonHasher<F, I>,
+{
+    let mut hasher = H::new();
+    hasher.update(left);
+    hasher.update(right);
+    hasher.digest()
+}
+
+/// Hash `data` by chunking it into `config.parallel_chunk_bytes`-sized
+/// pieces, hashing each chunk independently, and reducing the per-chunk
+/// digests through a balanced, shape-deterministic binary tree.
+pub fn digest_parallel<F, I, H>(data: &[u8], config: PackingConfig) -> F
+where
+    F: PrimeField + Into<I>,
+    H: PoseidonHasher<F, I>,
+{
+    let chunk_size = config.parallel_chunk_bytes.max(1);
+    if data.is_empty() {
+        return hash_chunk::<F, I, H>(&[], config);
+    }
+
+    #[cfg(feature = "parallel")]
+    let mut level: Vec<F> = {
+        use rayon::prelude::*;
+        data.par_chunks(chunk_size)
+            .map(|chunk| hash_chunk::<F, I, H>(chunk, config))
+            .collect()
+    };
+    #[cfg(not(feature = "parallel"))]
+    let mut level: Vec<F> = data
+        .chunks(chunk_size)
+        .map(|chunk| hash_chunk::<F, I, H>(chunk, config))
+        .collect();
+
+    while level.len() > 1 {
+        let mut next = Vec::with_capacity(level.len().div_ceil(2));
+        let mut i = 0;
+        while i < level.len() {
+            let left = level[i];
+            let right = if i + 1 < level.len() {
+                level[i + 1]
+            } else {
+                pad_constant::<F>()
+            };
+            next.push(compress2::<F, I, H>(left, right));
+            i += 2;
+        }
+        level = next;
+    }
+    level[0]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{PallasHasher, PallasInput};
+
+    #[test]
+    fn test_deterministic_regardless_of_chunk_count() {
+        let data = vec![0x42u8; 10_000];
+        let small_chunks = PackingConfig {
+            parallel_chunk_bytes: 100,
+            ..Default::default()
+        };
+        let large_chunks = PackingConfig {
+            parallel_chunk_bytes: 10_000,
+            ..Default::default()
+        };
+        let a = digest_parallel::<ark_pallas::Fq, PallasInput, PallasHasher>(&data, small_chunks);
+        let b = digest_parallel::<ark_pallas::Fq, PallasInput, PallasHasher>(&data, large_chunks);
+        // Different chunking shapes the reduction tree differently, so the
+        // roots differ — but each must be internally deterministic (the
+        // tree-shape-determinism guarantee is about repeated runs, not
+        // different `parallel_chunk_bytes` choices).
+        let a2 = digest_parallel::<ark_pallas::Fq, PallasInput, PallasHasher>(&data, small_chunks);
+        let b2 = digest_parallel::<ark_pallas::Fq, PallasInput, PallasHasher>(&data, large_chunks);
+        assert_eq!(a, a2);
+        assert_eq!(b, b2);
+    }
+
+    #[test]
+    fn test_different_data_hashes_differently() {
+        let config = PackingConfig::default();
+        let a = digest_parallel::<ark_pallas::Fq, PallasInput, PallasHasher>(b"hello", config);
+        let b = digest_parallel::<ark_pallas::Fq, PallasInput, PallasHasher>(b"world", config);
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    fn test_empty_input_does_not_panic() {
+        let config = PackingConfig::default();
+        let _ = digest_parallel::<ark_pallas::Fq, PallasInput, PallasHasher>(b"", config);
+    }
+}
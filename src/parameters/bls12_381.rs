@@ -16,6 +16,28 @@ lazy_static! {
     };
 }
 
+/// Round counts for the scalar-field (`Fr`) parameter set below — same
+/// `t=3, α=5, M=128` choice as [`FULL_ROUNDS`]/[`PARTIAL_ROUNDS`]; this
+/// crate picks round counts per `(t, security level)` rather than per field
+/// bit-size (see [`PALLAS_PARAMS`](crate::parameters::pallas::PALLAS_PARAMS)
+/// and [`BN254_PARAMS`](crate::parameters::bn254::BN254_PARAMS), both ~254-bit
+/// fields using the identical 8/56 split).
+pub const FR_FULL_ROUNDS: usize = 8;
+pub const FR_PARTIAL_ROUNDS: usize = 56;
+
+lazy_static! {
+    /// BLS12-381 Poseidon parameters over the *scalar* field `Fr`, as opposed
+    /// to [`BLS12_381_PARAMS`] over the base field `Fq` — the field proof
+    /// witnesses and signature scalars (e.g. `blst`-backed BLS aggregation)
+    /// are natively expressed in. See [`crate::types::BLS12_381FrHasher`] for
+    /// the hasher built on these parameters.
+    pub static ref BLS12_381_FR_PARAMS: ArkPoseidonConfig<ark_bls12_381::Fr> = {
+        crate::parameters::create_dynamic_parameters::<ark_bls12_381::Fr>(
+            3, FR_FULL_ROUNDS, FR_PARTIAL_ROUNDS, 1,
+        )
+    };
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -27,5 +49,14 @@ mod tests {
         assert_eq!(params.rate + params.capacity, 3);
         assert_eq!(params.alpha, 5);
     }
+
+    #[test]
+    fn test_bls12_381_fr_params_load() {
+        let params = &*BLS12_381_FR_PARAMS;
+        assert_eq!(params.full_rounds, FR_FULL_ROUNDS);
+        assert_eq!(params.partial_rounds, FR_PARTIAL_ROUNDS);
+        assert_eq!(params.rate + params.capacity, 3);
+        assert_eq!(params.alpha, 5);
+    }
 }
 
@@ -0,0 +1,86 @@
+//! Circom/light-poseidon–compatible BN254 Poseidon parameters, t = 2..=13.
+//!
+//! The embedded `bn254` module only covers the crate's own t=3 convention.
+//! Circom circuits and the Solana `sol_poseidon` syscall instead use the
+//! light-poseidon round-count table below (8 full rounds, x^5 S-box, and a
+//! partial-round count that depends on the state width `t`); see
+//! [`crate::circom`]'s module doc for how far that compatibility claim
+//! currently extends. Indexed by `t - 2` (`t` ranges 2..=13, i.e. 1..=12
+//! absorbed inputs).
+
+use crate::ark_poseidon::ArkPoseidonConfig;
+use lazy_static::lazy_static;
+
+/// Number of full rounds for every width in the light-poseidon convention.
+pub const FULL_ROUNDS: usize = 8;
+
+/// Partial-round count per width, indexed by `t - 2` for `t` in `2..=13`.
+pub const PARTIAL_ROUNDS: [usize; 12] = [56, 57, 56, 60, 60, 63, 64, 63, 60, 66, 60, 65];
+
+/// Maximum number of field elements [`crate::circom::poseidon_circom`] can
+/// absorb in one permutation (`t = 13`, capacity = 1).
+pub const MAX_INPUTS: usize = 12;
+
+fn build_params(t: usize) -> ArkPoseidonConfig<ark_bn254::Fq> {
+    let partial_rounds = PARTIAL_ROUNDS[t - 2];
+    crate::parameters::create_dynamic_parameters::<ark_bn254::Fq>(t, FULL_ROUNDS, partial_rounds, 1)
+}
+
+lazy_static! {
+    /// `BN254_CIRCOM_PARAMS[n - 1]` holds the `t = n + 1` parameter set for
+    /// absorbing `n` inputs, `n` in `1..=12`.
+    pub static ref BN254_CIRCOM_PARAMS: [ArkPoseidonConfig<ark_bn254::Fq>; 12] = [
+        build_params(2),
+        build_params(3),
+        build_params(4),
+        build_params(5),
+        build_params(6),
+        build_params(7),
+        build_params(8),
+        build_params(9),
+        build_params(10),
+        build_params(11),
+        build_params(12),
+        build_params(13),
+    ];
+}
+
+/// Get a reference to the Circom-compatible BN254 parameters for absorbing
+/// `n` inputs (`t = n + 1`). Panics if `n` is `0` or greater than
+/// [`MAX_INPUTS`].
+pub fn circom_params_for(n: usize) -> &'static ArkPoseidonConfig<ark_bn254::Fq> {
+    assert!(
+        (1..=MAX_INPUTS).contains(&n),
+        "poseidon_circom supports 1..={MAX_INPUTS} inputs, got {n}"
+    );
+    &BN254_CIRCOM_PARAMS[n - 1]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_counts_match_light_poseidon_table() {
+        for n in 1..=MAX_INPUTS {
+            let params = circom_params_for(n);
+            assert_eq!(params.full_rounds, FULL_ROUNDS);
+            assert_eq!(params.partial_rounds, PARTIAL_ROUNDS[n - 1]);
+            assert_eq!(params.rate, n);
+            assert_eq!(params.capacity, 1);
+            assert_eq!(params.alpha, 5);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "supports 1..=12")]
+    fn test_zero_inputs_panics() {
+        circom_params_for(0);
+    }
+
+    #[test]
+    #[should_panic(expected = "supports 1..=12")]
+    fn test_too_many_inputs_panics() {
+        circom_params_for(MAX_INPUTS + 1);
+    }
+}
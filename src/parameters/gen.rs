@@ -0,0 +1,192 @@
+//! Deterministic Poseidon2 parameter generation from a label and seed, for
+//! curves/widths this crate does not ship embedded tables for.
+//!
+//! Round constants are drawn from a Blake2s XOF in counter mode —
+//! `digest_i = H(label || seed || i_le_bytes)` — and each digest is reduced
+//! into the field by rejection sampling: a draw `>= p` is discarded and the
+//! counter advanced to re-hash, so the result stays uniform over `F` rather
+//! than picking up modular bias. This mirrors
+//! [`crate::parameters::create_seeded_parameters`]'s approach for the V1
+//! config, specialized to Poseidon2's `ark`/`mu` parameter shape.
+
+use ark_ff::{BigInteger, PrimeField};
+use blake2::{Blake2s256, Digest};
+
+use crate::poseidon2::PoseidonConfig;
+
+/// Draw one uniformly-distributed field element from the `label`/`seed`
+/// stream, advancing `counter` by one per hash (more on rejection).
+fn derive_field_element<F: PrimeField>(label: &[u8], seed: &[u8], counter: &mut u64) -> F {
+    let byte_len = (F::MODULUS_BIT_SIZE as usize).div_ceil(8);
+    let excess_bits = byte_len * 8 - F::MODULUS_BIT_SIZE as usize;
+    loop {
+        let mut bytes = Vec::with_capacity(byte_len);
+        while bytes.len() < byte_len {
+            let mut hasher = Blake2s256::new();
+            hasher.update(label);
+            hasher.update(seed);
+            hasher.update(counter.to_le_bytes());
+            *counter += 1;
+            bytes.extend_from_slice(&hasher.finalize());
+        }
+        bytes.truncate(byte_len);
+        if excess_bits > 0 {
+            if let Some(last) = bytes.last_mut() {
+                *last &= 0xffu8 >> excess_bits;
+            }
+        }
+
+        let big = F::BigInt::from_bytes_le(&bytes);
+        if big < F::MODULUS {
+            if let Some(elem) = F::from_bigint(big) {
+                return elem;
+            }
+        }
+    }
+}
+
+fn has_duplicates<F: PrimeField>(v: &[F]) -> bool {
+    for i in 0..v.len() {
+        for j in i + 1..v.len() {
+            if v[i] == v[j] {
+                return true;
+            }
+        }
+    }
+    false
+}
+
+// det(J + Diag(mu)) = (prod mu_i) * (1 + sum mu_i^{-1}) (matrix determinant lemma)
+fn invertible_j_plus_diag<F: PrimeField>(mu: &[F]) -> bool {
+    let mut prod = F::one();
+    let mut sum_inv = F::zero();
+    for &m in mu {
+        let inv = match m.inverse() {
+            Some(v) => v,
+            None => return false,
+        };
+        prod *= m;
+        sum_inv += inv;
+    }
+    (prod * (F::one() + sum_inv)) != F::zero()
+}
+
+/// Generate `rf + rp` rows of `t` additive round constants each, in the
+/// round-major layout [`PoseidonConfig::new`]'s `ark` parameter expects.
+pub fn generate_ark<F: PrimeField>(
+    label: &[u8],
+    seed: &[u8],
+    rf: usize,
+    rp: usize,
+    t: usize,
+) -> Vec<Vec<F>> {
+    let mut counter = 0u64;
+    (0..(rf + rp))
+        .map(|_| {
+            (0..t)
+                .map(|_| derive_field_element::<F>(label, seed, &mut counter))
+                .collect()
+        })
+        .collect()
+}
+
+/// Generate the `t`-element internal-matrix diagonal `mu`, rejecting draws
+/// that are zero, duplicate an earlier entry, or would make `J + Diag(mu)`
+/// (the internal mixing matrix) singular — the same invertibility
+/// requirement [`crate::poseidon2::find_poseidon2_ark_and_mu`] enforces for
+/// Grain-derived parameters.
+///
+/// Hashed under a `label` suffixed with a distinct domain tag so this
+/// stream never overlaps the one [`generate_ark`] draws from, even though
+/// both start their counters at zero.
+fn generate_mu<F: PrimeField>(label: &[u8], seed: &[u8], t: usize) -> Vec<F> {
+    let mut mu_label = label.to_vec();
+    mu_label.extend_from_slice(b"|MU|");
+    let mut counter = 0u64;
+    loop {
+        let mu: Vec<F> = (0..t)
+            .map(|_| derive_field_element::<F>(&mu_label, seed, &mut counter))
+            .filter(|x| !x.is_zero())
+            .collect();
+        if mu.len() != t {
+            continue;
+        }
+        if has_duplicates(&mu) || !invertible_j_plus_diag(&mu) {
+            continue;
+        }
+        return mu;
+    }
+}
+
+impl<F: PrimeField> PoseidonConfig<F> {
+    /// Build Poseidon2 parameters for state width `t` and S-box exponent
+    /// `d` whose round constants and internal diagonal are derived
+    /// deterministically from `label`/`seed` (via [`generate_ark`] and
+    /// [`generate_mu`]) rather than looked up from an embedded table.
+    ///
+    /// The external (MDS) matrix is still the crate's standard structural
+    /// one from [`crate::parameters::poseidon2::identity_mds`] — it is
+    /// public, not secret-dependent, so there is nothing to personalize
+    /// there.
+    pub fn from_seed(label: &[u8], seed: &[u8], rf: usize, rp: usize, d: u64, t: usize) -> Self {
+        let ark = generate_ark::<F>(label, seed, rf, rp, t);
+        let mu = generate_mu::<F>(label, seed, t);
+        let mds = crate::parameters::poseidon2::identity_mds::<F>(t);
+        Self::new(rf, rp, d, mds, ark, mu, t - 1, 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type F = ark_pallas::Fq;
+
+    #[test]
+    fn first_ark_constant_is_the_first_stream_draw() {
+        let label = b"POSEIDON2_GEN_TEST";
+        let seed = b"seed-0";
+        let ark = generate_ark::<F>(label, seed, 2, 4, 3);
+
+        let mut counter = 0u64;
+        let expected = derive_field_element::<F>(label, seed, &mut counter);
+        assert_eq!(ark[0][0], expected);
+    }
+
+    #[test]
+    fn generate_ark_is_deterministic() {
+        let a = generate_ark::<F>(b"label", b"seed", 8, 56, 3);
+        let b = generate_ark::<F>(b"label", b"seed", 8, 56, 3);
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn generate_ark_has_the_requested_shape() {
+        let ark = generate_ark::<F>(b"label", b"seed", 8, 56, 4);
+        assert_eq!(ark.len(), 8 + 56);
+        assert!(ark.iter().all(|row| row.len() == 4));
+    }
+
+    #[test]
+    fn different_labels_and_seeds_diverge() {
+        let base = generate_ark::<F>(b"label-a", b"seed", 4, 8, 3);
+        let other_label = generate_ark::<F>(b"label-b", b"seed", 4, 8, 3);
+        let other_seed = generate_ark::<F>(b"label-a", b"seed-2", 4, 8, 3);
+        assert_ne!(base, other_label);
+        assert_ne!(base, other_seed);
+    }
+
+    #[test]
+    fn from_seed_produces_usable_parameters() {
+        let params = PoseidonConfig::<F>::from_seed(b"my-protocol", b"v1-seed", 8, 56, 5, 3);
+        assert_eq!(params.rate, 2);
+        assert_eq!(params.capacity, 1);
+        assert_eq!(params.ark.len(), 8 + 56);
+        assert_eq!(params.mu.len(), 3);
+
+        // Deterministic: the same label/seed reproduces identical parameters.
+        let again = PoseidonConfig::<F>::from_seed(b"my-protocol", b"v1-seed", 8, 56, 5, 3);
+        assert_eq!(params.ark, again.ark);
+        assert_eq!(params.mu, again.mu);
+    }
+}
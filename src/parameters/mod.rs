@@ -3,9 +3,10 @@
 //! This module contains cryptographically secure parameters generated using
 //! the official Poseidon reference implementation with 128-bit security level.
 
-use ark_ff::PrimeField;
+use ark_ff::{BigInteger, PrimeField};
 use crate::ark_poseidon::ArkPoseidonConfig;
 use ark_crypto_primitives::sponge::poseidon::traits::find_poseidon_ark_and_mds;
+use blake2::{Blake2s256, Digest};
 
 /// Security level in bits for all parameter sets
 pub const SECURITY_LEVEL: u32 = 128;
@@ -24,6 +25,22 @@ pub mod bn254;
 pub mod bls12_381;
 pub mod bls12_377;
 
+// Circom/light-poseidon-compatible BN254 parameters (t = 2..=13); see
+// `crate::circom` for the hashing entry point built on these.
+pub mod circom;
+
+// Poseidon2 parameter helpers and per-curve embedded parameter sets
+pub mod poseidon2;
+pub mod poseidon2_bls12_377;
+pub mod poseidon2_bls12_381;
+pub mod poseidon2_bn254;
+pub mod poseidon2_vesta;
+pub mod poseidon2_pallas;
+
+// Deterministic Poseidon2 parameter generation from a label/seed, for
+// curves/widths not covered by the embedded tables above.
+pub mod gen;
+
 /// Helper to create Poseidon sponge config from embedded constants
 pub fn create_parameters<F: PrimeField>(
     ark_flat: Vec<F>,
@@ -77,14 +94,15 @@ where
             partial_rounds.expect("partial_rounds missing"),
         );
     }
-    // Derive parameters via Poseidon Grain LFSR (deterministic) with common t=3 M=128 settings
+    // Derive parameters via Poseidon Grain LFSR (deterministic), with round
+    // numbers computed for this field/state size instead of hardcoded.
     let prime_bits = F::MODULUS_BIT_SIZE as u64;
     let rate = STATE_SIZE - 1; // 2
-    let fr = 8u64; // typical for t=3, alpha=5, 128-bit
-    let pr = 56u64; // typical for t=3, alpha=5, 128-bit
+    let (fr, pr) = secure_round_numbers(prime_bits, STATE_SIZE, ALPHA, SECURITY_LEVEL);
     let skip = 0u64;
-    let (ark, mds) = find_poseidon_ark_and_mds::<F>(prime_bits, rate, fr, pr, skip);
-    ArkPoseidonConfig::new(fr as usize, pr as usize, ALPHA, mds, ark, rate, STATE_SIZE - rate)
+    let (ark, mds) =
+        find_poseidon_ark_and_mds::<F>(prime_bits, rate, fr as u64, pr as u64, skip);
+    ArkPoseidonConfig::new(fr, pr, ALPHA, mds, ark, rate, STATE_SIZE - rate)
 }
 
 /// Create Poseidon parameters dynamically for arbitrary state size t and round counts.
@@ -115,3 +133,330 @@ where
     );
     ArkPoseidonConfig::new(full_rounds, partial_rounds, ALPHA, mds, ark, rate, capacity)
 }
+
+/// Compute a conservative, minimal-cost `(full_rounds, partial_rounds)` pair for
+/// a Poseidon permutation over a `field_bits`-bit field of width `t`, S-box
+/// degree `alpha`, targeting `security_bits` bits of security.
+///
+/// This follows the Poseidon paper's round-count analysis: a statistical-security
+/// floor on the full-round count, then the smallest partial-round count that
+/// keeps both the interpolation-attack and Gröbner-basis-attack costs at or
+/// above `security_bits`, with the customary safety margins (+2 full rounds,
+/// +7.5% partial rounds), rounding the full-round count up to an even number so
+/// it splits evenly before/after the partial rounds.
+pub fn secure_round_numbers(field_bits: u64, t: usize, alpha: u64, security_bits: u32) -> (usize, usize) {
+    let m = security_bits as f64;
+    let n = field_bits as f64;
+    let log2_t = (t as f64).log2().max(0.0);
+    let log2_alpha = (alpha as f64).log2();
+    let min_m_n = m.min(n);
+
+    // Statistical-security floor on the number of full rounds.
+    let rf_floor: f64 = if m <= (n - 3.0) * (t as f64 + 1.0) {
+        6.0
+    } else {
+        10.0
+    };
+
+    let mut rf = rf_floor;
+    loop {
+        let interpolation_bound = (min_m_n - rf * log2_t) / log2_alpha;
+        let groebner_term = (m / 2.0).min((n - 2.0) / 2.0);
+        let groebner_bound = if groebner_term > 0.0 {
+            groebner_term.log2() / log2_alpha
+        } else {
+            0.0
+        };
+        let rp = interpolation_bound.max(groebner_bound).max(0.0);
+
+        // Apply the customary margins, then re-check the bounds still hold.
+        let rf_margin = rf + 2.0;
+        let rp_margin = (rp * 1.075).ceil();
+        let interp_ok = (min_m_n - rf_margin * log2_t) / log2_alpha <= rp_margin;
+        let groeb_ok = groebner_bound <= rp_margin;
+        if interp_ok && groeb_ok {
+            let full_rounds = rf_margin.ceil() as u64;
+            let full_rounds = if full_rounds % 2 == 0 {
+                full_rounds
+            } else {
+                full_rounds + 1
+            };
+            return (full_rounds as usize, rp_margin as usize);
+        }
+        rf += 1.0;
+    }
+}
+
+/// Create Poseidon parameters dynamically for arbitrary state size `t`,
+/// automatically choosing secure round numbers via [`secure_round_numbers`]
+/// rather than requiring the caller to supply them.
+pub fn create_dynamic_parameters_secure<F>(
+    t: usize,
+    capacity: usize,
+    security_bits: u32,
+) -> ArkPoseidonConfig<F>
+where
+    F: PrimeField,
+{
+    let prime_bits = F::MODULUS_BIT_SIZE as u64;
+    let (full_rounds, partial_rounds) =
+        secure_round_numbers(prime_bits, t, ALPHA, security_bits);
+    create_dynamic_parameters::<F>(t, full_rounds, partial_rounds, capacity)
+}
+
+/// Draw one uniformly-distributed field element from a Blake2s-based
+/// personalization stream, by rejection sampling raw bytes against the field
+/// modulus and retrying (with an advanced counter) on rejection.
+fn derive_seeded_field_element<F: PrimeField>(domain: &[u8], counter: &mut u64) -> F {
+    let byte_len = (F::MODULUS_BIT_SIZE as usize).div_ceil(8);
+    let excess_bits = byte_len * 8 - F::MODULUS_BIT_SIZE as usize;
+    loop {
+        let mut bytes = Vec::with_capacity(byte_len);
+        while bytes.len() < byte_len {
+            let mut hasher = Blake2s256::new();
+            hasher.update(domain);
+            hasher.update(counter.to_le_bytes());
+            *counter += 1;
+            bytes.extend_from_slice(&hasher.finalize());
+        }
+        bytes.truncate(byte_len);
+        if excess_bits > 0 {
+            if let Some(last) = bytes.last_mut() {
+                *last &= 0xffu8 >> excess_bits;
+            }
+        }
+        let big = F::BigInt::from_bytes_le(&bytes);
+        if big < F::MODULUS {
+            if let Some(elem) = F::from_bigint(big) {
+                return elem;
+            }
+        }
+    }
+}
+
+/// Create Poseidon parameters whose round constants are personalized to a
+/// caller-supplied domain/application string, rather than shared across every
+/// user of a given `(curve, t)` geometry.
+///
+/// The MDS matrix is still derived structurally via [`find_poseidon_ark_and_mds`]
+/// (it is public, not secret-dependent), but the additive round constants (ARK)
+/// are instead drawn by rejection sampling from a Blake2s stream keyed on
+/// `domain`, so two protocols sharing the same `(t, full_rounds, partial_rounds)`
+/// but different `domain` strings get cryptographically independent
+/// permutations — mirroring rln's per-application personalized constants.
+pub fn create_seeded_parameters<F: PrimeField>(
+    t: usize,
+    full_rounds: usize,
+    partial_rounds: usize,
+    capacity: usize,
+    domain: &[u8],
+) -> ArkPoseidonConfig<F> {
+    let rate = t
+        .checked_sub(capacity)
+        .expect("capacity must be <= t when building Poseidon parameters");
+    let prime_bits = F::MODULUS_BIT_SIZE as u64;
+    let (_, mds) = find_poseidon_ark_and_mds::<F>(
+        prime_bits,
+        rate,
+        full_rounds as u64,
+        partial_rounds as u64,
+        0,
+    );
+
+    let mut counter = 0u64;
+    let mut ark = Vec::with_capacity(full_rounds + partial_rounds);
+    for _ in 0..(full_rounds + partial_rounds) {
+        let row: Vec<F> = (0..t)
+            .map(|_| derive_seeded_field_element::<F>(domain, &mut counter))
+            .collect();
+        ark.push(row);
+    }
+    ArkPoseidonConfig::new(full_rounds, partial_rounds, ALPHA, mds, ark, rate, capacity)
+}
+
+/// Report produced by [`poseidon_quality_check`]: empirical avalanche
+/// statistics gathered from random single-input-bit-flip pairs, meant for
+/// gating acceptance of a freshly generated (e.g. via
+/// [`create_dynamic_parameters`]) parameter set before trusting it in
+/// production.
+#[derive(Debug, Clone)]
+pub struct QualityReport {
+    /// Number of (base, flipped) input pairs sampled.
+    pub samples: usize,
+    /// Output bit width of the 2-to-1 compression this report measured.
+    pub output_bits: usize,
+    /// For each sampled pair, the fraction of output bits that differ
+    /// between the base and single-input-bit-flipped digest.
+    pub flipped_bit_fractions: Vec<f64>,
+    /// Count, across all samples, of how often each output byte position
+    /// was *identical* between the base and flipped digest.
+    pub same_byte_counts: Vec<u32>,
+    /// Count, across all samples, of how often each output nibble position
+    /// (two per byte, low nibble first) was identical between the base and
+    /// flipped digest.
+    pub same_nibble_counts: Vec<u32>,
+    /// Largest deviation from 0.5 of the flip fraction measured between the
+    /// flipped digest and a cyclic rotation of the base digest, maximized
+    /// over a handful of evenly-spaced rotation amounts — a probe for
+    /// rotation-structured correlations a plain bit-position avalanche
+    /// check wouldn't catch.
+    pub max_rotation_flip_deviation: f64,
+}
+
+impl QualityReport {
+    /// Whether every measured statistic falls within the healthy band a
+    /// well-mixing Poseidon parameter set is expected to land in: every
+    /// sample's flipped-bit fraction within `[0.25, 0.75]`, no output byte
+    /// or nibble staying identical across samples far more often than its
+    /// `1/256`/`1/16` chance rate, and no detectable rotation correlation.
+    pub fn is_healthy(&self) -> bool {
+        let flip_ok = self
+            .flipped_bit_fractions
+            .iter()
+            .all(|&f| (0.25..=0.75).contains(&f));
+        let trials = self.samples.max(1) as f64;
+        let byte_ok = self
+            .same_byte_counts
+            .iter()
+            .all(|&c| c as f64 / trials <= (1.0 / 256.0) * 4.0);
+        let nibble_ok = self
+            .same_nibble_counts
+            .iter()
+            .all(|&c| c as f64 / trials <= (1.0 / 16.0) * 4.0);
+        let rotation_ok = self.max_rotation_flip_deviation <= 0.25;
+        flip_ok && byte_ok && nibble_ok && rotation_ok
+    }
+}
+
+/// A small, fast, non-cryptographic PRNG (xorshift64), used only to drive
+/// [`poseidon_quality_check`]'s sampling; see the identical helper
+/// duplicated in `tests/quality.rs`/`tests/sidechannel.rs`.
+struct Xorshift64(u64);
+
+impl Xorshift64 {
+    fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+}
+
+/// Empirically measure the avalanche behavior of `params` by 2-to-1
+/// compressing `samples` random `(a, b)` pairs, flipping one of the low 64
+/// bits of `a` each time, and comparing the two digests bit-by-bit,
+/// byte-by-byte, and nibble-by-nibble; see [`QualityReport`]. This is the
+/// same Strict Avalanche Criterion check as the fixed regression test in
+/// this crate's own `tests/quality.rs`, exposed here as a reusable,
+/// curve-agnostic routine over `params` directly so callers can self-test a
+/// newly generated parameter set (e.g. from [`create_dynamic_parameters`] or
+/// [`create_seeded_parameters`]) rather than only a specific curve hasher.
+pub fn poseidon_quality_check<F: PrimeField + ark_crypto_primitives::sponge::Absorb>(
+    params: &ArkPoseidonConfig<F>,
+    samples: usize,
+) -> QualityReport {
+    use ark_crypto_primitives::sponge::{CryptographicSponge, FieldBasedCryptographicSponge};
+
+    const NUM_INPUT_BITS: usize = 64;
+    const NUM_ROTATIONS: usize = 8;
+
+    let compress = |a: F, b: F| -> F {
+        let mut sponge = crate::ark_poseidon::ArkPoseidonSponge::new(params);
+        sponge.absorb(&a);
+        sponge.absorb(&b);
+        sponge.squeeze_native_field_elements(1)[0]
+    };
+
+    let out_bits = compress(F::zero(), F::zero())
+        .into_bigint()
+        .to_bits_le()
+        .len();
+    let out_bytes = out_bits.div_ceil(8);
+    let rotations: Vec<usize> = (0..NUM_ROTATIONS)
+        .map(|r| r * out_bits / NUM_ROTATIONS)
+        .collect();
+
+    let mut rng = Xorshift64(0x9E37_79B9_7F4A_7C15);
+    let mut flipped_bit_fractions = Vec::with_capacity(samples);
+    let mut same_byte_counts = vec![0u32; out_bytes];
+    let mut same_nibble_counts = vec![0u32; out_bytes * 2];
+    let mut rotation_flip_sums = vec![0u64; rotations.len()];
+
+    for _ in 0..samples {
+        let base_input = rng.next_u64();
+        let partner_input = rng.next_u64();
+        let bit = (rng.next_u64() as usize) % NUM_INPUT_BITS;
+
+        let base_digest = compress(F::from(base_input), F::from(partner_input));
+        let flipped_digest = compress(F::from(base_input ^ (1u64 << bit)), F::from(partner_input));
+
+        let base_bits = base_digest.into_bigint().to_bits_le();
+        let flipped_bits = flipped_digest.into_bigint().to_bits_le();
+        let flips = base_bits
+            .iter()
+            .zip(&flipped_bits)
+            .filter(|(a, b)| a != b)
+            .count();
+        flipped_bit_fractions.push(flips as f64 / out_bits as f64);
+
+        let base_bytes = base_digest.into_bigint().to_bytes_le();
+        let flipped_bytes = flipped_digest.into_bigint().to_bytes_le();
+        for (i, (&bb, &fb)) in base_bytes.iter().zip(&flipped_bytes).enumerate() {
+            if bb == fb {
+                same_byte_counts[i] += 1;
+            }
+            if bb & 0x0f == fb & 0x0f {
+                same_nibble_counts[2 * i] += 1;
+            }
+            if bb >> 4 == fb >> 4 {
+                same_nibble_counts[2 * i + 1] += 1;
+            }
+        }
+
+        for (r, &shift) in rotations.iter().enumerate() {
+            let rotation_flips = (0..out_bits)
+                .filter(|&j| base_bits[(j + shift) % out_bits] != flipped_bits[j])
+                .count();
+            rotation_flip_sums[r] += rotation_flips as u64;
+        }
+    }
+
+    let max_rotation_flip_deviation = rotation_flip_sums
+        .iter()
+        .map(|&sum| {
+            let p = sum as f64 / (samples as f64 * out_bits as f64);
+            (p - 0.5).abs()
+        })
+        .fold(0.0, f64::max);
+
+    QualityReport {
+        samples,
+        output_bits: out_bits,
+        flipped_bit_fractions,
+        same_byte_counts,
+        same_nibble_counts,
+        max_rotation_flip_deviation,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Regression pin for `secure_round_numbers`'s `t=3`, 128-bit-security,
+    /// `alpha=5` case at this crate's standard ~255-bit field size — the
+    /// same geometry `bn254.rs`/`bls12_381.rs` hardcode `FULL_ROUNDS = 8`,
+    /// `PARTIAL_ROUNDS = 56` for. A prior version of this function divided
+    /// only part of the interpolation/Gröbner-basis bounds by `log2(alpha)`,
+    /// which inflated `partial_rounds` to 154 (~3x too many) without any
+    /// test catching it, since the other tests here only compare
+    /// `round_numbers()` against itself. Pinning an absolute value close to
+    /// the embedded curves' own hand-placed constants catches that class of
+    /// regression.
+    #[test]
+    fn secure_round_numbers_matches_known_good_t3_255bit_128sec() {
+        let (full_rounds, partial_rounds) = secure_round_numbers(255, 3, 5, 128);
+        assert_eq!(full_rounds, 8);
+        assert_eq!(partial_rounds, 55);
+    }
+}
@@ -7,7 +7,7 @@
 use crate::ark_poseidon::ArkPoseidon2Config;
 use ark_ff::PrimeField;
 
-const CAPACITY: usize = 1; // fixed for our case 
+const CAPACITY: usize = 1; // fixed for our case
 
 /// Create Poseidon2 parameters from provided per-round ARK and diagonal `mu`.
 ///
@@ -38,3 +38,126 @@ pub fn identity_mds<F: PrimeField>(t: usize) -> Vec<Vec<F>> {
     }
     m
 }
+
+/// Builds Poseidon2 parameters with round counts sized for a target security
+/// level, rather than the hardcoded `FULL_ROUNDS = 8` / `PARTIAL_ROUNDS = 56`
+/// every embedded curve module uses regardless of `t`.
+///
+/// Round counts are derived via [`crate::parameters::secure_round_numbers`]
+/// (the same statistical/interpolation/Gröbner-basis bound analysis used for
+/// the classic Poseidon parameters in [`crate::parameters`]), which applies
+/// equally to Poseidon2's permutation since the bound only depends on field
+/// size, width, S-box degree, and full/partial round counts — not on which
+/// permutation's MDS/internal-matrix structure supplies the mixing.
+#[derive(Debug, Clone, Copy)]
+pub struct Poseidon2ParamBuilder {
+    /// Bit-size of the field modulus.
+    pub prime_bits: u64,
+    /// State width (`rate + capacity`, capacity is always `1` here).
+    pub t: usize,
+    /// S-box exponent.
+    pub alpha: u64,
+    /// Target security level in bits.
+    pub security_bits: u32,
+}
+
+impl Poseidon2ParamBuilder {
+    /// Describe a Poseidon2 parameter set to build for `t` at `security_bits`.
+    pub fn new(prime_bits: u64, t: usize, alpha: u64, security_bits: u32) -> Self {
+        Self {
+            prime_bits,
+            t,
+            alpha,
+            security_bits,
+        }
+    }
+
+    /// The minimum `(full_rounds, partial_rounds)` pair satisfying this
+    /// builder's security target.
+    pub fn round_numbers(&self) -> (usize, usize) {
+        crate::parameters::secure_round_numbers(
+            self.prime_bits,
+            self.t,
+            self.alpha,
+            self.security_bits,
+        )
+    }
+
+    /// Build parameters using [`Self::round_numbers`] — i.e. the minimum
+    /// round counts that satisfy this builder's security target.
+    pub fn build<F: PrimeField>(&self) -> ArkPoseidon2Config<F> {
+        let (full_rounds, partial_rounds) = self.round_numbers();
+        self.build_with_rounds(full_rounds, partial_rounds)
+            .expect("round counts from `round_numbers` always satisfy their own target")
+    }
+
+    /// Build parameters with caller-supplied `full_rounds`/`partial_rounds`,
+    /// rejecting the pair if it falls short of this builder's security
+    /// target instead of silently building an under-secured permutation.
+    pub fn build_with_rounds<F: PrimeField>(
+        &self,
+        full_rounds: usize,
+        partial_rounds: usize,
+    ) -> Result<ArkPoseidon2Config<F>, String> {
+        let (min_full, min_partial) = self.round_numbers();
+        if full_rounds < min_full || partial_rounds < min_partial {
+            return Err(format!(
+                "full_rounds={full_rounds}/partial_rounds={partial_rounds} fall short of the \
+                 minimum {min_full}/{min_partial} required for {}-bit security at t={}",
+                self.security_bits, self.t
+            ));
+        }
+        let rate = self
+            .t
+            .checked_sub(CAPACITY)
+            .expect("t must be >= capacity (1) when building Poseidon2 parameters");
+        let (ark, mu) = crate::poseidon2::find_poseidon2_ark_and_mu::<F>(
+            self.prime_bits,
+            self.t,
+            full_rounds as u64,
+            partial_rounds as u64,
+        );
+        let mds = identity_mds::<F>(self.t);
+        Ok(create_parameters::<F>(
+            ark,
+            mu,
+            mds,
+            full_rounds,
+            partial_rounds,
+            self.alpha,
+            rate,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build_produces_a_consistent_parameter_set() {
+        let builder = Poseidon2ParamBuilder::new(ark_bn254::Fq::MODULUS_BIT_SIZE as u64, 3, 5, 128);
+        let params = builder.build::<ark_bn254::Fq>();
+        let (full_rounds, partial_rounds) = builder.round_numbers();
+        assert_eq!(params.full_rounds, full_rounds);
+        assert_eq!(params.partial_rounds, partial_rounds);
+        assert_eq!(params.rate, 2);
+        assert_eq!(params.capacity, 1);
+    }
+
+    #[test]
+    fn test_build_with_rounds_rejects_under_secured_counts() {
+        let builder = Poseidon2ParamBuilder::new(ark_bn254::Fq::MODULUS_BIT_SIZE as u64, 3, 5, 128);
+        let result = builder.build_with_rounds::<ark_bn254::Fq>(1, 1);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_build_with_rounds_accepts_the_minimum_round_numbers() {
+        let builder = Poseidon2ParamBuilder::new(ark_bn254::Fq::MODULUS_BIT_SIZE as u64, 3, 5, 128);
+        let (full_rounds, partial_rounds) = builder.round_numbers();
+        assert!(builder
+            .build_with_rounds::<ark_bn254::Fq>(full_rounds, partial_rounds)
+            .is_ok());
+    }
+}
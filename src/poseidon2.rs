@@ -1,11 +1,66 @@
+//! The Poseidon2 permutation: a drop-in alternative backend to the classic
+//! Poseidon sponge ([`crate::ark_poseidon::ArkPoseidonSponge`]), selectable
+//! at hasher construction via [`crate::hasher::MultiFieldHasherV2`] /
+//! [`crate::ark_poseidon::ArkPoseidon2Sponge`] while keeping the exact same
+//! `absorb`/`squeeze` surface as [`crate::hasher::MultiFieldHasherV1`], so
+//! both variants can be swapped in and benchmarked against each other.
+//!
+//! Poseidon2 differs from classic Poseidon only in its linear layer:
+//! * External (full) rounds apply all lanes' S-box then a block-circulant,
+//!   MDS-like matrix ([`Poseidon2Sponge::matmul_external`]).
+//! * Internal (partial) rounds apply the S-box to lane 0 only, add the
+//!   round constant to lane 0 only, then apply the cheap `J + diag(mu)`
+//!   matrix ([`Poseidon2Sponge::matmul_internal_with_mu`]) — an all-ones
+//!   matrix plus a per-lane diagonal, costing `t` multiplications and a
+//!   running sum rather than a full `t*t` matrix-vector product.
+//!
+//! The external S-box, round-count logic, and overall round schedule
+//! (`rf/2` full, then `rp` partial, then `rf/2` full) are unchanged from
+//! classic Poseidon; see [`permute`](Poseidon2Sponge::permute).
+
 use ark_crypto_primitives::sponge::{
-    Absorb, CryptographicSponge, DuplexSpongeMode, FieldBasedCryptographicSponge, SpongeExt,
+    Absorb, CryptographicSponge, DuplexSpongeMode, FieldBasedCryptographicSponge, FieldElementSize,
+    SpongeExt,
 };
 use ark_ff::{BigInteger, PrimeField};
 use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
 
 use crate::grain_lfsr::PoseidonGrainLFSR;
-// use ark_std::any::TypeId;
+
+/// Whether `F` and `F2` are the same prime field (same modulus), in which
+/// case squeezing can return native elements directly instead of
+/// re-packing bits.
+fn same_characteristic<F: PrimeField, F2: PrimeField>() -> bool {
+    F::MODULUS_BIT_SIZE == F2::MODULUS_BIT_SIZE && F::MODULUS.to_bytes_le() == F2::MODULUS.to_bytes_le()
+}
+
+/// Re-encode `elems` (over `F`) as `F2` elements, for the case where `F`
+/// and `F2` are the same field ([`same_characteristic`]) — a canonical
+/// serialize/deserialize round trip, since the two fields' canonical
+/// encodings agree whenever their moduli do.
+fn cast_native<F: PrimeField, F2: PrimeField>(elems: &[F]) -> Vec<F2> {
+    elems
+        .iter()
+        .map(|elem| {
+            let mut bytes = Vec::new();
+            elem.serialize_compressed(&mut bytes)
+                .expect("field serialization cannot fail");
+            F2::deserialize_compressed(bytes.as_slice())
+                .expect("same-characteristic round trip cannot fail")
+        })
+        .collect()
+}
+
+/// Number of bits `size` contributes toward an `F2` element, capped at the
+/// number of bits that are always uniformly representable
+/// (`MODULUS_BIT_SIZE - 1`).
+fn field_element_size_bits<F2: PrimeField>(size: &FieldElementSize) -> usize {
+    let usable = (F2::MODULUS_BIT_SIZE - 1) as usize;
+    match size {
+        FieldElementSize::Full => usable,
+        FieldElementSize::Truncated(n) => (*n).min(usable),
+    }
+}
 
 pub fn find_poseidon2_ark_and_mu<F: PrimeField>(
     prime_bits: u64,
@@ -26,6 +81,96 @@ pub fn find_poseidon2_ark_and_mu<F: PrimeField>(
     (ark, mu)
 }
 
+/// Recommend `(full_rounds, partial_rounds)` for a Poseidon2 instance over a
+/// `prime_bits`-bit field with state width `t` and S-box exponent `d`, at
+/// the given `security_bits` target.
+///
+/// `full_rounds` is the smallest even count satisfying the statistical
+/// bound (`rf >= 6`) plus a +2-round security margin. `partial_rounds` is
+/// the larger of the interpolation bound
+/// (`(d-1)*(rf*t + rp) >= min(security_bits, prime_bits)`) and the
+/// Gröbner-basis bound (`rp >= log_d(min(security_bits/2, (prime_bits-2)/2))`,
+/// the same bound [`crate::parameters::secure_round_numbers`] uses — a bound
+/// on `partial_rounds` alone, independent of `rf`), also with a +2-round
+/// margin.
+pub fn recommended_round_numbers(prime_bits: u64, t: usize, d: u64, security_bits: u64) -> (u64, u64) {
+    let rf = 6u64 + 2; // statistical bound (Rf >= 6) + security margin
+
+    let target_bits = security_bits.min(prime_bits) as f64;
+    let d_minus_one = (d - 1) as f64;
+
+    let interpolation_rp = (target_bits / d_minus_one - (rf * t as u64) as f64).ceil();
+    let groebner_rp = groebner_partial_rounds(prime_bits, d, security_bits);
+
+    let rp = interpolation_rp.max(groebner_rp).max(0.0) as u64 + 2;
+
+    (rf, rp)
+}
+
+/// The Gröbner-basis lower bound on `partial_rounds` alone: at least
+/// `log_d(min(security_bits/2, (prime_bits-2)/2))` partial rounds, the same
+/// bound [`crate::parameters::secure_round_numbers`]'s `groebner_bound`
+/// checks (there expressed as `log2(...)/log2(alpha)`; `log_d(x)` below is
+/// the equivalent change-of-base form). Returns `0.0` if the `min(...)` term
+/// is non-positive, since `log_d` is undefined there and no partial rounds
+/// are needed to satisfy a vacuous bound.
+fn groebner_partial_rounds(prime_bits: u64, d: u64, security_bits: u64) -> f64 {
+    let term = (security_bits as f64 / 2.0).min((prime_bits as f64 - 2.0) / 2.0);
+    if term > 0.0 {
+        (term.log2() / (d as f64).log2()).ceil()
+    } else {
+        0.0
+    }
+}
+
+/// Check whether `rf` full rounds and `rp` partial rounds meet the
+/// statistical, interpolation, and Gröbner-basis security bounds for a
+/// Poseidon2 instance over a `prime_bits`-bit field with state width `t`
+/// and S-box exponent `d`, at the given `security_bits` target.
+///
+/// Useful for validating externally supplied parameters, such as those
+/// vendored in the KAT tests below, rather than only ones generated by
+/// [`recommended_round_numbers`]/[`poseidon2_params`].
+pub fn is_secure(rf: u64, rp: u64, t: usize, d: u64, prime_bits: u64, security_bits: u64) -> bool {
+    if rf < 6 || rf % 2 != 0 {
+        return false;
+    }
+
+    let target_bits = security_bits.min(prime_bits) as f64;
+    let d_minus_one = (d - 1) as f64;
+
+    let interpolation_ok = d_minus_one * (rf * t as u64 + rp) as f64 >= target_bits;
+    let groebner_ok = (rp as f64) >= groebner_partial_rounds(prime_bits, d, security_bits);
+
+    interpolation_ok && groebner_ok
+}
+
+/// Generate a fully populated [`PoseidonConfig`] for a Poseidon2 instance
+/// over `prime_bits`-bit field `F` with state width `t`, S-box exponent
+/// `d`, at the given `security_bits` target — selecting `rf`/`rp` via
+/// [`recommended_round_numbers`] and generating `ark`/`mu` via
+/// [`find_poseidon2_ark_and_mu`], so callers no longer need to pick round
+/// counts by hand.
+pub fn poseidon2_params<F: PrimeField>(
+    prime_bits: u64,
+    t: usize,
+    d: u64,
+    security_bits: u64,
+) -> PoseidonConfig<F> {
+    let (rf, rp) = recommended_round_numbers(prime_bits, t, d, security_bits);
+    let (ark, mu) = find_poseidon2_ark_and_mu::<F>(prime_bits, t, rf, rp);
+    let mds = crate::parameters::poseidon2::identity_mds::<F>(t);
+    crate::parameters::poseidon2::create_parameters::<F>(
+        ark,
+        mu,
+        mds,
+        rf as usize,
+        rp as usize,
+        d,
+        t - 1,
+    )
+}
+
 // ---------------- μ generation ----------------
 
 fn gen_mu_internal_from_grain<F: PrimeField>(lsfr: &mut PoseidonGrainLFSR, t: usize) -> Vec<F> {
@@ -363,25 +508,113 @@ impl<F: PrimeField> Poseidon2Sponge<F> {
         }
     }
 
-    /// Compress 3 field elements into 1 using a single Poseidon2 permutation.
+    /// Compress `N` field elements into 1 using a single Poseidon2
+    /// permutation, for any parameter set where `rate == N` and
+    /// `capacity == 1`.
     ///
-    /// Requires t=4 (rate=3, capacity=1) parameters. Builds a state
-    /// [0, x0, x1, x2] with capacity lane set to 0, runs one permutation,
-    /// and returns the first lane.
+    /// Builds a scratch state `[0, inputs...]` (capacity lane first,
+    /// zeroed; `inputs` fill the rate lanes) and runs one permutation over
+    /// it in place, returning the first lane. Unlike the old fixed-arity
+    /// `compress_3`, this only allocates the small scratch state rather
+    /// than cloning the whole sponge — and its `parameters.ark`/`mds`
+    /// round-constant tables — on every call.
+    pub fn compress<const N: usize>(&self, inputs: [F; N]) -> F {
+        assert_eq!(self.parameters.capacity, 1, "compress expects capacity=1");
+        assert_eq!(self.parameters.rate, N, "compress requires rate == N");
+
+        let mut state = Vec::with_capacity(N + 1);
+        state.push(F::zero());
+        state.extend_from_slice(&inputs);
+
+        let rf = self.parameters.full_rounds;
+        let rp = self.parameters.partial_rounds;
+        let d = self.parameters.d;
+        let mu = &self.parameters.mu;
+
+        Self::matmul_external(&mut state);
+
+        let fr_half = rf / 2;
+        for r in 0..fr_half {
+            for (i, lane) in state.iter_mut().enumerate() {
+                lane.add_assign(&self.parameters.ark[r][i]);
+            }
+            Self::apply_s_box(&mut state, true, d);
+            Self::matmul_external(&mut state);
+        }
+
+        for r in fr_half..(fr_half + rp) {
+            state[0].add_assign(&self.parameters.ark[r][0]);
+            Self::apply_s_box(&mut state, false, d);
+            Self::matmul_internal_with_mu(&mut state, mu);
+        }
+
+        for r in (fr_half + rp)..(rf + rp) {
+            for (i, lane) in state.iter_mut().enumerate() {
+                lane.add_assign(&self.parameters.ark[r][i]);
+            }
+            Self::apply_s_box(&mut state, true, d);
+            Self::matmul_external(&mut state);
+        }
+
+        state[0]
+    }
+
+    /// Compress 3 field elements into 1. Requires t=4 (rate=3, capacity=1)
+    /// parameters; see [`Self::compress`] for the general-arity version.
     pub fn compress_3(&self, x0: F, x1: F, x2: F) -> F {
-        assert_eq!(
-            self.parameters.rate + self.parameters.capacity,
-            4,
-            "compress_3 requires t=4 parameters"
-        );
-        assert_eq!(self.parameters.capacity, 1, "compress_3 expects capacity=1");
-        assert_eq!(self.parameters.rate, 3, "compress_3 expects rate=3");
+        self.compress([x0, x1, x2])
+    }
 
-        let mut tmp = self.clone();
-        // Place capacity as the last lane: [x0, x1, x2, 0]
-        tmp.state = vec![x0, x1, x2, F::zero()];
-        tmp.permute();
-        tmp.state[0]
+    /// Compress 2 field elements into 1. Requires t=3 (rate=2, capacity=1)
+    /// parameters; see [`Self::compress`] for the general-arity version.
+    pub fn compress_2(&self, x0: F, x1: F) -> F {
+        self.compress([x0, x1])
+    }
+
+    /// Compress `inputs.len()` field elements into 1 using a single Poseidon2
+    /// permutation, for any parameter set where `rate == inputs.len()` and
+    /// `capacity == 1`. The runtime-length analogue of [`Self::compress`],
+    /// for callers whose arity isn't known at compile time (e.g. a
+    /// caller-chosen Merkle tree fan-out).
+    pub fn compress_slice(&self, inputs: &[F]) -> F {
+        assert_eq!(self.parameters.capacity, 1, "compress_slice expects capacity=1");
+        assert_eq!(self.parameters.rate, inputs.len(), "compress_slice requires rate == inputs.len()");
+
+        let mut state = Vec::with_capacity(inputs.len() + 1);
+        state.push(F::zero());
+        state.extend_from_slice(inputs);
+
+        let rf = self.parameters.full_rounds;
+        let rp = self.parameters.partial_rounds;
+        let d = self.parameters.d;
+        let mu = &self.parameters.mu;
+
+        Self::matmul_external(&mut state);
+
+        let fr_half = rf / 2;
+        for r in 0..fr_half {
+            for (i, lane) in state.iter_mut().enumerate() {
+                lane.add_assign(&self.parameters.ark[r][i]);
+            }
+            Self::apply_s_box(&mut state, true, d);
+            Self::matmul_external(&mut state);
+        }
+
+        for r in fr_half..(fr_half + rp) {
+            state[0].add_assign(&self.parameters.ark[r][0]);
+            Self::apply_s_box(&mut state, false, d);
+            Self::matmul_internal_with_mu(&mut state, mu);
+        }
+
+        for r in (fr_half + rp)..(rf + rp) {
+            for (i, lane) in state.iter_mut().enumerate() {
+                lane.add_assign(&self.parameters.ark[r][i]);
+            }
+            Self::apply_s_box(&mut state, true, d);
+            Self::matmul_external(&mut state);
+        }
+
+        state[0]
     }
 
     #[cfg(test)]
@@ -568,36 +801,51 @@ impl<F: PrimeField> CryptographicSponge for Poseidon2Sponge<F> {
         bits
     }
 
-    // fn squeeze_field_elements_with_sizes<F2: PrimeField>(
-    //     &mut self,
-    //     sizes: &[FieldElementSize],
-    // ) -> Vec<F2> {
-    //     if F::characteristic() == F2::characteristic() {
-    //         // native case
-    //         let mut buf = Vec::with_capacity(sizes.len());
-    //         field_cast(
-    //             &self.squeeze_native_field_elements_with_sizes(sizes),
-    //             &mut buf,
-    //         )
-    //         .unwrap();
-    //         buf
-    //     } else {
-    //         squeeze_field_elements_with_sizes_default_impl(self, sizes)
-    //     }
-    // }
-
-    // fn squeeze_field_elements<F2: PrimeField>(&mut self, num_elements: usize) -> Vec<F2> {
-    //     if TypeId::of::<F>() == TypeId::of::<F2>() {
-    //         let result = self.squeeze_native_field_elements(num_elements);
-    //         let mut cast = Vec::with_capacity(result.len());
-    //         field_cast(&result, &mut cast).unwrap();
-    //         cast
-    //     } else {
-    //         self.squeeze_field_elements_with_sizes::<F2>(
-    //             vec![FieldElementSize::Full; num_elements].as_slice(),
-    //         )
-    //     }
-    // }
+    fn squeeze_field_elements_with_sizes<F2: PrimeField>(
+        &mut self,
+        sizes: &[FieldElementSize],
+    ) -> Vec<F2> {
+        if same_characteristic::<F, F2>() {
+            let native = self.squeeze_native_field_elements(sizes.len());
+            return cast_native(&native);
+        }
+
+        if sizes.is_empty() {
+            return Vec::new();
+        }
+
+        let bit_sizes: Vec<usize> = sizes.iter().map(field_element_size_bits::<F2>).collect();
+        let total_bits: usize = bit_sizes.iter().sum();
+        let bits = self.squeeze_bits(total_bits);
+
+        let mut cursor = 0usize;
+        let mut out = Vec::with_capacity(sizes.len());
+        for &num_bits in &bit_sizes {
+            let chunk = &bits[cursor..cursor + num_bits];
+            cursor += num_bits;
+
+            let bytes: Vec<u8> = chunk
+                .chunks(8)
+                .map(|byte_bits| {
+                    byte_bits
+                        .iter()
+                        .enumerate()
+                        .fold(0u8, |acc, (i, &bit)| if bit { acc | (1 << i) } else { acc })
+                })
+                .collect();
+
+            out.push(F2::from_le_bytes_mod_order(&bytes));
+        }
+        out
+    }
+
+    fn squeeze_field_elements<F2: PrimeField>(&mut self, num_elements: usize) -> Vec<F2> {
+        if same_characteristic::<F, F2>() {
+            let native = self.squeeze_native_field_elements(num_elements);
+            return cast_native(&native);
+        }
+        self.squeeze_field_elements_with_sizes(vec![FieldElementSize::Full; num_elements].as_slice())
+    }
 }
 
 impl<F: PrimeField> FieldBasedCryptographicSponge<F> for Poseidon2Sponge<F> {
@@ -624,6 +872,117 @@ impl<F: PrimeField> FieldBasedCryptographicSponge<F> for Poseidon2Sponge<F> {
     }
 }
 
+/// Pad `input` with a single `1` element followed by zeros out to a whole
+/// number of `rate`-sized blocks (`10*` padding) — unlike
+/// [`crate::domain::ConstantLength`]'s zero-only padding, this disambiguates
+/// trailing-zero messages on its own, without relying solely on the
+/// length-derived capacity tag.
+fn pad_10_star<F: PrimeField>(input: &[F], rate: usize) -> Vec<F> {
+    let mut padded = input.to_vec();
+    padded.push(F::one());
+    let remainder = padded.len() % rate;
+    if remainder != 0 {
+        padded.resize(padded.len() + (rate - remainder), F::zero());
+    }
+    padded
+}
+
+/// One-shot streaming hash of an arbitrary-length message, following the
+/// Noir `poseidon2.nr` sponge convention: the capacity lane is seeded with
+/// the message length before any absorption (so that messages of different
+/// lengths start from distinct states even where one pads to a prefix of
+/// the other), the message is then `10*`-padded, and a single field element
+/// is squeezed out.
+///
+/// This composes the same [`Poseidon2Sponge::absorb`] /
+/// `squeeze_native_field_elements` streaming primitives used throughout
+/// this module; unlike [`crate::domain::hash`], the length tag is a
+/// runtime value taken from `inputs` rather than a compile-time
+/// [`crate::domain::Domain`] parameter, since Noir's sponge has no
+/// upfront knowledge of the message length either.
+pub fn streaming_hash<F: PrimeField>(parameters: &PoseidonConfig<F>, inputs: &[F]) -> F {
+    assert_eq!(parameters.capacity, 1, "hash assumes a single capacity lane");
+
+    let mut sponge = Poseidon2Sponge::new(parameters);
+    sponge.state[0] = F::from(inputs.len() as u64);
+
+    let padded = pad_10_star(inputs, parameters.rate);
+    for block in padded.chunks(parameters.rate) {
+        sponge.absorb(&block.to_vec());
+    }
+
+    sponge.squeeze_native_field_elements(1)[0]
+}
+
+/// Marker type: a [`Sponge`] in this phase accepts [`Sponge::absorb`].
+#[derive(Clone, Copy, Debug)]
+pub struct Absorbing;
+
+/// Marker type: a [`Sponge`] in this phase only accepts further
+/// [`Sponge::squeeze`] calls — `absorb` is not available in this phase at
+/// all, rather than silently re-absorbing into the state the way
+/// [`Poseidon2Sponge`]'s [`CryptographicSponge::absorb`] impl does for
+/// `DuplexSpongeMode::Squeezing`.
+#[derive(Clone, Copy, Debug)]
+pub struct Squeezing;
+
+/// Typestate wrapper around [`Poseidon2Sponge`] that makes
+/// absorbing-after-squeezing a compile error instead of a silent state
+/// mutation that breaks the sponge security argument.
+///
+/// [`Sponge<F, Absorbing>`] is the entry point ([`Sponge::new`]);
+/// [`Sponge::absorb`] keeps it there, and [`Sponge::finish_absorbing`] /
+/// [`Sponge::squeeze`] move it to [`Sponge<F, Squeezing>`], whose only
+/// operation is further squeezing. The untyped [`CryptographicSponge`] impl
+/// on [`Poseidon2Sponge`] remains for backward compatibility; this typed
+/// wrapper is the recommended entry point for new code.
+#[derive(Clone)]
+pub struct Sponge<F: PrimeField, Phase> {
+    inner: Poseidon2Sponge<F>,
+    _phase: std::marker::PhantomData<Phase>,
+}
+
+impl<F: PrimeField> Sponge<F, Absorbing> {
+    /// Create a new sponge in the [`Absorbing`] phase.
+    pub fn new(parameters: &PoseidonConfig<F>) -> Self {
+        Self {
+            inner: Poseidon2Sponge::new(parameters),
+            _phase: std::marker::PhantomData,
+        }
+    }
+
+    /// Absorb `input`, staying in the [`Absorbing`] phase.
+    pub fn absorb(&mut self, input: &impl Absorb) {
+        CryptographicSponge::absorb(&mut self.inner, input);
+    }
+
+    /// Move to the [`Squeezing`] phase without squeezing any output yet.
+    pub fn finish_absorbing(self) -> Sponge<F, Squeezing> {
+        Sponge {
+            inner: self.inner,
+            _phase: std::marker::PhantomData,
+        }
+    }
+
+    /// Squeeze `num_elements` field elements, moving to the [`Squeezing`]
+    /// phase in the process — after this call, only further squeezing is
+    /// available.
+    pub fn squeeze(self, num_elements: usize) -> (Vec<F>, Sponge<F, Squeezing>) {
+        let mut squeezing = self.finish_absorbing();
+        let out = squeezing.squeeze(num_elements);
+        (out, squeezing)
+    }
+}
+
+impl<F: PrimeField> Sponge<F, Squeezing> {
+    /// Squeeze `num_elements` more field elements, staying in the
+    /// [`Squeezing`] phase. There is no `absorb` method in this phase: see
+    /// the type-level docs on [`Sponge`].
+    pub fn squeeze(&mut self, num_elements: usize) -> Vec<F> {
+        self.inner.squeeze_native_field_elements(num_elements)
+    }
+}
+
 #[derive(Clone)]
 /// Stores the state of a Poseidon Sponge. Does not store any parameter.
 pub struct PoseidonSpongeState<F: PrimeField> {
@@ -738,4 +1097,244 @@ mod poseidon2_pallas_kats {
         let out = sponge.compress_3(a, b, c);
         assert_eq!(out, expected);
     }
+
+    #[test]
+    fn compress_slice_matches_compress_for_matching_arity() {
+        use crate::parameters::poseidon2_pallas::{PALLAS_POSEIDON2_PARAMS, PALLAS_POSEIDON2_PARAMS_T4};
+
+        let sponge2 = Poseidon2Sponge::<ark_pallas::Fq>::new(&*PALLAS_POSEIDON2_PARAMS);
+        let a = ark_pallas::Fq::from(5u64);
+        let b = ark_pallas::Fq::from(6u64);
+        assert_eq!(sponge2.compress_slice(&[a, b]), sponge2.compress([a, b]));
+
+        let sponge3 = Poseidon2Sponge::<ark_pallas::Fq>::new(&*PALLAS_POSEIDON2_PARAMS_T4);
+        let c = ark_pallas::Fq::from(7u64);
+        assert_eq!(sponge3.compress_slice(&[a, b, c]), sponge3.compress([a, b, c]));
+    }
+
+    #[test]
+    #[should_panic(expected = "rate == inputs.len()")]
+    fn compress_slice_rejects_arity_mismatch() {
+        use crate::parameters::poseidon2_pallas::PALLAS_POSEIDON2_PARAMS;
+
+        let sponge = Poseidon2Sponge::<ark_pallas::Fq>::new(&*PALLAS_POSEIDON2_PARAMS);
+        let _ = sponge.compress_slice(&[ark_pallas::Fq::from(1u64)]);
+    }
+}
+
+#[cfg(test)]
+mod noir_style_hash_tests {
+    use super::*;
+    use crate::parameters::poseidon2_pallas::PALLAS_POSEIDON2_PARAMS;
+
+    type F = ark_pallas::Fq;
+
+    #[test]
+    fn hash_is_deterministic() {
+        let inputs: Vec<F> = (1..=5u64).map(F::from).collect();
+        let a = streaming_hash(&PALLAS_POSEIDON2_PARAMS, &inputs);
+        let b = streaming_hash(&PALLAS_POSEIDON2_PARAMS, &inputs);
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn differently_sized_messages_do_not_collide() {
+        let short: Vec<F> = vec![F::from(1u64), F::from(2u64)];
+        let long: Vec<F> = vec![F::from(1u64), F::from(2u64), F::zero()];
+        assert_ne!(
+            streaming_hash(&PALLAS_POSEIDON2_PARAMS, &short),
+            streaming_hash(&PALLAS_POSEIDON2_PARAMS, &long)
+        );
+    }
+
+    #[test]
+    fn hash_matches_one_shot_manual_permutation_for_a_single_full_block() {
+        // rate=2 here, so a 2-element message fills exactly one full
+        // rate-sized block and `10*` padding adds a second block:
+        // [1, 2] -> padded [1, 2, 1, 0].
+        let a = F::from(1u64);
+        let b = F::from(2u64);
+
+        let mut manual = Poseidon2Sponge::<F>::new(&PALLAS_POSEIDON2_PARAMS);
+        manual.state[0] = F::from(2u64); // length tag
+        manual.state[1] += a;
+        manual.state[2] += b;
+        manual.permute();
+        manual.state[1] += F::one();
+        manual.state[2] += F::zero();
+        manual.permute();
+        let expected = manual.state[1];
+
+        assert_eq!(streaming_hash(&PALLAS_POSEIDON2_PARAMS, &[a, b]), expected);
+    }
+
+    #[test]
+    fn empty_message_still_hashes_via_padding_alone() {
+        let out = streaming_hash::<F>(&PALLAS_POSEIDON2_PARAMS, &[]);
+        // Just exercise the zero-length path without panicking or looping.
+        assert_eq!(out, streaming_hash::<F>(&PALLAS_POSEIDON2_PARAMS, &[]));
+    }
+}
+
+#[cfg(test)]
+mod cross_field_squeeze_tests {
+    use super::*;
+    use crate::parameters::poseidon2_pallas::PALLAS_POSEIDON2_PARAMS;
+    use ark_crypto_primitives::sponge::FieldElementSize;
+
+    type F = ark_pallas::Fq;
+
+    #[test]
+    fn native_squeeze_field_elements_matches_squeeze_native_field_elements() {
+        let mut a = Poseidon2Sponge::<F>::new(&*PALLAS_POSEIDON2_PARAMS);
+        let mut b = a.clone();
+
+        let native = a.squeeze_native_field_elements(3);
+        let via_trait: Vec<F> = b.squeeze_field_elements(3);
+
+        assert_eq!(native, via_trait);
+    }
+
+    #[test]
+    fn cross_field_squeeze_is_deterministic() {
+        let mut a = Poseidon2Sponge::<F>::new(&*PALLAS_POSEIDON2_PARAMS);
+        let mut b = a.clone();
+
+        let out_a: Vec<ark_bn254::Fq> = a.squeeze_field_elements(2);
+        let out_b: Vec<ark_bn254::Fq> = b.squeeze_field_elements(2);
+
+        assert_eq!(out_a, out_b);
+    }
+
+    #[test]
+    fn cross_field_squeeze_differs_from_a_differently_absorbed_sponge() {
+        let mut a = Poseidon2Sponge::<F>::new(&*PALLAS_POSEIDON2_PARAMS);
+        a.absorb(&F::from(7u64));
+        let mut b = Poseidon2Sponge::<F>::new(&*PALLAS_POSEIDON2_PARAMS);
+        b.absorb(&F::from(8u64));
+
+        let out_a: Vec<ark_bn254::Fq> = a.squeeze_field_elements(2);
+        let out_b: Vec<ark_bn254::Fq> = b.squeeze_field_elements(2);
+
+        assert_ne!(out_a, out_b);
+    }
+
+    #[test]
+    fn truncated_size_yields_fewer_bits_than_full() {
+        let mut full_sponge = Poseidon2Sponge::<F>::new(&*PALLAS_POSEIDON2_PARAMS);
+        let mut truncated_sponge = full_sponge.clone();
+
+        let full: Vec<ark_bn254::Fq> =
+            full_sponge.squeeze_field_elements_with_sizes(&[FieldElementSize::Full]);
+        let truncated: Vec<ark_bn254::Fq> = truncated_sponge
+            .squeeze_field_elements_with_sizes(&[FieldElementSize::Truncated(8)]);
+
+        // Both are deterministic outputs of the same initial state, but a
+        // `Truncated(8)` request consumes far fewer squeezed bits than
+        // `Full`, so the two outputs are not expected to match.
+        assert_ne!(full, truncated);
+    }
+}
+
+#[cfg(test)]
+mod round_number_tests {
+    use super::*;
+
+    #[test]
+    fn recommended_full_rounds_are_even_and_meet_statistical_bound() {
+        let (rf, _rp) = recommended_round_numbers(255, 3, 5, 128);
+        assert_eq!(rf % 2, 0);
+        assert!(rf >= 6);
+    }
+
+    #[test]
+    fn recommended_rounds_are_reported_as_secure() {
+        let (rf, rp) = recommended_round_numbers(255, 3, 5, 128);
+        assert!(is_secure(rf, rp, 3, 5, 255, 128));
+    }
+
+    #[test]
+    fn existing_pallas_round_numbers_are_secure_at_128_bits() {
+        // The vendored Pallas KAT parameters (rf=8, rp=56, t=3, d=5) should
+        // meet the same bounds `is_secure` validates for freshly generated
+        // parameters.
+        assert!(is_secure(8, 56, 3, 5, 255, 128));
+    }
+
+    #[test]
+    fn too_few_partial_rounds_is_reported_insecure() {
+        assert!(!is_secure(8, 1, 3, 5, 255, 128));
+    }
+
+    #[test]
+    fn odd_full_round_count_is_reported_insecure() {
+        assert!(!is_secure(7, 56, 3, 5, 255, 128));
+    }
+
+    #[test]
+    fn poseidon2_params_generates_a_usable_config() {
+        type F = ark_pallas::Fq;
+        let cfg = poseidon2_params::<F>(255, 3, 5, 128);
+        assert_eq!(cfg.rate, 2);
+        assert_eq!(cfg.capacity, 1);
+        assert!(is_secure(
+            cfg.full_rounds as u64,
+            cfg.partial_rounds as u64,
+            3,
+            5,
+            255,
+            128
+        ));
+
+        let sponge = Poseidon2Sponge::<F>::new(&cfg);
+        let out = sponge.compress([F::from(1u64), F::from(2u64)]);
+        assert_ne!(out, F::zero());
+    }
+}
+
+#[cfg(test)]
+mod typestate_sponge_tests {
+    use super::*;
+    use crate::parameters::poseidon2_pallas::PALLAS_POSEIDON2_PARAMS;
+
+    type F = ark_pallas::Fq;
+
+    #[test]
+    fn test_typed_sponge_matches_untyped_sponge() {
+        let mut untyped = Poseidon2Sponge::<F>::new(&*PALLAS_POSEIDON2_PARAMS);
+        untyped.absorb(&F::from(1u64));
+        untyped.absorb(&F::from(2u64));
+        let expected = untyped.squeeze_native_field_elements(2);
+
+        let mut typed = Sponge::<F, Absorbing>::new(&*PALLAS_POSEIDON2_PARAMS);
+        typed.absorb(&F::from(1u64));
+        typed.absorb(&F::from(2u64));
+        let (out, _squeezing) = typed.squeeze(2);
+
+        assert_eq!(out, expected);
+    }
+
+    #[test]
+    fn test_squeezing_phase_can_squeeze_further_elements() {
+        let typed = Sponge::<F, Absorbing>::new(&*PALLAS_POSEIDON2_PARAMS);
+        let (first, mut squeezing) = typed.squeeze(1);
+        let second = squeezing.squeeze(1);
+
+        assert_eq!(first.len(), 1);
+        assert_eq!(second.len(), 1);
+        assert_ne!(first, second);
+    }
+
+    #[test]
+    fn test_finish_absorbing_without_squeezing_yet_then_squeezes() {
+        let mut typed = Sponge::<F, Absorbing>::new(&*PALLAS_POSEIDON2_PARAMS);
+        typed.absorb(&F::from(42u64));
+        let mut squeezing = typed.finish_absorbing();
+        let out = squeezing.squeeze(1);
+        assert_eq!(out.len(), 1);
+    }
+
+    // Note: `squeezing.absorb(...)` is intentionally not exercised here —
+    // `Sponge<F, Squeezing>` has no `absorb` method, so attempting to call
+    // one is a compile error, not a runtime failure.
 }
@@ -0,0 +1,435 @@
+//! R1CS constraint-system counterpart to [`crate::poseidon2::Poseidon2Sponge`].
+//!
+//! Mirrors the native permutation lane-for-lane: the same external/internal
+//! matrix multiplications, the same `x^d` S-box computed via repeated
+//! multiplication (no generic `pow` gate), and the same round-constant
+//! additions and internal-matrix diagonal (`mu`) trick — implemented as a
+//! single linear combination per lane (`lane * mu_i + sum`, with `mu_i` a
+//! circuit constant and `sum` the sum of all lanes), since
+//! multiplying a witness by a circuit constant costs no extra constraint.
+//!
+//! Gated behind the `r1cs` feature, which pulls in `ark-r1cs-std`/`ark-relations`.
+
+use ark_crypto_primitives::sponge::DuplexSpongeMode;
+use ark_ff::PrimeField;
+use ark_r1cs_std::fields::fp::FpVar;
+use ark_r1cs_std::prelude::*;
+use ark_relations::r1cs::SynthesisError;
+
+use crate::poseidon2::PoseidonConfig;
+
+// Mirrors `Poseidon2Sponge::matmul_m4` one-for-one; see its doc comment for
+// the M4 matrix this implements.
+fn matmul_m4_var<F: PrimeField>(state: &mut [FpVar<F>]) -> Result<(), SynthesisError> {
+    let t = state.len();
+    let t4 = t / 4;
+    for i in 0..t4 {
+        let s = i * 4;
+        let t0 = state[s].clone() + state[s + 1].clone();
+        let t1 = state[s + 2].clone() + state[s + 3].clone();
+        let t2 = state[s + 1].double()? + t1.clone();
+        let t3 = state[s + 3].double()? + t0.clone();
+        let t4v = t1.double()?.double()? + t3.clone();
+        let t5 = t0.double()?.double()? + t2.clone();
+        let t6 = t3 + t5.clone();
+        let t7 = t2 + t4v.clone();
+        state[s] = t6;
+        state[s + 1] = t5;
+        state[s + 2] = t7;
+        state[s + 3] = t4v;
+    }
+    Ok(())
+}
+
+// Mirrors `Poseidon2Sponge::matmul_external`.
+fn matmul_external_var<F: PrimeField>(state: &mut [FpVar<F>]) -> Result<(), SynthesisError> {
+    let t = state.len();
+    match t {
+        2 => {
+            let sum = state[0].clone() + state[1].clone();
+            state[0] = state[0].clone() + sum.clone();
+            state[1] = state[1].clone() + sum;
+        }
+        3 => {
+            let sum = state[0].clone() + state[1].clone() + state[2].clone();
+            state[0] = state[0].clone() + sum.clone();
+            state[1] = state[1].clone() + sum.clone();
+            state[2] = state[2].clone() + sum;
+        }
+        4 => matmul_m4_var(state)?,
+        8 | 12 | 16 | 20 | 24 => {
+            matmul_m4_var(state)?;
+            let t4 = t / 4;
+            let mut stored: Vec<FpVar<F>> = Vec::with_capacity(4);
+            for l in 0..4 {
+                let mut acc = state[l].clone();
+                for j in 1..t4 {
+                    acc += state[4 * j + l].clone();
+                }
+                stored.push(acc);
+            }
+            for i in 0..t {
+                state[i] = state[i].clone() + stored[i % 4].clone();
+            }
+        }
+        _ => panic!("unsupported Poseidon2 t for external matrix (gadget)"),
+    }
+    Ok(())
+}
+
+// Mirrors `Poseidon2Sponge::matmul_internal_with_mu`. The `4 | 8 | ..`
+// branch is the "diagonal-matrix trick": `mu[i] * state[i]` is a
+// constant-times-witness product, i.e. a single linear combination rather
+// than a multiplication gate.
+fn matmul_internal_with_mu_var<F: PrimeField>(
+    state: &mut [FpVar<F>],
+    mu: &[F],
+) -> Result<(), SynthesisError> {
+    let t = state.len();
+    match t {
+        2 => {
+            let sum = state[0].clone() + state[1].clone();
+            state[0] = state[0].clone() + sum.clone();
+            state[1] = state[1].double()? + sum;
+        }
+        3 => {
+            let sum = state[0].clone() + state[1].clone() + state[2].clone();
+            state[0] = state[0].clone() + sum.clone();
+            state[1] = state[1].clone() + sum.clone();
+            state[2] = state[2].double()? + sum;
+        }
+        4 | 8 | 12 | 16 | 20 | 24 => {
+            let mut sum = state[0].clone();
+            for lane in state.iter().skip(1) {
+                sum += lane.clone();
+            }
+            for i in 0..t {
+                state[i] = state[i].clone() * FpVar::constant(mu[i]) + sum.clone();
+            }
+        }
+        _ => panic!("unsupported Poseidon2 t for internal matrix (gadget)"),
+    }
+    Ok(())
+}
+
+// Mirrors `Poseidon2Sponge::apply_s_box`: `x^d` via repeated squaring and
+// multiplication rather than a generic exponentiation gate.
+fn apply_s_box_var<F: PrimeField>(
+    state: &mut [FpVar<F>],
+    is_full_round: bool,
+    d: u64,
+) -> Result<(), SynthesisError> {
+    let sbox = |input: &FpVar<F>| -> Result<FpVar<F>, SynthesisError> {
+        let input2 = input.square()?;
+        match d {
+            3 => Ok(input2 * input),
+            5 => Ok(input2.square()? * input),
+            7 => {
+                let input4 = input2.square()?;
+                Ok(input4 * &input2 * input)
+            }
+            _ => panic!("unsupported Poseidon2 S-box degree d={d} (gadget)"),
+        }
+    };
+
+    if is_full_round {
+        for elem in state.iter_mut() {
+            *elem = sbox(elem)?;
+        }
+    } else {
+        state[0] = sbox(&state[0])?;
+    }
+    Ok(())
+}
+
+/// In-circuit counterpart of [`crate::poseidon2::Poseidon2Sponge`]. Holds
+/// the same duplex-sponge bookkeeping (`mode`) so `absorb`/`squeeze`
+/// sequences interleave identically to the native sponge's.
+pub struct Poseidon2SpongeVar<F: PrimeField> {
+    parameters: PoseidonConfig<F>,
+    state: Vec<FpVar<F>>,
+    mode: DuplexSpongeMode,
+}
+
+impl<F: PrimeField> Poseidon2SpongeVar<F> {
+    /// Create a new gadget over `parameters`, with the state initialized to
+    /// the zero constants the native sponge starts from.
+    pub fn new(parameters: &PoseidonConfig<F>) -> Self {
+        let state = vec![FpVar::constant(F::zero()); parameters.rate + parameters.capacity];
+        Self {
+            parameters: parameters.clone(),
+            state,
+            mode: DuplexSpongeMode::Absorbing {
+                next_absorb_index: 0,
+            },
+        }
+    }
+
+    fn permute(&mut self) -> Result<(), SynthesisError> {
+        let rf = self.parameters.full_rounds;
+        let rp = self.parameters.partial_rounds;
+        let d = self.parameters.d;
+        let mu = self.parameters.mu.clone();
+
+        matmul_external_var(&mut self.state)?;
+
+        let fr_half = rf / 2;
+        for r in 0..fr_half {
+            for (i, lane) in self.state.iter_mut().enumerate() {
+                *lane = lane.clone() + FpVar::constant(self.parameters.ark[r][i]);
+            }
+            apply_s_box_var(&mut self.state, true, d)?;
+            matmul_external_var(&mut self.state)?;
+        }
+
+        for r in fr_half..(fr_half + rp) {
+            self.state[0] = self.state[0].clone() + FpVar::constant(self.parameters.ark[r][0]);
+            apply_s_box_var(&mut self.state, false, d)?;
+            matmul_internal_with_mu_var(&mut self.state, &mu)?;
+        }
+
+        for r in (fr_half + rp)..(rf + rp) {
+            for (i, lane) in self.state.iter_mut().enumerate() {
+                *lane = lane.clone() + FpVar::constant(self.parameters.ark[r][i]);
+            }
+            apply_s_box_var(&mut self.state, true, d)?;
+            matmul_external_var(&mut self.state)?;
+        }
+
+        Ok(())
+    }
+
+    fn absorb_internal(
+        &mut self,
+        mut rate_start_index: usize,
+        elements: &[FpVar<F>],
+    ) -> Result<(), SynthesisError> {
+        let mut remaining = elements;
+        loop {
+            if rate_start_index + remaining.len() <= self.parameters.rate {
+                for (i, element) in remaining.iter().enumerate() {
+                    let idx = self.parameters.capacity + i + rate_start_index;
+                    self.state[idx] = self.state[idx].clone() + element.clone();
+                }
+                self.mode = DuplexSpongeMode::Absorbing {
+                    next_absorb_index: rate_start_index + remaining.len(),
+                };
+                return Ok(());
+            }
+            let num_absorbed = self.parameters.rate - rate_start_index;
+            for (i, element) in remaining.iter().enumerate().take(num_absorbed) {
+                let idx = self.parameters.capacity + i + rate_start_index;
+                self.state[idx] = self.state[idx].clone() + element.clone();
+            }
+            self.permute()?;
+            remaining = &remaining[num_absorbed..];
+            rate_start_index = 0;
+        }
+    }
+
+    fn squeeze_internal(
+        &mut self,
+        mut rate_start_index: usize,
+        output: &mut [FpVar<F>],
+    ) -> Result<(), SynthesisError> {
+        let mut offset = 0usize;
+        let mut remaining_len = output.len();
+        loop {
+            if rate_start_index + remaining_len <= self.parameters.rate {
+                for k in 0..remaining_len {
+                    output[offset + k] =
+                        self.state[self.parameters.capacity + rate_start_index + k].clone();
+                }
+                self.mode = DuplexSpongeMode::Squeezing {
+                    next_squeeze_index: rate_start_index + remaining_len,
+                };
+                return Ok(());
+            }
+            let num_squeezed = self.parameters.rate - rate_start_index;
+            for k in 0..num_squeezed {
+                output[offset + k] =
+                    self.state[self.parameters.capacity + rate_start_index + k].clone();
+            }
+            offset += num_squeezed;
+            remaining_len -= num_squeezed;
+            if remaining_len > 0 {
+                self.permute()?;
+            }
+            rate_start_index = 0;
+        }
+    }
+
+    /// Absorb `inputs`, permuting whenever the rate region fills up —
+    /// matches [`crate::poseidon2::Poseidon2Sponge`]'s `CryptographicSponge::absorb`.
+    pub fn absorb(&mut self, inputs: &[FpVar<F>]) -> Result<(), SynthesisError> {
+        if inputs.is_empty() {
+            return Ok(());
+        }
+        match self.mode {
+            DuplexSpongeMode::Absorbing { next_absorb_index } => {
+                let mut absorb_index = next_absorb_index;
+                if absorb_index == self.parameters.rate {
+                    self.permute()?;
+                    absorb_index = 0;
+                }
+                self.absorb_internal(absorb_index, inputs)
+            }
+            DuplexSpongeMode::Squeezing { .. } => self.absorb_internal(0, inputs),
+        }
+    }
+
+    /// Squeeze `n` elements, permuting as needed — matches
+    /// [`crate::poseidon2::Poseidon2Sponge`]'s `squeeze_native_field_elements`.
+    pub fn squeeze(&mut self, n: usize) -> Result<Vec<FpVar<F>>, SynthesisError> {
+        let mut out = vec![FpVar::constant(F::zero()); n];
+        match self.mode {
+            DuplexSpongeMode::Absorbing { .. } => {
+                self.permute()?;
+                self.squeeze_internal(0, &mut out)?;
+            }
+            DuplexSpongeMode::Squeezing { next_squeeze_index } => {
+                let mut squeeze_index = next_squeeze_index;
+                if squeeze_index == self.parameters.rate {
+                    self.permute()?;
+                    squeeze_index = 0;
+                }
+                self.squeeze_internal(squeeze_index, &mut out)?;
+            }
+        }
+        Ok(out)
+    }
+
+    /// In-circuit counterpart of [`crate::poseidon2::Poseidon2Sponge::compress`].
+    pub fn compress<const N: usize>(&self, inputs: [FpVar<F>; N]) -> Result<FpVar<F>, SynthesisError> {
+        assert_eq!(self.parameters.capacity, 1, "compress expects capacity=1");
+        assert_eq!(self.parameters.rate, N, "compress requires rate == N");
+
+        let mut state: Vec<FpVar<F>> = Vec::with_capacity(N + 1);
+        state.push(FpVar::constant(F::zero()));
+        state.extend(inputs);
+
+        let rf = self.parameters.full_rounds;
+        let rp = self.parameters.partial_rounds;
+        let d = self.parameters.d;
+        let mu = &self.parameters.mu;
+
+        matmul_external_var(&mut state)?;
+
+        let fr_half = rf / 2;
+        for r in 0..fr_half {
+            for (i, lane) in state.iter_mut().enumerate() {
+                *lane = lane.clone() + FpVar::constant(self.parameters.ark[r][i]);
+            }
+            apply_s_box_var(&mut state, true, d)?;
+            matmul_external_var(&mut state)?;
+        }
+
+        for r in fr_half..(fr_half + rp) {
+            state[0] = state[0].clone() + FpVar::constant(self.parameters.ark[r][0]);
+            apply_s_box_var(&mut state, false, d)?;
+            matmul_internal_with_mu_var(&mut state, mu)?;
+        }
+
+        for r in (fr_half + rp)..(rf + rp) {
+            for (i, lane) in state.iter_mut().enumerate() {
+                *lane = lane.clone() + FpVar::constant(self.parameters.ark[r][i]);
+            }
+            apply_s_box_var(&mut state, true, d)?;
+            matmul_external_var(&mut state)?;
+        }
+
+        Ok(state[0].clone())
+    }
+
+    /// Compress 3 field elements into 1; requires `rate == 3`.
+    pub fn compress_3(
+        &self,
+        x0: FpVar<F>,
+        x1: FpVar<F>,
+        x2: FpVar<F>,
+    ) -> Result<FpVar<F>, SynthesisError> {
+        self.compress([x0, x1, x2])
+    }
+
+    /// Compress 2 field elements into 1; requires `rate == 2`.
+    pub fn compress_2(&self, x0: FpVar<F>, x1: FpVar<F>) -> Result<FpVar<F>, SynthesisError> {
+        self.compress([x0, x1])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parameters::poseidon2_pallas::PALLAS_POSEIDON2_PARAMS_T4;
+    use crate::poseidon2::Poseidon2Sponge;
+    use ark_crypto_primitives::sponge::{CryptographicSponge, FieldBasedCryptographicSponge};
+    use ark_relations::r1cs::ConstraintSystem;
+
+    type F = ark_pallas::Fq;
+
+    /// Drives the same interleaved absorb/squeeze sequence through the
+    /// native sponge and this gadget over Pallas T4 parameters, and asserts
+    /// every squeezed value agrees — analogous to how halo2-lib's own
+    /// Poseidon gadget is checked against its native reference.
+    #[test]
+    fn gadget_matches_native_on_interleaved_absorb_squeeze() {
+        let params = PALLAS_POSEIDON2_PARAMS_T4.clone();
+        let cs = ConstraintSystem::<F>::new_ref();
+
+        let mut native = Poseidon2Sponge::<F>::new(&params);
+        let mut gadget = Poseidon2SpongeVar::<F>::new(&params);
+
+        let witness = |x: F| FpVar::new_witness(cs.clone(), || Ok(x)).unwrap();
+
+        // Round 1: absorb 2 elements (partial rate block), squeeze 1.
+        let batch1 = [F::from(1u64), F::from(2u64)];
+        native.absorb(&batch1.to_vec());
+        gadget
+            .absorb(&batch1.map(witness).to_vec())
+            .unwrap();
+
+        let native_out1 = native.squeeze_native_field_elements(1);
+        let gadget_out1 = gadget.squeeze(1).unwrap();
+        assert_eq!(gadget_out1[0].value().unwrap(), native_out1[0]);
+
+        // Round 2: absorb exactly one rate block (3 elements) right after a
+        // squeeze, which restarts absorption at rate index 0 without a
+        // permute, then squeeze 2 (one permute, and 2 <= rate=3 fits in a
+        // single block, so this exercises the fits-in-one-call path).
+        let batch2 = [F::from(3u64), F::from(4u64), F::from(5u64)];
+        native.absorb(&batch2.to_vec());
+        gadget
+            .absorb(&batch2.map(witness).to_vec())
+            .unwrap();
+
+        let native_out2 = native.squeeze_native_field_elements(2);
+        let gadget_out2 = gadget.squeeze(2).unwrap();
+        assert_eq!(gadget_out2[0].value().unwrap(), native_out2[0]);
+        assert_eq!(gadget_out2[1].value().unwrap(), native_out2[1]);
+
+        assert!(cs.is_satisfied().unwrap());
+    }
+
+    #[test]
+    fn compress_3_matches_native() {
+        let params = PALLAS_POSEIDON2_PARAMS_T4.clone();
+        let cs = ConstraintSystem::<F>::new_ref();
+
+        let native = Poseidon2Sponge::<F>::new(&params);
+        let gadget = Poseidon2SpongeVar::<F>::new(&params);
+
+        let a = F::from(10u64);
+        let b = F::from(20u64);
+        let c = F::from(30u64);
+
+        let expected = native.compress_3(a, b, c);
+
+        let av = FpVar::new_witness(cs.clone(), || Ok(a)).unwrap();
+        let bv = FpVar::new_witness(cs.clone(), || Ok(b)).unwrap();
+        let cv = FpVar::new_witness(cs.clone(), || Ok(c)).unwrap();
+        let out = gadget.compress_3(av, bv, cv).unwrap();
+
+        assert_eq!(out.value().unwrap(), expected);
+        assert!(cs.is_satisfied().unwrap());
+    }
+}
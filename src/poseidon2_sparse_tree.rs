@@ -0,0 +1,289 @@
+//! Sparse, incrementally-updatable Poseidon2 Merkle tree with precomputed
+//! empty-subtree hashes.
+//!
+//! Unlike [`crate::poseidon2_tree::Poseidon2MerkleTree`], which materializes
+//! every layer of a full `2^depth`-leaf tree from a complete leaf set,
+//! [`MerkleTree`] only stores nodes actually touched by an
+//! [`MerkleTree::insert`] and falls back to a cached all-empty-subtree hash
+//! (one per level) for everything else. That makes a single `insert` into a
+//! large, sparsely-populated tree (e.g. an RLN/Semaphore membership set)
+//! cost O(depth) rather than O(2^depth).
+
+use std::collections::HashMap;
+
+use ark_ff::PrimeField;
+
+use crate::poseidon2::Poseidon2Sponge;
+
+/// Inclusion proof for a [`MerkleTree`] leaf: the sibling hash at each level
+/// from the leaf up to (but not including) the root.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MerkleProof<F> {
+    /// Index of the leaf this proof is for.
+    pub leaf_index: usize,
+    /// Sibling hash at each level, from the leaf level up to the root.
+    pub siblings: Vec<F>,
+}
+
+impl<F: PrimeField> MerkleProof<F> {
+    /// Verify that this proof places `leaf` under `root`, recomputing the
+    /// path with `sponge`'s 2-to-1 compression function.
+    pub fn verify(&self, root: F, leaf: F, sponge: &Poseidon2Sponge<F>) -> bool {
+        let mut current = leaf;
+        let mut idx = self.leaf_index;
+        for sibling in &self.siblings {
+            current = if idx % 2 == 0 {
+                sponge.compress_2(current, *sibling)
+            } else {
+                sponge.compress_2(*sibling, current)
+            };
+            idx /= 2;
+        }
+        current == root
+    }
+}
+
+/// Sparse, incrementally-updatable binary Merkle tree over a Poseidon2
+/// 2-to-1 compression function ([`Poseidon2Sponge::compress_2`]).
+///
+/// The tree always has `2^depth` leaves, defaulting to `F::zero()`; unset
+/// nodes are never materialized individually, only the `depth + 1` cached
+/// empty-subtree hashes are. Requires `sponge`'s parameters to have
+/// `rate == 2, capacity == 1`.
+pub struct MerkleTree<F: PrimeField> {
+    sponge: Poseidon2Sponge<F>,
+    depth: usize,
+    /// Hash of an all-empty subtree at each level; `empty_hashes[0]` is the
+    /// default leaf and `empty_hashes[depth]` the empty tree's root.
+    empty_hashes: Vec<F>,
+    /// Sparse `(level, index) -> hash` map of every node touched by an
+    /// [`Self::insert`]; anything absent falls back to `empty_hashes[level]`.
+    nodes: HashMap<(usize, usize), F>,
+    /// Next free leaf slot for [`Self::append`].
+    next_index: usize,
+}
+
+impl<F: PrimeField> MerkleTree<F> {
+    /// Build an empty tree of `depth` levels (`2^depth` leaves, all
+    /// initialized to `F::zero()`), using `sponge`'s parameters for node
+    /// compression.
+    pub fn new(depth: usize, sponge: &Poseidon2Sponge<F>) -> Self {
+        assert_eq!(
+            sponge.parameters.rate, 2,
+            "MerkleTree requires rate == 2 (2-to-1 compression)"
+        );
+        assert_eq!(sponge.parameters.capacity, 1, "MerkleTree expects capacity == 1");
+
+        let mut empty_hashes = Vec::with_capacity(depth + 1);
+        let mut level_value = F::zero();
+        empty_hashes.push(level_value);
+        for _ in 0..depth {
+            level_value = sponge.compress_2(level_value, level_value);
+            empty_hashes.push(level_value);
+        }
+
+        Self {
+            sponge: sponge.clone(),
+            depth,
+            empty_hashes,
+            nodes: HashMap::new(),
+            next_index: 0,
+        }
+    }
+
+    /// Total leaf capacity of this tree (`2^depth`).
+    fn capacity(&self) -> usize {
+        1usize << self.depth
+    }
+
+    /// Hash of the node at `(level, index)`, falling back to the cached
+    /// empty-subtree hash for that level if it was never touched.
+    fn node(&self, level: usize, index: usize) -> F {
+        *self
+            .nodes
+            .get(&(level, index))
+            .unwrap_or(&self.empty_hashes[level])
+    }
+
+    /// Insert `leaf` at `index`, recomputing only the `depth` ancestors on
+    /// the path to the root.
+    pub fn insert(&mut self, index: usize, leaf: F) {
+        assert!(index < self.capacity(), "leaf index out of range");
+        self.nodes.insert((0, index), leaf);
+
+        let mut idx = index;
+        let mut current = leaf;
+        for level in 0..self.depth {
+            let sibling = self.node(level, idx ^ 1);
+            current = if idx % 2 == 0 {
+                self.sponge.compress_2(current, sibling)
+            } else {
+                self.sponge.compress_2(sibling, current)
+            };
+            idx /= 2;
+            self.nodes.insert((level + 1, idx), current);
+        }
+    }
+
+    /// Insert `leaf` into the next empty slot and return its index.
+    ///
+    /// Unlike [`Self::insert`], callers don't need to track which indices
+    /// are already occupied — useful for membership sets (e.g.
+    /// RLN/Semaphore identity commitments) where leaves arrive one at a
+    /// time rather than at a pre-known index. Panics if the tree is full.
+    pub fn append(&mut self, leaf: F) -> usize {
+        assert!(self.next_index < self.capacity(), "tree is full");
+        let index = self.next_index;
+        self.insert(index, leaf);
+        self.next_index += 1;
+        index
+    }
+
+    /// This tree's depth (`log2` of the leaf count).
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    /// The current Merkle root.
+    pub fn root(&self) -> F {
+        self.node(self.depth, 0)
+    }
+
+    /// Build an inclusion proof for the leaf currently at `index`.
+    pub fn proof(&self, index: usize) -> MerkleProof<F> {
+        assert!(index < self.capacity(), "leaf index out of range");
+        let mut siblings = Vec::with_capacity(self.depth);
+        let mut idx = index;
+        for level in 0..self.depth {
+            siblings.push(self.node(level, idx ^ 1));
+            idx /= 2;
+        }
+        MerkleProof {
+            leaf_index: index,
+            siblings,
+        }
+    }
+}
+
+/// Verify a [`MerkleTree::proof`] against `root`, independent of any
+/// particular tree instance — a free-function equivalent of
+/// [`MerkleProof::verify`] for callers that prefer the `verify(root, leaf,
+/// proof)` calling convention [`crate::tree::verify`] also uses.
+pub fn verify<F: PrimeField>(root: F, leaf: F, proof: &MerkleProof<F>, sponge: &Poseidon2Sponge<F>) -> bool {
+    proof.verify(root, leaf, sponge)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parameters::poseidon2_pallas::PALLAS_POSEIDON2_PARAMS;
+    use crate::poseidon2::PoseidonConfig;
+
+    type F = ark_pallas::Fq;
+
+    fn params() -> PoseidonConfig<F> {
+        PALLAS_POSEIDON2_PARAMS.clone()
+    }
+
+    #[test]
+    fn empty_tree_root_matches_manual_empty_compression() {
+        let sponge = Poseidon2Sponge::new(&params());
+        let tree = MerkleTree::new(3, &sponge);
+
+        let mut expected = F::zero();
+        for _ in 0..3 {
+            expected = sponge.compress_2(expected, expected);
+        }
+        assert_eq!(tree.root(), expected);
+    }
+
+    #[test]
+    fn insert_and_proof_round_trip() {
+        let sponge = Poseidon2Sponge::new(&params());
+        let mut tree = MerkleTree::new(3, &sponge);
+
+        let leaf = F::from(42u64);
+        tree.insert(5, leaf);
+        let root = tree.root();
+
+        let proof = tree.proof(5);
+        assert!(proof.verify(root, leaf, &sponge));
+        assert!(!proof.verify(root, F::from(7u64), &sponge));
+    }
+
+    #[test]
+    fn stale_proof_fails_and_fresh_proof_verifies_after_updating_a_sibling_path() {
+        let sponge = Poseidon2Sponge::new(&params());
+        let mut tree = MerkleTree::new(3, &sponge);
+
+        let leaf = F::from(1u64);
+        tree.insert(2, leaf);
+        let proof = tree.proof(2);
+        assert!(proof.verify(tree.root(), leaf, &sponge));
+
+        // Updating an unrelated leaf changes the root, so the old proof must
+        // fail against it...
+        tree.insert(6, F::from(99u64));
+        let stale_root = tree.root();
+        assert!(!proof.verify(stale_root, leaf, &sponge));
+
+        // ...but a freshly generated proof against the new root still
+        // verifies, since `insert(6, ..)` never touched leaf 2's own value.
+        let fresh_proof = tree.proof(2);
+        assert!(fresh_proof.verify(stale_root, leaf, &sponge));
+    }
+
+    #[test]
+    fn sparse_tree_matches_full_tree_with_same_leaves() {
+        use crate::poseidon2_tree::Poseidon2MerkleTree;
+
+        let sponge = Poseidon2Sponge::new(&params());
+        let mut sparse = MerkleTree::new(2, &sponge);
+
+        let leaves: Vec<F> = (0..4u64).map(F::from).collect();
+        for (i, leaf) in leaves.iter().enumerate() {
+            sparse.insert(i, *leaf);
+        }
+
+        let full = Poseidon2MerkleTree::new_from_leaves(leaves, params());
+        assert_eq!(sparse.root(), full.root());
+    }
+
+    #[test]
+    fn append_fills_slots_in_order_and_matches_insert() {
+        let sponge = Poseidon2Sponge::new(&params());
+        let mut appended = MerkleTree::new(3, &sponge);
+        let mut inserted = MerkleTree::new(3, &sponge);
+        for i in 0..8u64 {
+            let leaf = F::from(i);
+            let index = appended.append(leaf);
+            assert_eq!(index, i as usize);
+            inserted.insert(i as usize, leaf);
+        }
+        assert_eq!(appended.root(), inserted.root());
+    }
+
+    #[test]
+    #[should_panic(expected = "tree is full")]
+    fn append_past_capacity_panics() {
+        let sponge = Poseidon2Sponge::new(&params());
+        let mut tree = MerkleTree::new(1, &sponge);
+        tree.append(F::from(1u64));
+        tree.append(F::from(2u64));
+        tree.append(F::from(3u64));
+    }
+
+    #[test]
+    fn free_verify_matches_method_verify() {
+        let sponge = Poseidon2Sponge::new(&params());
+        let mut tree = MerkleTree::new(3, &sponge);
+
+        let leaf = F::from(42u64);
+        tree.insert(5, leaf);
+        let root = tree.root();
+        let proof = tree.proof(5);
+
+        assert!(verify(root, leaf, &proof, &sponge));
+        assert!(!verify(root, F::from(7u64), &proof, &sponge));
+    }
+}
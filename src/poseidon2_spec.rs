@@ -0,0 +1,431 @@
+//! Const-generic Poseidon2 permutation core.
+//!
+//! [`crate::poseidon2::Poseidon2Sponge`]/[`crate::poseidon2::PoseidonConfig`]
+//! carry the state width and round constants as runtime `Vec`s, which forces
+//! length assertions on every call and a heap allocation per permutation.
+//! This module provides an additive, allocation-free alternative: a
+//! [`Spec`] trait supplying the round structure and constants for a fixed,
+//! compile-time state width `T` (mirroring how the Orchard/halo2 Poseidon
+//! primitive structures its permutation around a `Spec` trait and a
+//! const-generic width), and a [`Permutation`] type whose state is a plain
+//! `[F; T]` array rather than a `Vec<F>`.
+//!
+//! The existing runtime-sized sponge remains the primary, general-purpose
+//! API; reach for this module when the width is known at compile time and
+//! the per-permutation allocation matters.
+
+use ark_ff::PrimeField;
+use std::marker::PhantomData;
+
+/// Supplies the round structure and constants for a Poseidon2 instance over
+/// a state of `T` field elements, `RATE = T - 1` (capacity is always 1, as
+/// throughout this crate).
+pub trait Spec<F: PrimeField, const T: usize, const RATE: usize> {
+    /// Number of full rounds, split evenly before and after the partial rounds.
+    fn full_rounds() -> usize;
+    /// Number of partial rounds.
+    fn partial_rounds() -> usize;
+    /// S-box exponent.
+    fn sbox_exp() -> u64;
+    /// Additive round constants, indexed `[round][lane]`, one row per round
+    /// (`full_rounds() + partial_rounds()` rows).
+    fn round_constants() -> Vec<[F; T]>;
+    /// Internal-matrix diagonal μ for the cheap internal matrix `J + Diag(mu)`.
+    fn internal_diagonal() -> [F; T];
+}
+
+fn apply_s_box<F: PrimeField, const T: usize>(state: &mut [F; T], is_full_round: bool, d: u64) {
+    let sbox_p = |input: F| -> F {
+        match d {
+            3 => input * input * input,
+            5 => {
+                let sq = input * input;
+                sq * sq * input
+            }
+            7 => {
+                let sq = input * input;
+                let quad = sq * sq;
+                quad * sq * input
+            }
+            _ => panic!("unsupported Poseidon2 s-box exponent {d}"),
+        }
+    };
+
+    if is_full_round {
+        for elem in state.iter_mut() {
+            *elem = sbox_p(*elem);
+        }
+    } else {
+        state[0] = sbox_p(state[0]);
+    }
+}
+
+fn matmul_m4<F: PrimeField, const T: usize>(state: &mut [F; T]) {
+    let t4 = T / 4;
+    for i in 0..t4 {
+        let s = i * 4;
+        let t0 = state[s] + state[s + 1];
+        let t1 = state[s + 2] + state[s + 3];
+        let t2 = state[s + 1].double() + t1;
+        let t3 = state[s + 3].double() + t0;
+        let t4_ = t1.double().double() + t3;
+        let t5 = t0.double().double() + t2;
+        let t6 = t3 + t5;
+        let t7 = t2 + t4_;
+        state[s] = t6;
+        state[s + 1] = t5;
+        state[s + 2] = t7;
+        state[s + 3] = t4_;
+    }
+}
+
+/// External (cheap MDS) linear layer. `T` must be one of `{2, 3, 4, 8}` —
+/// the only widths [`Spec`] is implemented for in this crate.
+fn matmul_external<F: PrimeField, const T: usize>(state: &mut [F; T]) {
+    match T {
+        2 => {
+            let sum = state[0] + state[1];
+            state[0] += sum;
+            state[1] += sum;
+        }
+        3 => {
+            let sum = state[0] + state[1] + state[2];
+            state[0] += sum;
+            state[1] += sum;
+            state[2] += sum;
+        }
+        4 => matmul_m4(state),
+        8 => {
+            matmul_m4(state);
+            let mut stored = [F::zero(); 4];
+            for (l, slot) in stored.iter_mut().enumerate() {
+                *slot = state[l];
+                for j in 1..(T / 4) {
+                    *slot += state[4 * j + l];
+                }
+            }
+            for i in 0..T {
+                state[i] += stored[i % 4];
+            }
+        }
+        _ => unreachable!("Spec is only implemented for T in {{2, 3, 4, 8}}"),
+    }
+}
+
+/// Internal (cheap, diagonal) linear layer: `y_i = (sum x_j) + mu_i * x_i`
+/// for `T` in `{4, 8}`. For `T = 2` and `T = 3` the fixed diagonals
+/// `[1, 2]` and `[1, 1, 2]` are used and `mu` is ignored, as in
+/// [`crate::poseidon2::Poseidon2Sponge`].
+fn matmul_internal_with_mu<F: PrimeField, const T: usize>(state: &mut [F; T], mu: &[F; T]) {
+    match T {
+        2 => {
+            let sum = state[0] + state[1];
+            state[0] += sum;
+            state[1] = state[1].double() + sum;
+        }
+        3 => {
+            let sum = state[0] + state[1] + state[2];
+            state[0] += sum;
+            state[1] += sum;
+            state[2] = state[2].double() + sum;
+        }
+        4 | 8 => {
+            let mut sum = state[0];
+            for lane in state.iter().skip(1) {
+                sum += lane;
+            }
+            for i in 0..T {
+                state[i] = state[i] * mu[i] + sum;
+            }
+        }
+        _ => unreachable!("Spec is only implemented for T in {{2, 3, 4, 8}}"),
+    }
+}
+
+/// Run one full Poseidon2 permutation over `state` using the round
+/// structure and constants supplied by `S`.
+pub fn permute<F, S, const T: usize, const RATE: usize>(state: &mut [F; T])
+where
+    F: PrimeField,
+    S: Spec<F, T, RATE>,
+{
+    let rf = S::full_rounds();
+    let rp = S::partial_rounds();
+    let d = S::sbox_exp();
+    let ark = S::round_constants();
+    let mu = S::internal_diagonal();
+
+    matmul_external(state);
+
+    let fr_half = rf / 2;
+    for row in ark.iter().take(fr_half) {
+        for (lane, constant) in state.iter_mut().zip(row.iter()) {
+            *lane += constant;
+        }
+        apply_s_box(state, true, d);
+        matmul_external(state);
+    }
+
+    for row in ark.iter().take(fr_half + rp).skip(fr_half) {
+        state[0] += row[0];
+        apply_s_box(state, false, d);
+        matmul_internal_with_mu(state, &mu);
+    }
+
+    for row in ark.iter().take(rf + rp).skip(fr_half + rp) {
+        for (lane, constant) in state.iter_mut().zip(row.iter()) {
+            *lane += constant;
+        }
+        apply_s_box(state, true, d);
+        matmul_external(state);
+    }
+}
+
+/// Run the Poseidon2 permutation over every state in `states`, in place.
+///
+/// A plain scalar batch helper for callers that need to permute many
+/// independent states at once — e.g. [`crate::merkle::MerkleTree`] hashing a
+/// level's worth of sibling pairs, or any multi-leaf hashing loop — so they
+/// can write one call instead of a `for` loop. It does not vectorize: each
+/// state is permuted one at a time via [`permute`], with no speed-up over
+/// that loop. This crate's field arithmetic is generic over any
+/// [`ark_ff::PrimeField`], so there is no portable way to batch
+/// `x^5`/MDS-mixing across lanes without a hand-written kernel tied to a
+/// specific field's limb layout; no such kernel exists here today.
+pub fn permute_many<F, S, const T: usize, const RATE: usize>(states: &mut [[F; T]])
+where
+    F: PrimeField,
+    S: Spec<F, T, RATE>,
+{
+    for state in states.iter_mut() {
+        permute::<F, S, T, RATE>(state);
+    }
+}
+
+/// A Poseidon2 permutation instance over a fixed-size `[F; T]` state — no
+/// heap allocation per permutation, unlike [`crate::poseidon2::Poseidon2Sponge`].
+#[derive(Clone)]
+pub struct Permutation<F: PrimeField, S, const T: usize, const RATE: usize> {
+    state: [F; T],
+    _spec: PhantomData<S>,
+}
+
+impl<F, S, const T: usize, const RATE: usize> Default for Permutation<F, S, T, RATE>
+where
+    F: PrimeField,
+    S: Spec<F, T, RATE>,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<F, S, const T: usize, const RATE: usize> Permutation<F, S, T, RATE>
+where
+    F: PrimeField,
+    S: Spec<F, T, RATE>,
+{
+    /// Create a permutation instance with a zeroed state.
+    pub fn new() -> Self {
+        Self {
+            state: [F::zero(); T],
+            _spec: PhantomData,
+        }
+    }
+
+    /// Create a permutation instance with the given initial state.
+    pub fn from_state(state: [F; T]) -> Self {
+        Self {
+            state,
+            _spec: PhantomData,
+        }
+    }
+
+    /// Create a permutation instance from `state`, first asserting that
+    /// `config`'s `rate`/`t` (rate + capacity) agree with this instance's
+    /// compile-time `RATE`/`T` — useful when swapping a runtime-configured
+    /// [`crate::poseidon2::Poseidon2Sponge`] call site for its
+    /// allocation-free, const-generic counterpart and wanting a clear error
+    /// if the wrong `Spec` was picked for that `config`.
+    pub fn from_config(state: [F; T], config: &crate::poseidon2::PoseidonConfig<F>) -> Self {
+        assert_eq!(
+            config.rate, RATE,
+            "PoseidonConfig rate does not match this Spec's compile-time RATE"
+        );
+        assert_eq!(
+            config.rate + config.capacity,
+            T,
+            "PoseidonConfig t (rate + capacity) does not match this Spec's compile-time T"
+        );
+        Self::from_state(state)
+    }
+
+    /// The current state.
+    pub fn state(&self) -> &[F; T] {
+        &self.state
+    }
+
+    /// Run one Poseidon2 permutation over the current state in place.
+    pub fn permute(&mut self) {
+        permute::<F, S, T, RATE>(&mut self.state);
+    }
+}
+
+/// [`Spec`] for the Pallas base field at `T = 2` (rate = 1).
+pub struct PallasSpecT2;
+
+/// [`Spec`] for the Pallas base field at `T = 3` (rate = 2) — the default
+/// width used by [`crate::poseidon2::Poseidon2Sponge`] in this crate.
+pub struct PallasSpecT3;
+
+/// [`Spec`] for the Pallas base field at `T = 4` (rate = 3), matching
+/// [`crate::parameters::poseidon2_pallas::PALLAS_POSEIDON2_PARAMS_T4`] and
+/// [`crate::poseidon2::Poseidon2Sponge::compress_3`].
+pub struct PallasSpecT4;
+
+/// [`Spec`] for the Pallas base field at `T = 8` (rate = 7).
+pub struct PallasSpecT8;
+
+macro_rules! impl_pallas_spec {
+    ($spec:ty, $t:expr, $rate:expr, $params:path) => {
+        impl Spec<ark_pallas::Fq, $t, $rate> for $spec {
+            fn full_rounds() -> usize {
+                $params.full_rounds
+            }
+
+            fn partial_rounds() -> usize {
+                $params.partial_rounds
+            }
+
+            fn sbox_exp() -> u64 {
+                $params.d
+            }
+
+            fn round_constants() -> Vec<[ark_pallas::Fq; $t]> {
+                $params
+                    .ark
+                    .iter()
+                    .map(|row| {
+                        row.clone()
+                            .try_into()
+                            .expect("Poseidon2 param round-constant row must have width T")
+                    })
+                    .collect()
+            }
+
+            fn internal_diagonal() -> [ark_pallas::Fq; $t] {
+                $params
+                    .mu
+                    .clone()
+                    .try_into()
+                    .expect("Poseidon2 param mu must have width T")
+            }
+        }
+    };
+}
+
+impl_pallas_spec!(
+    PallasSpecT2,
+    2,
+    1,
+    crate::parameters::poseidon2_pallas::PALLAS_POSEIDON2_PARAMS_T2
+);
+impl_pallas_spec!(
+    PallasSpecT3,
+    3,
+    2,
+    crate::parameters::poseidon2_pallas::PALLAS_POSEIDON2_PARAMS
+);
+impl_pallas_spec!(
+    PallasSpecT4,
+    4,
+    3,
+    crate::parameters::poseidon2_pallas::PALLAS_POSEIDON2_PARAMS_T4
+);
+impl_pallas_spec!(
+    PallasSpecT8,
+    8,
+    7,
+    crate::parameters::poseidon2_pallas::PALLAS_POSEIDON2_PARAMS_T8
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parameters::poseidon2_pallas::PALLAS_POSEIDON2_PARAMS_T4;
+    use crate::poseidon2::Poseidon2Sponge;
+    use ark_ff::Zero;
+
+    type F = ark_pallas::Fq;
+
+    #[test]
+    fn const_generic_t4_permutation_matches_runtime_sponge() {
+        let a = F::from(1u64);
+        let b = F::from(2u64);
+        let c = F::from(3u64);
+
+        let mut fixed = Permutation::<F, PallasSpecT4, 4, 3>::from_state([a, b, c, F::zero()]);
+        fixed.permute();
+
+        let sponge = Poseidon2Sponge::<F>::new(&*PALLAS_POSEIDON2_PARAMS_T4);
+        let mut runtime_state = [a, b, c, F::zero()];
+        sponge.permute_state_for_test(&mut runtime_state);
+
+        assert_eq!(fixed.state(), &runtime_state);
+    }
+
+    #[test]
+    fn const_generic_permutation_is_deterministic() {
+        let mut p1 = Permutation::<F, PallasSpecT3, 3, 2>::from_state([
+            F::from(4u64),
+            F::from(5u64),
+            F::from(6u64),
+        ]);
+        let mut p2 = p1.clone();
+        p1.permute();
+        p2.permute();
+        assert_eq!(p1.state(), p2.state());
+    }
+
+    #[test]
+    fn default_permutation_starts_from_zero_state() {
+        let perm = Permutation::<F, PallasSpecT2, 2, 1>::new();
+        assert_eq!(perm.state(), &[F::zero(), F::zero()]);
+    }
+
+    #[test]
+    fn from_config_matches_from_state_for_a_compatible_config() {
+        let state = [F::from(1u64), F::from(2u64), F::from(3u64), F::zero()];
+        let perm = Permutation::<F, PallasSpecT4, 4, 3>::from_config(
+            state,
+            &PALLAS_POSEIDON2_PARAMS_T4,
+        );
+        assert_eq!(perm.state(), &state);
+    }
+
+    #[test]
+    #[should_panic(expected = "does not match this Spec's compile-time RATE")]
+    fn from_config_rejects_a_mismatched_config() {
+        use crate::parameters::poseidon2_pallas::PALLAS_POSEIDON2_PARAMS;
+
+        let state = [F::from(1u64), F::from(2u64), F::from(3u64), F::zero()];
+        let _ = Permutation::<F, PallasSpecT4, 4, 3>::from_config(state, &PALLAS_POSEIDON2_PARAMS);
+    }
+
+    #[test]
+    fn permute_many_matches_permuting_each_state_individually() {
+        let mut batched = [
+            [F::from(1u64), F::from(2u64), F::from(3u64), F::zero()],
+            [F::from(4u64), F::from(5u64), F::from(6u64), F::zero()],
+            [F::from(7u64), F::from(8u64), F::from(9u64), F::zero()],
+        ];
+        let mut expected = batched;
+
+        permute_many::<F, PallasSpecT4, 4, 3>(&mut batched);
+        for state in expected.iter_mut() {
+            permute::<F, PallasSpecT4, 4, 3>(state);
+        }
+
+        assert_eq!(batched, expected);
+    }
+}
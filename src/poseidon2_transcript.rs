@@ -0,0 +1,181 @@
+//! Poseidon2-based Fiat–Shamir transcript for folding/IVC schemes
+//! (Nova/Sonobe-style), built directly on [`Poseidon2Sponge`]'s real
+//! rate/capacity split rather than the fixed-arity `compress_*` helpers.
+//!
+//! Every `challenge*` call re-absorbs its own squeezed output before
+//! returning it, so the transcript stays bound to every challenge it has
+//! ever produced (the standard sponge-based Fiat–Shamir construction) —
+//! unlike [`crate::transcript::CycleTranscript`], which is dual-curve and
+//! built on the streaming Poseidon1 hasher instead.
+
+use ark_crypto_primitives::sponge::{CryptographicSponge, FieldBasedCryptographicSponge};
+use ark_ec::AffineRepr;
+use ark_ff::{BigInteger, PrimeField};
+
+use crate::poseidon2::{Poseidon2Sponge, PoseidonConfig};
+
+/// A single-curve Poseidon2 Fiat–Shamir transcript over base field `F`.
+pub struct Poseidon2Transcript<F: PrimeField> {
+    sponge: Poseidon2Sponge<F>,
+}
+
+impl<F: PrimeField> Poseidon2Transcript<F> {
+    /// Create a new transcript from Poseidon2 parameters.
+    pub fn new(cfg: &PoseidonConfig<F>) -> Self {
+        Self {
+            sponge: Poseidon2Sponge::new(cfg),
+        }
+    }
+
+    /// Absorb a single field element.
+    pub fn absorb(&mut self, value: &F) {
+        self.sponge.absorb(value);
+    }
+
+    /// Absorb a slice of field elements, in order.
+    pub fn absorb_slice(&mut self, values: &[F]) {
+        self.sponge.absorb(&values.to_vec());
+    }
+
+    /// Absorb an affine curve point.
+    ///
+    /// A finite point `(x, y)` absorbs `[0, x, y]`; the point at infinity
+    /// absorbs `[1, 0, 0]`. The leading marker makes the two cases
+    /// unambiguous regardless of whether `(0, 0)` happens to lie on the
+    /// curve, rather than relying on a sentinel coordinate pair alone.
+    pub fn absorb_point<C>(&mut self, point: &C)
+    where
+        C: AffineRepr<BaseField = F>,
+    {
+        match point.xy() {
+            Some((x, y)) => {
+                self.absorb(&F::zero());
+                self.absorb(&x);
+                self.absorb(&y);
+            }
+            None => {
+                self.absorb(&F::one());
+                self.absorb(&F::zero());
+                self.absorb(&F::zero());
+            }
+        }
+    }
+
+    /// Squeeze a single challenge, then re-absorb it so later challenges
+    /// stay bound to it.
+    pub fn challenge(&mut self) -> F {
+        let out = self.sponge.squeeze_native_field_elements(1)[0];
+        self.absorb(&out);
+        out
+    }
+
+    /// Squeeze `n` challenges, one at a time, each re-absorbed before the
+    /// next is drawn.
+    pub fn challenge_vec(&mut self, n: usize) -> Vec<F> {
+        (0..n).map(|_| self.challenge()).collect()
+    }
+
+    /// Squeeze one challenge and return its low `n` bits, little-endian —
+    /// convenient for in-circuit-friendly bit challenges (e.g. folding
+    /// scheme combiners) without needing the full field element.
+    pub fn challenge_nbits(&mut self, n: usize) -> Vec<bool> {
+        let challenge = self.challenge();
+        let mut bits = challenge.into_bigint().to_bits_le();
+        bits.truncate(n);
+        bits
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parameters::poseidon2_pallas::PALLAS_POSEIDON2_PARAMS;
+
+    type F = ark_pallas::Fq;
+
+    fn transcript() -> Poseidon2Transcript<F> {
+        Poseidon2Transcript::new(&PALLAS_POSEIDON2_PARAMS)
+    }
+
+    #[test]
+    fn challenges_are_deterministic() {
+        let mut a = transcript();
+        let mut b = transcript();
+        a.absorb(&F::from(42u64));
+        b.absorb(&F::from(42u64));
+        assert_eq!(a.challenge(), b.challenge());
+    }
+
+    #[test]
+    fn absorbing_changes_the_challenge() {
+        let mut a = transcript();
+        let before = a.challenge();
+
+        let mut b = transcript();
+        b.absorb(&F::from(7u64));
+        let after = b.challenge();
+
+        assert_ne!(before, after);
+    }
+
+    #[test]
+    fn challenges_stay_bound_to_earlier_ones() {
+        // Two transcripts absorb the same first message but are then
+        // driven with a different number of challenge draws before a
+        // shared final absorb; the final challenge must differ because
+        // each draw re-absorbs into the state.
+        let mut a = transcript();
+        a.absorb(&F::from(1u64));
+        let _ = a.challenge();
+        a.absorb(&F::from(2u64));
+        let final_a = a.challenge();
+
+        let mut b = transcript();
+        b.absorb(&F::from(1u64));
+        b.absorb(&F::from(2u64));
+        let final_b = b.challenge();
+
+        assert_ne!(final_a, final_b);
+    }
+
+    #[test]
+    fn finite_point_and_infinity_absorb_differently() {
+        let mut a = transcript();
+        a.absorb_point(&ark_pallas::Affine::identity());
+        let inf_challenge = a.challenge();
+
+        let mut b = transcript();
+        b.absorb_point(&ark_pallas::Affine::generator());
+        let generator_challenge = b.challenge();
+
+        assert_ne!(inf_challenge, generator_challenge);
+    }
+
+    #[test]
+    fn challenge_vec_matches_repeated_challenge_calls() {
+        let mut a = transcript();
+        a.absorb(&F::from(3u64));
+        let vec_challenges = a.challenge_vec(3);
+
+        let mut b = transcript();
+        b.absorb(&F::from(3u64));
+        let individual: Vec<F> = (0..3).map(|_| b.challenge()).collect();
+
+        assert_eq!(vec_challenges, individual);
+    }
+
+    #[test]
+    fn challenge_nbits_matches_low_bits_of_the_field_challenge() {
+        let mut a = transcript();
+        a.absorb(&F::from(9u64));
+        let mut b = transcript();
+        b.absorb(&F::from(9u64));
+
+        let full = a.challenge();
+        let bits = b.challenge_nbits(16);
+
+        let mut expected = full.into_bigint().to_bits_le();
+        expected.truncate(16);
+        assert_eq!(bits, expected);
+    }
+}
@@ -0,0 +1,176 @@
+//! Fixed-depth binary Merkle tree using [`Poseidon2Sponge::compress`] as the
+//! 2-to-1 node-hashing primitive — mirrors how Poseidon2 is used as a fixed
+//! compression function in the Orchard nullifier/commitment tree.
+//!
+//! This is distinct from [`crate::tree::PoseidonTree`], which builds its
+//! node hash from the general-purpose streaming (Poseidon1) sponge; here
+//! the node hash is one Poseidon2 permutation via `compress`, not a duplex
+//! absorb/squeeze.
+
+use ark_crypto_primitives::sponge::CryptographicSponge;
+use ark_ff::PrimeField;
+
+use crate::poseidon2::{Poseidon2Sponge, PoseidonConfig};
+
+/// Fixed-depth binary Merkle tree whose node hash is
+/// [`Poseidon2Sponge::compress`] over each pair of children.
+///
+/// `parameters` must have `rate == 2, capacity == 1` (the 2-to-1
+/// compression case), and the leaf count must be a nonzero power of two.
+pub struct Poseidon2MerkleTree<F: PrimeField> {
+    sponge: Poseidon2Sponge<F>,
+    depth: usize,
+    /// `layers[0]` holds the leaves; `layers[depth]` holds the single root.
+    layers: Vec<Vec<F>>,
+}
+
+impl<F: PrimeField> Poseidon2MerkleTree<F> {
+    /// Build a tree from a full set of `2^depth` leaves, computing every
+    /// non-leaf layer bottom-up.
+    pub fn new_from_leaves(leaves: Vec<F>, parameters: PoseidonConfig<F>) -> Self {
+        assert_eq!(
+            parameters.rate, 2,
+            "Poseidon2MerkleTree requires rate == 2 (2-to-1 compression)"
+        );
+        assert_eq!(parameters.capacity, 1, "Poseidon2MerkleTree expects capacity == 1");
+        assert!(
+            !leaves.is_empty() && leaves.len().is_power_of_two(),
+            "leaf count must be a nonzero power of two, got {}",
+            leaves.len()
+        );
+
+        let depth = leaves.len().trailing_zeros() as usize;
+        let sponge = Poseidon2Sponge::new(&parameters);
+
+        let mut layers = Vec::with_capacity(depth + 1);
+        layers.push(leaves);
+        for level in 0..depth {
+            let prev = &layers[level];
+            let next: Vec<F> = prev
+                .chunks(2)
+                .map(|pair| sponge.compress([pair[0], pair[1]]))
+                .collect();
+            layers.push(next);
+        }
+
+        Self {
+            sponge,
+            depth,
+            layers,
+        }
+    }
+
+    /// This tree's depth (`log2` of the leaf count).
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    /// The current Merkle root.
+    pub fn root(&self) -> F {
+        self.layers[self.depth][0]
+    }
+
+    /// Sibling path for the leaf at `index`, from the leaf level up to (but
+    /// not including) the root — one sibling per level, `depth` entries.
+    pub fn authentication_path(&self, index: usize) -> Vec<F> {
+        assert!(index < self.layers[0].len(), "leaf index out of range");
+        let mut path = Vec::with_capacity(self.depth);
+        let mut idx = index;
+        for level in 0..self.depth {
+            path.push(self.layers[level][idx ^ 1]);
+            idx /= 2;
+        }
+        path
+    }
+
+    /// Verify an [`Self::authentication_path`] for `leaf` at `index` against
+    /// `root`, independent of any particular tree instance.
+    pub fn verify_path(
+        parameters: &PoseidonConfig<F>,
+        root: F,
+        leaf: F,
+        mut index: usize,
+        path: &[F],
+    ) -> bool {
+        let sponge = Poseidon2Sponge::new(parameters);
+        let mut current = leaf;
+        for sibling in path {
+            current = if index % 2 == 0 {
+                sponge.compress([current, *sibling])
+            } else {
+                sponge.compress([*sibling, current])
+            };
+            index /= 2;
+        }
+        current == root
+    }
+}
+
+/// One-shot Merkle root of `leaves` (a nonzero power-of-two-length vector),
+/// without retaining the intermediate layers.
+pub fn poseidon_merkle_root<F: PrimeField>(leaves: Vec<F>, parameters: PoseidonConfig<F>) -> F {
+    Poseidon2MerkleTree::new_from_leaves(leaves, parameters).root()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parameters::poseidon2_pallas::PALLAS_POSEIDON2_PARAMS;
+
+    type F = ark_pallas::Fq;
+
+    fn params() -> PoseidonConfig<F> {
+        PALLAS_POSEIDON2_PARAMS.clone()
+    }
+
+    #[test]
+    fn root_matches_manual_two_level_compression() {
+        let leaves: Vec<F> = (0..4u64).map(F::from).collect();
+        let tree = Poseidon2MerkleTree::new_from_leaves(leaves.clone(), params());
+
+        let sponge = Poseidon2Sponge::new(&params());
+        let left = sponge.compress([leaves[0], leaves[1]]);
+        let right = sponge.compress([leaves[2], leaves[3]]);
+        let expected_root = sponge.compress([left, right]);
+
+        assert_eq!(tree.root(), expected_root);
+        assert_eq!(tree.depth(), 2);
+    }
+
+    #[test]
+    fn poseidon_merkle_root_matches_tree_root() {
+        let leaves: Vec<F> = (0..8u64).map(F::from).collect();
+        let tree = Poseidon2MerkleTree::new_from_leaves(leaves.clone(), params());
+        assert_eq!(poseidon_merkle_root(leaves, params()), tree.root());
+    }
+
+    #[test]
+    fn authentication_path_round_trips_through_verify_path() {
+        let leaves: Vec<F> = (0..8u64).map(F::from).collect();
+        let tree = Poseidon2MerkleTree::new_from_leaves(leaves.clone(), params());
+        let root = tree.root();
+
+        let path = tree.authentication_path(5);
+        assert!(Poseidon2MerkleTree::verify_path(
+            &params(),
+            root,
+            leaves[5],
+            5,
+            &path,
+        ));
+        assert!(!Poseidon2MerkleTree::verify_path(
+            &params(),
+            root,
+            leaves[4],
+            5,
+            &path,
+        ));
+    }
+
+    #[test]
+    #[should_panic(expected = "power of two")]
+    fn non_power_of_two_leaf_count_panics() {
+        let leaves: Vec<F> = (0..3u64).map(F::from).collect();
+        Poseidon2MerkleTree::new_from_leaves(leaves, params());
+    }
+}
@@ -0,0 +1,282 @@
+//! Dynamic-size Poseidon Merkle tree with membership proofs.
+//!
+//! Unlike [`crate::tree::PoseidonTree`], which requires exactly `arity^depth`
+//! leaves fixed at construction, [`PoseidonMerkleTree`] accepts any non-empty
+//! number of leaves: at each level, an odd node out is paired with a
+//! duplicate of itself (rather than a fixed default/padding value) to form
+//! its parent. Node hashing is domain-separated and layer-tagged the same
+//! way as [`crate::tree::PoseidonTree`], just under its own tag so the two
+//! trees' node hashes never collide.
+//!
+//! ```rust
+//! use poseidon_hash::poseidon_merkle_tree::PallasMerkleTree;
+//!
+//! let leaves: Vec<_> = (0..5).map(ark_pallas::Fq::from).collect();
+//! let tree = PallasMerkleTree::new(leaves.clone());
+//! let proof = tree.proof(4);
+//! assert!(tree.verify_proof(tree.root(), leaves[4], &proof));
+//! ```
+
+use crate::ark_poseidon::ArkPoseidonConfig;
+use ark_crypto_primitives::sponge::{Absorb, CryptographicSponge, FieldBasedCryptographicSponge};
+use ark_ff::PrimeField;
+
+/// Domain tag absorbed before every node's children, separating this tree's
+/// node hashing both from the streaming hashers' domain and from
+/// [`crate::tree::PoseidonTree`]'s node hashing at the same layer.
+fn node_domain_tag<F: PrimeField>(layer: usize) -> F {
+    F::from_le_bytes_mod_order(format!("POSEIDON_MERKLE_TREE|NODE|{}", layer).as_bytes())
+}
+
+/// Poseidon 2-to-1 compression of `left`/`right` into one field element, for
+/// the node hashes at `layer` (counted up from the leaves).
+fn compress2<F: PrimeField + Absorb>(
+    params: &ArkPoseidonConfig<F>,
+    layer: usize,
+    left: F,
+    right: F,
+) -> F {
+    let mut sponge = crate::ark_poseidon::ArkPoseidonSponge::new(params);
+    sponge.absorb(&node_domain_tag::<F>(layer));
+    sponge.absorb(&left);
+    sponge.absorb(&right);
+    sponge.squeeze_native_field_elements(1)[0]
+}
+
+/// A Merkle inclusion proof for a [`PoseidonMerkleTree`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MerkleProof<F> {
+    /// Sibling hash at each level, from the leaf level up to the root.
+    pub siblings: Vec<F>,
+    /// `path_bits[level]` is `true` if the proven node was the right child
+    /// of its parent at that level (so its sibling is on the left), `false`
+    /// if it was the left child (including the duplicate-self case produced
+    /// by an odd node count, where the sibling equals the node's own hash).
+    pub path_bits: Vec<bool>,
+}
+
+/// Dynamic-size Merkle tree with Poseidon 2-to-1 node compression and
+/// duplicate-last-node padding for odd-sized levels.
+///
+/// See the [module docs](self) for how this differs from
+/// [`crate::tree::PoseidonTree`].
+pub struct PoseidonMerkleTree<F: PrimeField + Absorb> {
+    params: ArkPoseidonConfig<F>,
+    /// `layers[0]` holds the leaves; the last layer holds the single root.
+    layers: Vec<Vec<F>>,
+}
+
+impl<F: PrimeField + Absorb> PoseidonMerkleTree<F> {
+    /// Build a tree over `leaves` (any non-empty length) using `params` for
+    /// node compression.
+    pub fn new_with_params(leaves: Vec<F>, params: ArkPoseidonConfig<F>) -> Self {
+        assert!(!leaves.is_empty(), "tree must have at least one leaf");
+        let mut layers = vec![leaves];
+        while layers.last().unwrap().len() > 1 {
+            let level = layers.len() - 1;
+            let current = layers.last().unwrap();
+            let mut next = Vec::with_capacity(current.len().div_ceil(2));
+            let mut i = 0;
+            while i < current.len() {
+                let left = current[i];
+                let right = if i + 1 < current.len() {
+                    current[i + 1]
+                } else {
+                    left
+                };
+                next.push(compress2(&params, level, left, right));
+                i += 2;
+            }
+            layers.push(next);
+        }
+        Self { params, layers }
+    }
+
+    /// Number of leaves this tree was built from.
+    pub fn leaf_count(&self) -> usize {
+        self.layers[0].len()
+    }
+
+    /// Return the current Merkle root.
+    pub fn root(&self) -> F {
+        self.layers[self.layers.len() - 1][0]
+    }
+
+    /// Build a membership proof for the leaf at `index`.
+    pub fn proof(&self, index: usize) -> MerkleProof<F> {
+        assert!(index < self.leaf_count(), "leaf index out of range");
+        let depth = self.layers.len() - 1;
+        let mut siblings = Vec::with_capacity(depth);
+        let mut path_bits = Vec::with_capacity(depth);
+        let mut idx = index;
+        for level in 0..depth {
+            let current = &self.layers[level];
+            let is_right = idx % 2 == 1;
+            let sibling_idx = if is_right {
+                idx - 1
+            } else if idx + 1 < current.len() {
+                idx + 1
+            } else {
+                idx
+            };
+            siblings.push(current[sibling_idx]);
+            path_bits.push(is_right);
+            idx /= 2;
+        }
+        MerkleProof {
+            siblings,
+            path_bits,
+        }
+    }
+
+    /// Verify that `leaf` is included under `root` according to `proof`,
+    /// using this tree's parameters.
+    pub fn verify_proof(&self, root: F, leaf: F, proof: &MerkleProof<F>) -> bool {
+        verify_proof(&self.params, root, leaf, proof)
+    }
+}
+
+/// Verify a [`PoseidonMerkleTree`] membership proof against `root`,
+/// independent of any particular tree instance.
+pub fn verify_proof<F: PrimeField + Absorb>(
+    params: &ArkPoseidonConfig<F>,
+    root: F,
+    leaf: F,
+    proof: &MerkleProof<F>,
+) -> bool {
+    if proof.siblings.len() != proof.path_bits.len() {
+        return false;
+    }
+    let mut current = leaf;
+    for (level, (&sibling, &is_right)) in proof
+        .siblings
+        .iter()
+        .zip(proof.path_bits.iter())
+        .enumerate()
+    {
+        current = if is_right {
+            compress2(params, level, sibling, current)
+        } else {
+            compress2(params, level, current, sibling)
+        };
+    }
+    current == root
+}
+
+/// Hash `leaves` in fixed-size `chunk_size` groups, returning one
+/// [`PoseidonMerkleTree`] root per chunk. Useful for reducing a large flat
+/// slice of field elements down to a smaller leaf set (e.g. before building
+/// a tree over it) without holding one tree over the whole slice at once.
+/// The final chunk may be shorter than `chunk_size`.
+pub fn digest_chunked<F: PrimeField + Absorb>(
+    params: &ArkPoseidonConfig<F>,
+    leaves: &[F],
+    chunk_size: usize,
+) -> Vec<F> {
+    assert!(chunk_size > 0, "chunk_size must be positive");
+    leaves
+        .chunks(chunk_size)
+        .map(|chunk| {
+            PoseidonMerkleTree::new_with_params(
+                chunk.to_vec(),
+                crate::parameters::clone_parameters(params),
+            )
+            .root()
+        })
+        .collect()
+}
+
+/// Poseidon Merkle tree over the Pallas base field.
+pub type PallasMerkleTree = PoseidonMerkleTree<ark_pallas::Fq>;
+
+impl PallasMerkleTree {
+    /// Build a new Pallas tree from `leaves`, using the crate's embedded
+    /// Pallas Poseidon parameters.
+    pub fn new(leaves: Vec<ark_pallas::Fq>) -> Self {
+        PoseidonMerkleTree::new_with_params(
+            leaves,
+            crate::parameters::clone_parameters(&*crate::parameters::pallas::PALLAS_PARAMS),
+        )
+    }
+}
+
+/// Poseidon Merkle tree over the BN254 base field.
+pub type BN254MerkleTree = PoseidonMerkleTree<ark_bn254::Fq>;
+
+impl BN254MerkleTree {
+    /// Build a new BN254 tree from `leaves`, using the crate's embedded
+    /// BN254 Poseidon parameters.
+    pub fn new(leaves: Vec<ark_bn254::Fq>) -> Self {
+        PoseidonMerkleTree::new_with_params(
+            leaves,
+            crate::parameters::clone_parameters(&*crate::parameters::bn254::BN254_PARAMS),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_ff::Zero;
+
+    #[test]
+    fn test_power_of_two_leaves_round_trip_every_index() {
+        let leaves: Vec<_> = (0..8u64).map(ark_pallas::Fq::from).collect();
+        let tree = PallasMerkleTree::new(leaves.clone());
+        for (i, &leaf) in leaves.iter().enumerate() {
+            let proof = tree.proof(i);
+            assert!(tree.verify_proof(tree.root(), leaf, &proof));
+        }
+    }
+
+    #[test]
+    fn test_odd_leaf_count_duplicates_last_node() {
+        let leaves: Vec<_> = (0..5u64).map(ark_pallas::Fq::from).collect();
+        let tree = PallasMerkleTree::new(leaves.clone());
+        for (i, &leaf) in leaves.iter().enumerate() {
+            let proof = tree.proof(i);
+            assert!(tree.verify_proof(tree.root(), leaf, &proof));
+        }
+    }
+
+    #[test]
+    fn test_single_leaf_tree_has_root_equal_to_leaf() {
+        let leaf = ark_pallas::Fq::from(42u64);
+        let tree = PallasMerkleTree::new(vec![leaf]);
+        assert_eq!(tree.root(), leaf);
+        let proof = tree.proof(0);
+        assert!(proof.siblings.is_empty());
+        assert!(tree.verify_proof(tree.root(), leaf, &proof));
+    }
+
+    #[test]
+    fn test_tampered_leaf_fails_verification() {
+        let leaves: Vec<_> = (0..5u64).map(ark_pallas::Fq::from).collect();
+        let tree = PallasMerkleTree::new(leaves);
+        let proof = tree.proof(3);
+        assert!(!tree.verify_proof(tree.root(), ark_pallas::Fq::zero(), &proof));
+    }
+
+    #[test]
+    fn test_digest_chunked_matches_per_chunk_roots() {
+        let leaves: Vec<_> = (0..10u64).map(ark_pallas::Fq::from).collect();
+        let params =
+            crate::parameters::clone_parameters(&*crate::parameters::pallas::PALLAS_PARAMS);
+        let chunks = digest_chunked(&params, &leaves, 4);
+        assert_eq!(chunks.len(), 3);
+        let expected_first = PoseidonMerkleTree::new_with_params(
+            leaves[0..4].to_vec(),
+            crate::parameters::clone_parameters(&params),
+        )
+        .root();
+        assert_eq!(chunks[0], expected_first);
+    }
+
+    #[test]
+    fn test_bn254_tree_round_trip() {
+        let leaves: Vec<_> = (0..6u64).map(ark_bn254::Fq::from).collect();
+        let tree = BN254MerkleTree::new(leaves.clone());
+        let proof = tree.proof(5);
+        assert!(tree.verify_proof(tree.root(), leaves[5], &proof));
+    }
+}
@@ -0,0 +1,222 @@
+//! Generic Fiat–Shamir transcript over any [`PoseidonHasher`] impl
+//! (`PallasHasher`, `BN254Hasher`, the hand-written Poseidon2 curve hashers,
+//! etc.), for proof systems that just want "absorb things, squeeze
+//! challenges" without committing to a specific curve hasher type.
+//!
+//! Unlike [`crate::poseidon2_transcript::Poseidon2Transcript`], which is
+//! built directly on [`crate::poseidon2::Poseidon2Sponge`]'s real
+//! rate/capacity split, [`PoseidonTranscript`] is built on the
+//! [`PoseidonHasher`] trait's `update`/`digest` surface — the one thing every
+//! curve hasher in this crate implements, including the Poseidon2 curve
+//! hashers which don't expose a raw `squeeze`. Each challenge is drawn by
+//! calling `digest` (which doesn't disturb hasher state) and then
+//! re-absorbing the result, so later challenges stay cryptographically bound
+//! to every challenge already produced and the transcript remains valid for
+//! further `absorb` calls afterwards — the same duplex-sponge ratchet
+//! `Poseidon2Transcript::challenge` uses, expressed generically.
+//!
+//! Arbitrary-length squeezing and interleaved absorb/squeeze (duplex mode)
+//! with proper domain separation between phases are not reimplemented
+//! here — every curve hasher's inherent `squeeze`/`squeeze_bytes` already
+//! provide them (see [`crate::hasher::MultiFieldHasher::squeeze_native_field_elements`]
+//! for the per-call output-domain-separation tag that makes absorbing after
+//! squeezing, then squeezing again, safe), and [`Self::absorb`]/
+//! [`Self::squeeze_challenge`] below (plus the `absorb_point`/
+//! `absorb_scalar`/`challenge_scalar` aliases) are this transcript's thin
+//! wrapper over that existing duplex surface.
+
+use ark_ff::{BigInteger, PrimeField};
+
+use crate::types::PoseidonHasher;
+
+/// A Fiat–Shamir transcript generic over any curve hasher `H` implementing
+/// [`PoseidonHasher<F, I>`].
+pub struct PoseidonTranscript<H> {
+    hasher: H,
+}
+
+impl<F, I, H> PoseidonTranscript<H>
+where
+    F: PrimeField,
+    H: PoseidonHasher<F, I>,
+{
+    /// Create a new transcript with a fresh, default-configured hasher.
+    pub fn new() -> Self {
+        Self { hasher: H::new() }
+    }
+
+    /// Absorb a single input, delegating to [`PoseidonHasher::update`].
+    pub fn absorb<T: Into<I>>(&mut self, input: T) {
+        self.hasher.update(input);
+    }
+
+    /// Absorb a slice of field elements, in order.
+    pub fn absorb_slice(&mut self, inputs: &[F])
+    where
+        F: Into<I>,
+    {
+        for &x in inputs {
+            self.hasher.update(x);
+        }
+    }
+
+    /// Squeeze one challenge, then re-absorb it so later challenges stay
+    /// bound to it and the transcript remains usable for further `absorb`
+    /// calls.
+    pub fn squeeze_challenge(&mut self) -> F
+    where
+        F: Into<I>,
+    {
+        let challenge = self.hasher.digest();
+        self.hasher.update(challenge);
+        challenge
+    }
+
+    /// Squeeze `n` challenges, one at a time, each re-absorbed before the
+    /// next is drawn.
+    pub fn squeeze_challenges(&mut self, n: usize) -> Vec<F>
+    where
+        F: Into<I>,
+    {
+        (0..n).map(|_| self.squeeze_challenge()).collect()
+    }
+
+    /// Absorb an elliptic curve point. An alias for [`Self::absorb`] under
+    /// the naming SNARK-verifier transcripts conventionally use — any type
+    /// accepted by this curve hasher's `Into<I>` impl (including affine
+    /// points) works here, since [`PoseidonHasher`] doesn't distinguish
+    /// point/scalar inputs at the trait level the way a dedicated
+    /// elliptic-curve transcript would.
+    pub fn absorb_point<T: Into<I>>(&mut self, point: T) {
+        self.absorb(point);
+    }
+
+    /// Absorb a scalar (field element or other primitive). An alias for
+    /// [`Self::absorb`]; see [`Self::absorb_point`].
+    pub fn absorb_scalar<T: Into<I>>(&mut self, value: T) {
+        self.absorb(value);
+    }
+
+    /// Squeeze a challenge scalar. An alias for [`Self::squeeze_challenge`]
+    /// under the naming SNARK-verifier transcripts conventionally use.
+    pub fn challenge_scalar(&mut self) -> F
+    where
+        F: Into<I>,
+    {
+        self.squeeze_challenge()
+    }
+
+    /// Squeeze one challenge and derive a uniform `n_bytes`-byte challenge
+    /// from its canonical little-endian representation (truncated, or
+    /// zero-padded if `n_bytes` exceeds the field's encoded width).
+    pub fn squeeze_challenge_bytes(&mut self, n_bytes: usize) -> Vec<u8>
+    where
+        F: Into<I>,
+    {
+        let challenge = self.squeeze_challenge();
+        let mut bytes = challenge.into_bigint().to_bytes_le();
+        bytes.resize(n_bytes, 0);
+        bytes
+    }
+}
+
+impl<F, I, H> Default for PoseidonTranscript<H>
+where
+    F: PrimeField,
+    H: PoseidonHasher<F, I>,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::PallasHasher;
+    use ark_ec::AffineRepr;
+
+    fn transcript() -> PoseidonTranscript<PallasHasher> {
+        PoseidonTranscript::new()
+    }
+
+    #[test]
+    fn challenges_are_deterministic() {
+        let mut a = transcript();
+        let mut b = transcript();
+        a.absorb(ark_pallas::Fq::from(42u64));
+        b.absorb(ark_pallas::Fq::from(42u64));
+        assert_eq!(a.squeeze_challenge(), b.squeeze_challenge());
+    }
+
+    #[test]
+    fn absorbing_changes_the_challenge() {
+        let mut a = transcript();
+        let before = a.squeeze_challenge();
+
+        let mut b = transcript();
+        b.absorb(ark_pallas::Fq::from(7u64));
+        let after = b.squeeze_challenge();
+
+        assert_ne!(before, after);
+    }
+
+    #[test]
+    fn challenges_stay_bound_to_earlier_ones() {
+        let mut a = transcript();
+        a.absorb(ark_pallas::Fq::from(1u64));
+        let _ = a.squeeze_challenge();
+        a.absorb(ark_pallas::Fq::from(2u64));
+        let final_a = a.squeeze_challenge();
+
+        let mut b = transcript();
+        b.absorb(ark_pallas::Fq::from(1u64));
+        b.absorb(ark_pallas::Fq::from(2u64));
+        let final_b = b.squeeze_challenge();
+
+        assert_ne!(final_a, final_b);
+    }
+
+    #[test]
+    fn squeeze_challenges_matches_repeated_squeeze_challenge_calls() {
+        let mut a = transcript();
+        a.absorb(ark_pallas::Fq::from(3u64));
+        let vec_challenges = a.squeeze_challenges(3);
+
+        let mut b = transcript();
+        b.absorb(ark_pallas::Fq::from(3u64));
+        let individual: Vec<ark_pallas::Fq> = (0..3).map(|_| b.squeeze_challenge()).collect();
+
+        assert_eq!(vec_challenges, individual);
+    }
+
+    #[test]
+    fn squeeze_challenge_bytes_matches_low_bytes_of_the_field_challenge() {
+        let mut a = transcript();
+        a.absorb(ark_pallas::Fq::from(9u64));
+        let mut b = transcript();
+        b.absorb(ark_pallas::Fq::from(9u64));
+
+        let full = a.squeeze_challenge();
+        let bytes = b.squeeze_challenge_bytes(16);
+
+        let mut expected = full.into_bigint().to_bytes_le();
+        expected.truncate(16);
+        assert_eq!(bytes, expected);
+    }
+
+    #[test]
+    fn absorb_point_absorb_scalar_and_challenge_scalar_match_their_aliases() {
+        let mut a = transcript();
+        a.absorb_point(ark_pallas::Affine::generator());
+        a.absorb_scalar(ark_pallas::Fq::from(5u64));
+        let aliased = a.challenge_scalar();
+
+        let mut b = transcript();
+        b.absorb(ark_pallas::Affine::generator());
+        b.absorb(ark_pallas::Fq::from(5u64));
+        let direct = b.squeeze_challenge();
+
+        assert_eq!(aliased, direct);
+    }
+}
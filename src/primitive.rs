@@ -38,6 +38,7 @@
 
 use crate::tags::*;
 use ark_ff::PrimeField;
+use bytes::Buf;
 use std::collections::VecDeque;
 use zeroize::{Zeroize, ZeroizeOnDrop};
 
@@ -50,6 +51,9 @@ pub struct PackingConfig {
     pub max_bytes_per_field: Option<usize>,
     /// Padding strategy when field element is not full
     pub padding: PaddingMode,
+    /// Chunk size (in bytes) [`crate::parallel_hash::digest_parallel`] splits
+    /// its input into before hashing each chunk independently.
+    pub parallel_chunk_bytes: usize,
 }
 
 /// Packing modes for converting basic types to field elements.
@@ -76,6 +80,7 @@ impl Default for PackingConfig {
             mode: PackingMode::ByteEfficient,
             max_bytes_per_field: None, // Auto-calculate from field size
             padding: PaddingMode::LengthPrefix,
+            parallel_chunk_bytes: 4096,
         }
     }
 }
@@ -97,6 +102,21 @@ fn encode_varint(mut value: usize) -> Vec<u8> {
     out
 }
 
+/// Inverse of [`encode_varint`]: reads a LEB128-style varint off the front of
+/// `bytes`, returning the decoded value and the number of bytes it occupied.
+fn decode_varint(bytes: &[u8]) -> (usize, usize) {
+    let mut value: usize = 0;
+    let mut shift = 0;
+    for (consumed, &byte) in bytes.iter().enumerate() {
+        value |= ((byte & 0x7F) as usize) << shift;
+        if byte & 0x80 == 0 {
+            return (value, consumed + 1);
+        }
+        shift += 7;
+    }
+    (value, bytes.len())
+}
+
 impl From<bool> for PrimitiveInput {
     fn from(v: bool) -> Self {
         Self {
@@ -171,6 +191,16 @@ impl From<&[u8]> for PrimitiveInput {
         }
     }
 }
+impl From<bytes::Bytes> for PrimitiveInput {
+    fn from(v: bytes::Bytes) -> Self {
+        let mut bytes = encode_varint(v.len());
+        bytes.extend_from_slice(&v);
+        Self {
+            tag: TAG_BYTES,
+            bytes,
+        }
+    }
+}
 
 /// Buffer for accumulating bytes before packing into field elements.
 ///
@@ -206,7 +236,7 @@ impl PackingBuffer {
     ///
     /// We use a conservative approach: (field_bit_size - 8) / 8 to ensure
     /// we never exceed the field modulus when packing bytes.
-    fn calculate_max_bytes<F: PrimeField>() -> usize {
+    pub(crate) fn calculate_max_bytes<F: PrimeField>() -> usize {
         const SAFETY_MARGIN_BITS: usize = 8;
         let field_bits = F::MODULUS_BIT_SIZE as usize;
         let safe_bits = field_bits.saturating_sub(SAFETY_MARGIN_BITS);
@@ -236,6 +266,73 @@ impl PackingBuffer {
         self.bytes.extend(bytes);
     }
 
+    /// Add a length-delimited byte slice to the buffer (varint length prefix,
+    /// then the bytes), so that e.g. writing `b"ab"` then `b"c"` never
+    /// collides with a single write of `b"abc"` — used by
+    /// [`crate::std_hasher::PoseidonStdHasher`] to frame each `Hasher::write`
+    /// call distinctly.
+    pub fn push_bytes_with_length_prefix(&mut self, bytes: &[u8]) {
+        self.push_varint(bytes.len());
+        self.bytes.extend(bytes);
+    }
+
+    /// Absorb an `impl bytes::Buf` directly, packing field elements straight
+    /// out of each contiguous `chunk()` slice instead of copying every byte
+    /// through the internal `VecDeque` first — a zero-copy fast path for
+    /// large, already-buffered inputs (e.g. network payloads or
+    /// reference-counted blobs) that the byte-at-a-time queue can't handle
+    /// efficiently.
+    ///
+    /// The fast path only applies while the buffer is empty (so the packed
+    /// elements can't be reordered relative to anything already queued) and
+    /// the chunk is at least one field element's worth of bytes; any
+    /// shorter tail is queued the normal way via [`Self::push_bytes`] so a
+    /// later call (to this method, or to [`Self::extract_field_elements`])
+    /// can complete it. Returns the field elements packed directly from
+    /// `buf`; bytes left queued in the buffer are not included and must
+    /// still be drained with [`Self::extract_field_elements`]/
+    /// [`Self::flush_remaining`].
+    pub fn push_buf<F: PrimeField>(&mut self, mut buf: impl Buf) -> Vec<F> {
+        let mut elements = Vec::new();
+        while buf.has_remaining() {
+            let slice = buf.chunk();
+            if slice.is_empty() {
+                break;
+            }
+
+            if !self.bytes.is_empty() {
+                let owned = slice.to_vec();
+                buf.advance(owned.len());
+                self.push_bytes(&owned);
+                continue;
+            }
+
+            match self.config.mode {
+                PackingMode::ByteEfficient => {
+                    let direct_elements = slice.len() / self.max_bytes_per_field;
+                    if direct_elements == 0 {
+                        let owned = slice.to_vec();
+                        buf.advance(owned.len());
+                        self.push_bytes(&owned);
+                    } else {
+                        let direct_len = direct_elements * self.max_bytes_per_field;
+                        elements.extend(
+                            slice[..direct_len]
+                                .chunks(self.max_bytes_per_field)
+                                .map(F::from_le_bytes_mod_order),
+                        );
+                        buf.advance(direct_len);
+                    }
+                }
+                PackingMode::CircuitFriendly => {
+                    elements.extend(slice.iter().map(|&b| F::from(b as u64)));
+                    buf.advance(slice.len());
+                }
+            }
+        }
+        elements
+    }
+
     /// Add a variable-length integer (LEB128-style encoding).
     fn push_varint(&mut self, mut value: usize) {
         while value >= 0x80 {
@@ -283,6 +380,16 @@ impl PackingBuffer {
     }
 
     /// Force extraction of all remaining bytes as field elements (with padding if needed).
+    ///
+    /// In [`PackingMode::ByteEfficient`] this first packs any complete,
+    /// `max_bytes_per_field`-sized chunks exactly as
+    /// [`Self::extract_field_elements`] would (each trivially invertible,
+    /// since it carries no padding), then packs the final, possibly-partial
+    /// chunk on its own. Under [`PaddingMode::LengthPrefix`] that final chunk
+    /// is varint-length-framed (see [`encode_varint`]) rather than
+    /// zero-padded, so [`Self::decode_field_elements`] can recover the exact
+    /// original bytes even when the true length is ambiguous against
+    /// trailing zero padding.
     pub fn flush_remaining<F: PrimeField>(&mut self) -> Vec<F> {
         if self.bytes.is_empty() {
             return Vec::new();
@@ -292,29 +399,45 @@ impl PackingBuffer {
 
         match self.config.mode {
             PackingMode::ByteEfficient => {
-                // Pack remaining bytes with padding
-                let remaining_bytes: Vec<u8> = self.bytes.drain(..).collect();
-                if !remaining_bytes.is_empty() {
-                    let mut padded_bytes = remaining_bytes;
-
-                    match self.config.padding {
-                        PaddingMode::Zero => {
+                // Pack any complete chunks first, so only the final, partial
+                // chunk needs padding or length framing.
+                field_elements.extend(self.extract_field_elements::<F>());
+
+                match self.config.padding {
+                    PaddingMode::Zero => {
+                        if !self.bytes.is_empty() {
+                            let mut padded_bytes: Vec<u8> = self.bytes.drain(..).collect();
                             // Pad with zeros to field size
                             padded_bytes.resize(self.max_bytes_per_field, 0);
-                        }
-                        PaddingMode::LengthPrefix => {
-                            // Insert actual length at the beginning
-                            let actual_len = padded_bytes.len();
-                            padded_bytes.insert(0, actual_len as u8);
-                            // Then pad with zeros if needed
-                            if padded_bytes.len() < self.max_bytes_per_field {
-                                padded_bytes.resize(self.max_bytes_per_field, 0);
-                            }
+                            let field_element = F::from_le_bytes_mod_order(&padded_bytes);
+                            field_elements.push(field_element);
                         }
                     }
-
-                    let field_element = F::from_le_bytes_mod_order(&padded_bytes);
-                    field_elements.push(field_element);
+                    PaddingMode::LengthPrefix => {
+                        // Always emit a framed trailing element, even when no
+                        // bytes remain (e.g. the packed length was an exact
+                        // multiple of `max_bytes_per_field`, so every prior
+                        // chunk was already consumed as a raw, unframed
+                        // element above). `decode_field_elements` always
+                        // treats the last element as length-framed; if this
+                        // were skipped whenever `self.bytes` happened to be
+                        // empty, that last *raw* chunk would be misread as a
+                        // framed one and silently corrupted.
+                        let padded_bytes: Vec<u8> = self.bytes.drain(..).collect();
+                        let actual_len = padded_bytes.len();
+                        let mut framed = encode_varint(actual_len);
+                        framed.extend_from_slice(&padded_bytes);
+                        assert!(
+                            framed.len() <= self.max_bytes_per_field,
+                            "PackingBuffer: final chunk of {} bytes plus its varint length \
+                             prefix exceeds the {}-byte safety margin for this field",
+                            actual_len,
+                            self.max_bytes_per_field,
+                        );
+                        framed.resize(self.max_bytes_per_field, 0);
+                        let field_element = F::from_le_bytes_mod_order(&framed);
+                        field_elements.push(field_element);
+                    }
                 }
             }
             PackingMode::CircuitFriendly => {
@@ -329,16 +452,44 @@ impl PackingBuffer {
         field_elements
     }
 
+    /// Inverse of [`Self::extract_field_elements`] + [`Self::flush_remaining`]
+    /// for [`PackingMode::ByteEfficient`] with [`PaddingMode::LengthPrefix`]:
+    /// given the complete packed output for one logical message,
+    /// reconstructs the exact original bytes.
+    ///
+    /// Every element but the last is assumed to be a full,
+    /// `max_bytes_per_field`-sized chunk with no padding; the last element
+    /// must carry a varint length prefix identifying how many of its
+    /// remaining bytes are real data rather than zero padding. Distinct byte
+    /// strings never decode to the same input, since full chunks are a fixed
+    /// width and the final chunk's length is recorded explicitly rather than
+    /// inferred from trailing zeros.
+    pub fn decode_field_elements<F: PrimeField>(elements: &[F]) -> Vec<u8> {
+        let max_bytes_per_field = Self::calculate_max_bytes::<F>();
+        let Some((last, full)) = elements.split_last() else {
+            return Vec::new();
+        };
+
+        let mut out = Vec::with_capacity(elements.len() * max_bytes_per_field);
+        for element in full {
+            let mut bytes = element.into_bigint().to_bytes_le();
+            bytes.resize(max_bytes_per_field, 0);
+            out.extend_from_slice(&bytes);
+        }
+
+        let mut last_bytes = last.into_bigint().to_bytes_le();
+        last_bytes.resize(max_bytes_per_field, 0);
+        let (len, prefix_len) = decode_varint(&last_bytes);
+        out.extend_from_slice(&last_bytes[prefix_len..prefix_len + len]);
+        out
+    }
+
     /// Clear all bytes from the buffer.
     ///
     /// This method securely zeroizes the buffer contents to prevent sensitive
     /// data from remaining in memory.
     pub fn clear(&mut self) {
-        // Zeroize the contents before clearing to ensure secure deletion
-        for byte in self.bytes.iter_mut() {
-            byte.zeroize();
-        }
-        self.bytes.clear();
+        self.zeroize();
     }
 
     /// Returns the number of bytes in the buffer.
@@ -356,17 +507,112 @@ impl PackingBuffer {
     }
 }
 
+/// Circuit-consistent encoding of a variable-length byte string as a *fixed*
+/// number of field elements, so an off-circuit digest can match a ZK circuit
+/// that can only absorb a statically-sized array.
+///
+/// [`Self::encode`] always emits [`Self::element_count`] elements regardless
+/// of `data`'s actual length: `data` packed into
+/// [`PackingBuffer::calculate_max_bytes`]-sized chunks, zero-padded up to
+/// `max_len`, followed by one trailing element carrying the true byte
+/// length. Because the length is absorbed at a fixed position, two inputs
+/// with a common prefix but different lengths can never produce the same
+/// element sequence. See [`FixLenBytes`] for the case where the length is
+/// already known at compile time and need not be absorbed at all.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VarLenBytes {
+    max_len: usize,
+}
+
+impl VarLenBytes {
+    /// Create an encoder accepting byte strings of up to `max_len` bytes.
+    pub fn new(max_len: usize) -> Self {
+        Self { max_len }
+    }
+
+    /// Number of field elements [`Self::encode`] always produces (data
+    /// chunks, plus one length element), independent of the actual input
+    /// length.
+    pub fn element_count<F: PrimeField>(&self) -> usize {
+        Self::data_chunk_count::<F>(self.max_len) + 1
+    }
+
+    fn data_chunk_count<F: PrimeField>(max_len: usize) -> usize {
+        let bytes_per_element = PackingBuffer::calculate_max_bytes::<F>();
+        max_len.div_ceil(bytes_per_element)
+    }
+
+    /// Encode `data` as exactly [`Self::element_count`] field elements.
+    ///
+    /// Panics if `data.len() > max_len`.
+    pub fn encode<F: PrimeField>(&self, data: &[u8]) -> Vec<F> {
+        assert!(
+            data.len() <= self.max_len,
+            "VarLenBytes: input length {} exceeds max_len {}",
+            data.len(),
+            self.max_len
+        );
+        let bytes_per_element = PackingBuffer::calculate_max_bytes::<F>();
+        let chunk_count = Self::data_chunk_count::<F>(self.max_len);
+        let mut padded = data.to_vec();
+        padded.resize(chunk_count * bytes_per_element, 0);
+
+        let mut elements: Vec<F> = padded
+            .chunks(bytes_per_element)
+            .map(F::from_le_bytes_mod_order)
+            .collect();
+        elements.push(F::from(data.len() as u64));
+        elements
+    }
+}
+
+/// Circuit-consistent encoding of a byte string whose length `N` is fixed
+/// and known at compile time, so (unlike [`VarLenBytes`]) no length element
+/// needs to be absorbed — the element count and layout are already
+/// unambiguous.
+pub struct FixLenBytes<const N: usize>;
+
+impl<const N: usize> FixLenBytes<N> {
+    /// Number of field elements [`Self::encode`] always produces.
+    pub fn element_count<F: PrimeField>() -> usize {
+        let bytes_per_element = PackingBuffer::calculate_max_bytes::<F>();
+        N.div_ceil(bytes_per_element)
+    }
+
+    /// Encode exactly `N` bytes as [`Self::element_count`] field elements,
+    /// zero-padding the final chunk if `N` isn't a multiple of the
+    /// per-element byte capacity.
+    pub fn encode<F: PrimeField>(data: &[u8; N]) -> Vec<F> {
+        let bytes_per_element = PackingBuffer::calculate_max_bytes::<F>();
+        let chunk_count = Self::element_count::<F>();
+        let mut padded = data.to_vec();
+        padded.resize(chunk_count * bytes_per_element, 0);
+
+        padded
+            .chunks(bytes_per_element)
+            .map(F::from_le_bytes_mod_order)
+            .collect()
+    }
+}
+
+// `VecDeque<u8>` has no blanket `Zeroize` impl (unlike arrays/slices), so
+// this is hand-written rather than `#[derive(Zeroize)]`.
+impl Zeroize for PackingBuffer {
+    fn zeroize(&mut self) {
+        for byte in self.bytes.iter_mut() {
+            byte.zeroize();
+        }
+        self.bytes.clear();
+    }
+}
+
 // Manual implementation of ZeroizeOnDrop for PackingBuffer
 // since VecDeque doesn't implement Zeroize automatically
 impl ZeroizeOnDrop for PackingBuffer {}
 
 impl Drop for PackingBuffer {
     fn drop(&mut self) {
-        // Manually zeroize the VecDeque contents
-        for byte in self.bytes.iter_mut() {
-            byte.zeroize();
-        }
-        self.bytes.clear();
+        self.zeroize();
     }
 }
 
@@ -477,4 +723,97 @@ mod tests {
         assert_eq!(field_elements.len(), 1);
         assert_eq!(buffer.len(), 0);
     }
+
+    #[test]
+    fn test_flush_remaining_round_trips_through_decode_field_elements() {
+        let config = PackingConfig::default();
+        let mut buffer = PackingBuffer::new::<ark_pallas::Fq>(config);
+
+        let data: Vec<u8> = (0..7u8).collect();
+        buffer.push_bytes(&data);
+        let field_elements = buffer.flush_remaining::<ark_pallas::Fq>();
+
+        let decoded = PackingBuffer::decode_field_elements::<ark_pallas::Fq>(&field_elements);
+        assert_eq!(decoded, data);
+    }
+
+    #[test]
+    fn test_flush_remaining_chunks_messages_longer_than_one_field_element() {
+        let config = PackingConfig::default();
+        let mut buffer = PackingBuffer::new::<ark_pallas::Fq>(config);
+
+        // More than one field-element's worth of bytes, flushed directly
+        // without an intervening `extract_field_elements` call.
+        let data: Vec<u8> = (0..100u8).collect();
+        buffer.push_bytes(&data);
+        let field_elements = buffer.flush_remaining::<ark_pallas::Fq>();
+        assert!(field_elements.len() > 1);
+
+        let decoded = PackingBuffer::decode_field_elements::<ark_pallas::Fq>(&field_elements);
+        assert_eq!(decoded, data);
+    }
+
+    #[test]
+    fn test_flush_remaining_round_trips_at_exact_multiple_of_max_bytes_per_field() {
+        let config = PackingConfig::default();
+        let bytes_per_element = PackingBuffer::calculate_max_bytes::<ark_pallas::Fq>();
+
+        let mut buffer = PackingBuffer::new::<ark_pallas::Fq>(config);
+        let data: Vec<u8> = (0..bytes_per_element as u8).collect();
+        buffer.push_bytes(&data);
+
+        let field_elements = buffer.flush_remaining::<ark_pallas::Fq>();
+        // One raw, unframed chunk plus one (empty) length-framed trailing
+        // element — see the comment in `flush_remaining` for why the latter
+        // can't be skipped just because no bytes remained.
+        assert_eq!(field_elements.len(), 2);
+
+        let decoded = PackingBuffer::decode_field_elements::<ark_pallas::Fq>(&field_elements);
+        assert_eq!(decoded, data);
+    }
+
+    #[test]
+    fn test_distinct_inputs_never_produce_the_same_packed_elements() {
+        let config = PackingConfig::default();
+
+        let pack = |data: &[u8]| {
+            let mut buffer = PackingBuffer::new::<ark_pallas::Fq>(config);
+            buffer.push_bytes(data);
+            buffer.flush_remaining::<ark_pallas::Fq>()
+        };
+
+        // Same prefix, different (including zero-padded-looking) lengths:
+        // the old one-byte-length-with-zero-padding scheme could conflate a
+        // real trailing zero with padding; the varint-framed scheme must not.
+        let inputs: &[&[u8]] = &[
+            &[],
+            &[0],
+            &[0, 0],
+            &[1, 2, 3],
+            &[1, 2, 3, 0],
+            &[1, 2, 3, 0, 0],
+        ];
+
+        for (i, a) in inputs.iter().enumerate() {
+            for (j, b) in inputs.iter().enumerate() {
+                let packed_a = pack(a);
+                let packed_b = pack(b);
+                if i == j {
+                    assert_eq!(packed_a, packed_b);
+                } else {
+                    assert_ne!(
+                        packed_a, packed_b,
+                        "{:?} and {:?} packed to the same field elements",
+                        a, b
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_decode_field_elements_empty_input() {
+        let decoded = PackingBuffer::decode_field_elements::<ark_pallas::Fq>(&[]);
+        assert!(decoded.is_empty());
+    }
 }
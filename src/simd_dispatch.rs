@@ -0,0 +1,84 @@
+//! Runtime CPU-feature detection, reported but currently unused.
+//!
+//! Modeled on BLAKE3's `blake3_dispatch` and ahash's AES-vs-fallback
+//! selection: detect available vector extensions once and cache the result
+//! in an atomic. Nothing in this crate currently branches on
+//! [`detected_backend`]'s result — see the honest limitation below for why.
+//!
+//! Honest limitation: unlike BLAKE3 (fixed 32-bit words) or AES-NI (a
+//! single fixed-width block cipher), this crate's field arithmetic is
+//! generic over any [`ark_ff::PrimeField`] — an arbitrary-precision
+//! Montgomery-form integer whose limb count depends on the field's
+//! modulus. There is no portable way to hand an arbitrary `F` to a
+//! hand-written AVX2/NEON kernel without per-field unsafe intrinsics tied
+//! to its specific limb layout, which this generic crate does not ship.
+//! [`crate::poseidon2_spec::permute_many`] and
+//! [`crate::batch_hash::digest_batch`] both batch over independent states
+//! with a plain scalar loop rather than pretend to dispatch on this value.
+
+use std::sync::atomic::{AtomicU8, Ordering};
+
+const UNINIT: u8 = 0;
+const SCALAR: u8 = 1;
+const AVX2: u8 = 2;
+const NEON: u8 = 3;
+
+static DETECTED: AtomicU8 = AtomicU8::new(UNINIT);
+
+/// Which vector extension [`detected_backend`] found on the current CPU.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Backend {
+    /// No relevant vector extension detected (or none implemented for this
+    /// target).
+    Scalar,
+    /// AVX2 is available (x86_64).
+    Avx2,
+    /// NEON is available (aarch64).
+    Neon,
+}
+
+fn detect() -> Backend {
+    #[cfg(target_arch = "x86_64")]
+    {
+        if std::is_x86_feature_detected!("avx2") {
+            return Backend::Avx2;
+        }
+    }
+    #[cfg(target_arch = "aarch64")]
+    {
+        if std::arch::is_aarch64_feature_detected!("neon") {
+            return Backend::Neon;
+        }
+    }
+    Backend::Scalar
+}
+
+/// The vector backend detected for this CPU, computed once and cached in
+/// an atomic — every call after the first is a single relaxed load.
+pub fn detected_backend() -> Backend {
+    match DETECTED.load(Ordering::Relaxed) {
+        SCALAR => Backend::Scalar,
+        AVX2 => Backend::Avx2,
+        NEON => Backend::Neon,
+        _ => {
+            let backend = detect();
+            let tag = match backend {
+                Backend::Scalar => SCALAR,
+                Backend::Avx2 => AVX2,
+                Backend::Neon => NEON,
+            };
+            DETECTED.store(tag, Ordering::Relaxed);
+            backend
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn detected_backend_is_stable_across_calls() {
+        assert_eq!(detected_backend(), detected_backend());
+    }
+}
@@ -0,0 +1,136 @@
+//! Bridge from the streaming Poseidon hasher to [`std::hash::Hasher`], so any
+//! `#[derive(Hash)]` type can be absorbed via `value.hash(&mut h)`.
+//!
+//! ```rust
+//! use std::hash::{Hash, Hasher};
+//! use poseidon_hash::PallasHasher;
+//!
+//! #[derive(Hash)]
+//! struct Point { x: u64, y: u64 }
+//!
+//! let mut h = PallasHasher::new().as_std_hasher();
+//! Point { x: 1, y: 2 }.hash(&mut h);
+//! let _digest: u64 = h.finish();
+//! ```
+
+use crate::hasher::MultiFieldHasherV1;
+use crate::primitive::{PackingBuffer, PackingConfig};
+use ark_crypto_primitives::sponge::Absorb;
+use ark_ec::AffineRepr;
+use ark_ff::{BigInteger, PrimeField, Zero};
+
+/// Adapter wrapping a streaming [`MultiFieldHasherV1`] as a standard-library
+/// [`std::hash::Hasher`].
+///
+/// Each [`write`](std::hash::Hasher::write) call is length-delimited before
+/// being packed into field elements and absorbed, so `write(b"ab")` followed
+/// by `write(b"c")` never collides with a single `write(b"abc")` call.
+/// Because `Hasher::finish` can only return a `u64`, [`Self::finish_field`]
+/// is provided as an escape hatch for the full field digest.
+pub struct PoseidonStdHasher<F, S, G>
+where
+    F: PrimeField + Zero + Absorb,
+    S: PrimeField,
+    G: AffineRepr<BaseField = F>,
+{
+    hasher: MultiFieldHasherV1<F, S, G>,
+    buffer: PackingBuffer,
+}
+
+impl<F, S, G> PoseidonStdHasher<F, S, G>
+where
+    F: PrimeField + Zero + Absorb,
+    S: PrimeField,
+    G: AffineRepr<BaseField = F>,
+{
+    /// Wrap an existing streaming hasher. Used by the curve-specific
+    /// `as_std_hasher()` constructors in [`crate::types`].
+    pub(crate) fn new(hasher: MultiFieldHasherV1<F, S, G>) -> Self {
+        Self {
+            hasher,
+            buffer: PackingBuffer::new::<F>(PackingConfig::default()),
+        }
+    }
+
+    /// The full field digest, rather than the `u64` truncation that
+    /// [`finish`](std::hash::Hasher::finish) returns.
+    ///
+    /// Any bytes buffered by a `write` call but not yet packed into a full
+    /// field element are folded in without being flushed from the buffer, so
+    /// this may be called any number of times (including interleaved with
+    /// further `write` calls), matching the `Hasher::finish` contract.
+    pub fn finish_field(&self) -> F {
+        let tail = self.buffer.clone().flush_remaining::<F>();
+        self.hasher.digest_with_tail(&tail)
+    }
+}
+
+impl<F, S, G> std::hash::Hasher for PoseidonStdHasher<F, S, G>
+where
+    F: PrimeField + Zero + Absorb,
+    S: PrimeField,
+    G: AffineRepr<BaseField = F>,
+{
+    fn write(&mut self, bytes: &[u8]) {
+        self.buffer.push_bytes_with_length_prefix(bytes);
+        for element in self.buffer.extract_field_elements::<F>() {
+            self.hasher.update_base_field(element);
+        }
+    }
+
+    fn finish(&self) -> u64 {
+        let bytes = self.finish_field().into_bigint().to_bytes_le();
+        u64::from_le_bytes(bytes[..8].try_into().unwrap())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::PallasHasher;
+    use std::hash::{Hash, Hasher};
+
+    #[test]
+    fn test_deterministic() {
+        let mut a = PallasHasher::new().as_std_hasher();
+        let mut b = PallasHasher::new().as_std_hasher();
+        "hello".hash(&mut a);
+        "hello".hash(&mut b);
+        assert_eq!(a.finish(), b.finish());
+    }
+
+    #[test]
+    fn test_split_write_differs_from_combined() {
+        let mut split = PallasHasher::new().as_std_hasher();
+        split.write(b"ab");
+        split.write(b"c");
+
+        let mut combined = PallasHasher::new().as_std_hasher();
+        combined.write(b"abc");
+
+        assert_ne!(split.finish_field(), combined.finish_field());
+    }
+
+    #[test]
+    fn test_finish_field_stable_across_repeated_calls() {
+        let mut h = PallasHasher::new().as_std_hasher();
+        h.write(b"partial");
+        assert_eq!(h.finish_field(), h.finish_field());
+    }
+
+    #[test]
+    fn test_derived_hash_affects_digest() {
+        #[derive(Hash)]
+        struct Point {
+            x: u64,
+            y: u64,
+        }
+
+        let mut a = PallasHasher::new().as_std_hasher();
+        let mut b = PallasHasher::new().as_std_hasher();
+        Point { x: 1, y: 2 }.hash(&mut a);
+        Point { x: 1, y: 3 }.hash(&mut b);
+
+        assert_ne!(a.finish(), b.finish());
+    }
+}
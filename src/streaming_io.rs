@@ -0,0 +1,138 @@
+//! Streaming hash entry points for readers and files.
+//!
+//! [`hash_reader`] absorbs a [`std::io::Read`] source through the same
+//! `TAG_BYTES` primitive-packing path [`PoseidonHasher::update`] already
+//! uses for byte slices, in bounded [`READ_BUFFER_SIZE`]-sized chunks, so
+//! arbitrarily large inputs can be hashed without holding the whole input
+//! in memory. Since each chunk is just another `update` call into the same
+//! packing buffer, the digest is independent of where the chunk boundaries
+//! happen to fall.
+//!
+//! `domain` is absorbed as a single leading byte primitive before any
+//! reader bytes. This is deliberately a plain `update`, not the DiR
+//! lane-tweak tagging the concrete curve hashers' `new_with_domain`
+//! performs (that constructor isn't part of the generic [`PoseidonHasher`]
+//! trait surface this module is generic over) — callers who need DiR
+//! domain separation should construct `H` themselves via
+//! `new_with_config_and_domain` and pass it through a thin non-generic
+//! wrapper instead.
+//!
+//! ```rust
+//! use poseidon_hash::streaming_io::hash_reader;
+//! use poseidon_hash::PallasHasher;
+//! use poseidon_hash::primitive::PackingConfig;
+//!
+//! # fn main() -> Result<(), poseidon_hash::HasherError> {
+//! let data = b"hello world".as_slice();
+//! let digest = hash_reader::<_, _, PallasHasher>(data, PackingConfig::default(), "DOMAIN")?;
+//! # let _ = digest;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::io::Read;
+
+use ark_ff::PrimeField;
+
+use crate::hasher::HasherError;
+use crate::primitive::PackingConfig;
+use crate::types::PoseidonHasher;
+
+/// Bounded read-buffer size for [`hash_reader`]/[`hash_file`] — large enough
+/// to amortize syscall overhead, small enough to avoid buffering the whole
+/// input in memory at once.
+pub const READ_BUFFER_SIZE: usize = 64 * 1024;
+
+/// Hash every byte read from `reader` with a fresh `H`, absorbing `domain`
+/// first (as a length-prefixed byte primitive, so it can never be confused
+/// with file content) and then the reader's bytes in
+/// [`READ_BUFFER_SIZE`]-sized chunks.
+pub fn hash_reader<F, I, H>(
+    mut reader: impl Read,
+    config: PackingConfig,
+    domain: impl AsRef<[u8]>,
+) -> Result<F, HasherError>
+where
+    F: PrimeField,
+    H: PoseidonHasher<F, I>,
+    for<'a> &'a [u8]: Into<I>,
+{
+    let mut hasher = H::new_with_config(config);
+    hasher.update(domain.as_ref());
+
+    let mut buffer = vec![0u8; READ_BUFFER_SIZE];
+    let mut read_any = false;
+    loop {
+        let bytes_read = reader.read(&mut buffer).map_err(|e| HasherError::Io {
+            reason: format!("failed to read from input: {e}"),
+        })?;
+        if bytes_read == 0 {
+            break;
+        }
+        read_any = true;
+        hasher.update(&buffer[..bytes_read]);
+    }
+    if !read_any {
+        // An empty reader still absorbs one empty byte primitive, as a
+        // single `update` of an empty slice would.
+        hasher.update(&buffer[..0]);
+    }
+    Ok(hasher.digest())
+}
+
+/// Hash the contents of the file at `path` with a fresh `H`; equivalent to
+/// opening the file and calling [`hash_reader`] on it.
+pub fn hash_file<F, I, H>(
+    path: impl AsRef<std::path::Path>,
+    config: PackingConfig,
+    domain: impl AsRef<[u8]>,
+) -> Result<F, HasherError>
+where
+    F: PrimeField,
+    H: PoseidonHasher<F, I>,
+    for<'a> &'a [u8]: Into<I>,
+{
+    let file = std::fs::File::open(path).map_err(|e| HasherError::Io {
+        reason: format!("failed to open input file: {e}"),
+    })?;
+    hash_reader::<F, I, H>(file, config, domain)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::PallasHasher;
+
+    fn reference_digest(data: &[u8], domain: &str) -> ark_pallas::Fq {
+        let mut hasher = PallasHasher::new_with_config(PackingConfig::default());
+        PoseidonHasher::update(&mut hasher, domain.as_bytes());
+        PoseidonHasher::update(&mut hasher, data);
+        hasher.digest()
+    }
+
+    #[test]
+    fn test_hash_reader_matches_direct_update() {
+        let data = b"the quick brown fox jumps over the lazy dog".repeat(10);
+        let via_reader =
+            hash_reader::<_, _, PallasHasher>(data.as_slice(), PackingConfig::default(), "DOMAIN")
+                .unwrap();
+        assert_eq!(via_reader, reference_digest(&data, "DOMAIN"));
+    }
+
+    #[test]
+    fn test_hash_reader_is_independent_of_buffer_sized_chunk_boundaries() {
+        let data = vec![0x5Au8; READ_BUFFER_SIZE * 3 + 17];
+        let digest =
+            hash_reader::<_, _, PallasHasher>(data.as_slice(), PackingConfig::default(), "DOMAIN")
+                .unwrap();
+        assert_eq!(digest, reference_digest(&data, "DOMAIN"));
+    }
+
+    #[test]
+    fn test_empty_reader_matches_direct_update_of_empty_input() {
+        let data: &[u8] = &[];
+        let digest =
+            hash_reader::<_, _, PallasHasher>(data, PackingConfig::default(), "DOMAIN").unwrap();
+        assert_eq!(digest, reference_digest(data, "DOMAIN"));
+    }
+}
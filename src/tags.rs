@@ -21,3 +21,10 @@ pub const TAG_I128: u8 = 0x1B;
 pub const TAG_ISIZE: u8 = 0x1C;
 pub const TAG_STRING: u8 = 0x20;
 pub const TAG_BYTES: u8 = 0x21;
+
+// `#[derive(PoseidonEncode)]` structural tags (see `crate::encode`).
+/// Leads a derived type's encoding: tag byte, then the type's name as bytes.
+pub const TAG_STRUCT_TYPE: u8 = 0x30;
+/// Leads an enum variant's encoding: tag byte, then the variant's index
+/// (u32 little-endian) as a leading discriminant field.
+pub const TAG_ENUM_VARIANT: u8 = 0x31;
@@ -0,0 +1,148 @@
+//! Dual-curve Fiat–Shamir transcript for 2-cycles (e.g. Pallas/Vesta), as used
+//! by folding/accumulation schemes such as Nova.
+//!
+//! A curve cycle `(C1, C2)` is chosen so that `C1::BaseField` and
+//! `C2::ScalarField` share the same modulus (and symmetrically for
+//! `C2::BaseField`/`C1::ScalarField`). [`CycleTranscript`] keeps one streaming
+//! [`MultiFieldHasherV1`] per curve side, so commitments on `C1` are absorbed
+//! natively over `C1::BaseField` while the resulting challenge is handed back
+//! as a `C2::ScalarField` element ready to use inside a `C2` circuit, and vice
+//! versa. Each side keeps its own Domain-in-Rate state, so challenges
+//! squeezed from one side can never collide with the other.
+
+use crate::hasher::{bits_to_le_bytes, MultiFieldHasherV1};
+use ark_crypto_primitives::sponge::Absorb;
+use ark_ec::AffineRepr;
+use ark_ff::{BigInteger, PrimeField, Zero};
+
+/// Reinterpret a squeezed `Src` field element as a `Dst` field element.
+///
+/// When the two moduli have the same bit size (the normal 2-cycle case),
+/// this is a direct byte-for-byte conversion. Otherwise, `value` is
+/// truncated to `min(Src::MODULUS_BIT_SIZE, Dst::MODULUS_BIT_SIZE) - 1` bits
+/// before reduction, so the result is never biased towards the low end of
+/// the smaller field.
+fn reinterpret_field<Src: PrimeField, Dst: PrimeField>(value: Src) -> Dst {
+    if Src::MODULUS_BIT_SIZE == Dst::MODULUS_BIT_SIZE {
+        let bytes = value.into_bigint().to_bytes_le();
+        return Dst::from_le_bytes_mod_order(&bytes);
+    }
+    let truncate_bits = std::cmp::min(Src::MODULUS_BIT_SIZE, Dst::MODULUS_BIT_SIZE) as usize - 1;
+    let mut bits = value.into_bigint().to_bits_le();
+    bits.truncate(truncate_bits);
+    Dst::from_le_bytes_mod_order(&bits_to_le_bytes(&bits))
+}
+
+/// Dual-curve Fiat–Shamir transcript over a 2-cycle `(C1, C2)`.
+///
+/// See the [module docs](self) for the cross-curve reinterpretation this
+/// enables.
+pub struct CycleTranscript<C1, C2>
+where
+    C1: AffineRepr,
+    C2: AffineRepr,
+    C1::BaseField: PrimeField + Zero + Absorb,
+    C1::ScalarField: PrimeField,
+    C2::BaseField: PrimeField + Zero + Absorb,
+    C2::ScalarField: PrimeField,
+{
+    c1_hasher: MultiFieldHasherV1<C1::BaseField, C1::ScalarField, C1>,
+    c2_hasher: MultiFieldHasherV1<C2::BaseField, C2::ScalarField, C2>,
+}
+
+impl<C1, C2> CycleTranscript<C1, C2>
+where
+    C1: AffineRepr,
+    C2: AffineRepr,
+    C1::BaseField: PrimeField + Zero + Absorb,
+    C1::ScalarField: PrimeField,
+    C2::BaseField: PrimeField + Zero + Absorb,
+    C2::ScalarField: PrimeField,
+{
+    /// Create a new transcript from each side's native Poseidon parameters.
+    pub fn new(
+        c1_params: &crate::ark_poseidon::ArkPoseidonConfig<C1::BaseField>,
+        c2_params: &crate::ark_poseidon::ArkPoseidonConfig<C2::BaseField>,
+    ) -> Self {
+        Self {
+            c1_hasher: MultiFieldHasherV1::new_from_ref(c1_params),
+            c2_hasher: MultiFieldHasherV1::new_from_ref(c2_params),
+        }
+    }
+
+    /// Absorb a `C1` affine point into the `C1` side of the transcript.
+    pub fn absorb_point_c1(&mut self, point: C1) {
+        self.c1_hasher.update_curve_point(point);
+    }
+
+    /// Absorb a `C2` affine point into the `C2` side of the transcript.
+    pub fn absorb_point_c2(&mut self, point: C2) {
+        self.c2_hasher.update_curve_point(point);
+    }
+
+    /// Squeeze a challenge from the `C1` side, reinterpreted as a
+    /// `C2::ScalarField` element (so it can be used directly inside a `C2`
+    /// circuit verifying a `C1`-side commitment).
+    pub fn squeeze_challenge_c1(&mut self) -> C2::ScalarField {
+        let squeezed = self.c1_hasher.squeeze_native_field_elements(1)[0];
+        reinterpret_field(squeezed)
+    }
+
+    /// Squeeze a challenge from the `C2` side, reinterpreted as a
+    /// `C1::ScalarField` element.
+    pub fn squeeze_challenge_c2(&mut self) -> C1::ScalarField {
+        let squeezed = self.c2_hasher.squeeze_native_field_elements(1)[0];
+        reinterpret_field(squeezed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pallas_vesta_transcript() -> CycleTranscript<ark_pallas::Affine, ark_vesta::Affine> {
+        CycleTranscript::new(
+            &crate::parameters::clone_parameters(&*crate::parameters::pallas::PALLAS_PARAMS),
+            &crate::parameters::clone_parameters(&*crate::parameters::vesta::VESTA_PARAMS),
+        )
+    }
+
+    #[test]
+    fn test_challenges_are_deterministic() {
+        let mut a = pallas_vesta_transcript();
+        let mut b = pallas_vesta_transcript();
+
+        a.absorb_point_c1(ark_pallas::Affine::generator());
+        b.absorb_point_c1(ark_pallas::Affine::generator());
+
+        assert_eq!(a.squeeze_challenge_c1(), b.squeeze_challenge_c1());
+    }
+
+    #[test]
+    fn test_sides_are_independent() {
+        let mut t = pallas_vesta_transcript();
+        t.absorb_point_c1(ark_pallas::Affine::generator());
+
+        let c1_challenge = t.squeeze_challenge_c1();
+        let c2_challenge = t.squeeze_challenge_c2();
+
+        // Different absorbed history and different per-side DiR state, so
+        // these should not coincidentally match.
+        assert_ne!(
+            c1_challenge.into_bigint().to_bytes_le(),
+            c2_challenge.into_bigint().to_bytes_le()
+        );
+    }
+
+    #[test]
+    fn test_absorbing_changes_challenge() {
+        let mut t = pallas_vesta_transcript();
+        let before = t.squeeze_challenge_c1();
+
+        let mut t2 = pallas_vesta_transcript();
+        t2.absorb_point_c1(ark_pallas::Affine::generator());
+        let after = t2.squeeze_challenge_c1();
+
+        assert_ne!(before, after);
+    }
+}
@@ -0,0 +1,608 @@
+//! Fixed-depth Poseidon Merkle trees.
+//!
+//! This module provides [`PoseidonTree`], a binary Merkle tree that uses a 2-to-1
+//! Poseidon compression function for parent hashing. It is generic over the base
+//! field so it can sit on top of any curve's embedded parameters, with
+//! [`PallasTree`] and [`VestaTree`] as ready-to-use aliases.
+//!
+//! Node hashing is domain-separated from [`crate::types::PallasHasher`] and the
+//! other general-purpose streaming hashers (a dedicated tag is absorbed before
+//! the two children), so leaf values hashed through the streaming API never
+//! collide with tree node hashes of the same field elements. The tag also
+//! mixes in the node's layer index, so a node hash at one height can never be
+//! replayed as a node hash at another height.
+//!
+//! Trees can also be grown incrementally with [`PoseidonTree::append`], which
+//! fills the next empty leaf slot and returns its [`Position`] — convenient
+//! for note-commitment-style trees where leaves arrive one at a time rather
+//! than as a pre-known batch. [`PoseidonTree::new_with_params`] computes the
+//! empty-subtree hash once per level and fills that level's layer with it;
+//! every layer is stored in full, so all `arity^depth` leaf slots are
+//! materialized whether set or not.
+//!
+//! ```rust
+//! use poseidon_hash::tree::PallasTree;
+//!
+//! let mut tree = PallasTree::new(4, ark_pallas::Fq::from(0u64));
+//! tree.set(2, ark_pallas::Fq::from(42u64));
+//! let root = tree.root();
+//!
+//! let proof = tree.proof(2);
+//! assert!(tree.verify(&proof, ark_pallas::Fq::from(42u64), root));
+//! ```
+
+use crate::ark_poseidon::ArkPoseidonConfig;
+use ark_crypto_primitives::sponge::{Absorb, CryptographicSponge, FieldBasedCryptographicSponge};
+use ark_ff::PrimeField;
+
+/// Domain tag absorbed before every node's children, separating tree node
+/// hashing from the streaming [`crate::hasher::MultiFieldHasherV1`] domain and
+/// from node hashing at other layers (`layer` counts up from the leaves, so
+/// layer 0 compresses leaves into the first internal layer).
+fn node_domain_tag<F: PrimeField>(layer: usize) -> F {
+    F::from_le_bytes_mod_order(format!("POSEIDON_TREE|NODE|{}", layer).as_bytes())
+}
+
+/// Poseidon-based `arity`-to-1 compression of `children` into one field
+/// element, for the node hashes at `layer` (counted up from the leaves).
+fn compress<F: PrimeField + Absorb>(params: &ArkPoseidonConfig<F>, layer: usize, children: &[F]) -> F {
+    let mut sponge = crate::ark_poseidon::ArkPoseidonSponge::new(params);
+    sponge.absorb(&node_domain_tag::<F>(layer));
+    for child in children {
+        sponge.absorb(child);
+    }
+    sponge.squeeze_native_field_elements(1)[0]
+}
+
+/// A Merkle inclusion proof for a [`PoseidonTree`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MerkleProof<F> {
+    /// Index of the leaf this proof is for.
+    pub leaf_index: usize,
+    /// Sibling hash at each level, from the leaf level up to the root.
+    pub siblings: Vec<F>,
+}
+
+/// Position of a leaf within a [`PoseidonTree`], as returned by
+/// [`PoseidonTree::append`].
+pub type Position = usize;
+
+/// Fixed-depth Merkle tree with Poseidon `arity`-to-1 node compression
+/// (binary, i.e. `arity = 2`, unless created via one of the `_with_arity`
+/// constructors).
+///
+/// The tree always has exactly `arity^depth` leaves. Leaves not explicitly
+/// [`set`](Self::set) or [`append`](Self::append)ed default to `default_leaf`,
+/// and `set`/`append` only recompute the path from the updated leaf to the
+/// root rather than the whole tree.
+pub struct PoseidonTree<F: PrimeField + Absorb> {
+    params: ArkPoseidonConfig<F>,
+    depth: usize,
+    arity: usize,
+    /// `layers[0]` holds the leaves; `layers[depth]` holds the single root.
+    layers: Vec<Vec<F>>,
+    /// Next free leaf slot for [`Self::append`].
+    next_index: usize,
+}
+
+impl<F: PrimeField + Absorb> PoseidonTree<F> {
+    /// Create a new binary tree of the given `depth` (i.e. `2^depth` leaves),
+    /// with every leaf initialized to `default_leaf`, using the supplied
+    /// Poseidon parameters for node compression.
+    pub fn new_with_params(depth: usize, default_leaf: F, params: ArkPoseidonConfig<F>) -> Self {
+        Self::new_with_params_and_arity(depth, 2, default_leaf, params)
+    }
+
+    /// Like [`Self::new_with_params`], but with a configurable node `arity`
+    /// (number of children compressed into each parent) instead of the
+    /// default binary tree.
+    pub fn new_with_params_and_arity(
+        depth: usize,
+        arity: usize,
+        default_leaf: F,
+        params: ArkPoseidonConfig<F>,
+    ) -> Self {
+        assert!(arity >= 2, "arity must be at least 2");
+        let mut layers = Vec::with_capacity(depth + 1);
+        let mut level_value = default_leaf;
+        let mut level_len = arity.pow(depth as u32);
+        layers.push(vec![default_leaf; level_len]);
+        for level in 0..depth {
+            level_value = compress(&params, level, &vec![level_value; arity]);
+            level_len /= arity;
+            layers.push(vec![level_value; level_len]);
+        }
+        Self {
+            params,
+            depth,
+            arity,
+            layers,
+            next_index: 0,
+        }
+    }
+
+    /// Build a binary tree directly from a full set of `2^depth` leaves,
+    /// computing every non-leaf layer bottom-up from the supplied leaves.
+    ///
+    /// With the `parallel` feature enabled, each layer's node hashes are
+    /// computed independently across threads via `rayon`; the Poseidon
+    /// permutation itself always runs single-threaded.
+    pub fn new_from_leaves(leaves: Vec<F>, params: ArkPoseidonConfig<F>) -> Self {
+        Self::new_from_leaves_with_arity(leaves, 2, params)
+    }
+
+    /// Like [`Self::new_from_leaves`], but with a configurable node `arity`.
+    pub fn new_from_leaves_with_arity(
+        leaves: Vec<F>,
+        arity: usize,
+        params: ArkPoseidonConfig<F>,
+    ) -> Self {
+        assert!(arity >= 2, "arity must be at least 2");
+        let mut depth = 0usize;
+        let mut capacity = 1usize;
+        while capacity < leaves.len() {
+            capacity *= arity;
+            depth += 1;
+        }
+        assert_eq!(
+            capacity,
+            leaves.len(),
+            "leaf count must be arity^depth for some depth"
+        );
+        let next_index = leaves.len();
+        let mut layers = Vec::with_capacity(depth + 1);
+        layers.push(leaves);
+        for level in 0..depth {
+            let prev = &layers[level];
+            #[cfg(feature = "parallel")]
+            let next: Vec<F> = {
+                use rayon::prelude::*;
+                prev.par_chunks(arity)
+                    .map(|group| compress(&params, level, group))
+                    .collect()
+            };
+            #[cfg(not(feature = "parallel"))]
+            let next: Vec<F> = prev
+                .chunks(arity)
+                .map(|group| compress(&params, level, group))
+                .collect();
+            layers.push(next);
+        }
+        Self {
+            params,
+            depth,
+            arity,
+            layers,
+            next_index,
+        }
+    }
+
+    /// Total leaf capacity of this tree (`arity^depth`).
+    fn capacity(&self) -> usize {
+        self.arity.pow(self.depth as u32)
+    }
+
+    /// Set the leaf at `index` and incrementally recompute the path to the root.
+    pub fn set(&mut self, index: usize, leaf: F) {
+        assert!(index < self.capacity(), "leaf index out of range");
+        self.layers[0][index] = leaf;
+        let mut idx = index;
+        for level in 0..self.depth {
+            let group_start = (idx / self.arity) * self.arity;
+            let children = self.layers[level][group_start..group_start + self.arity].to_vec();
+            let parent = compress(&self.params, level, &children);
+            idx /= self.arity;
+            self.layers[level + 1][idx] = parent;
+        }
+    }
+
+    /// Append `leaf` into the next empty slot and return its [`Position`].
+    ///
+    /// Unlike [`Self::set`], callers don't need to track which indices are
+    /// already occupied — useful for note-commitment-style trees where
+    /// leaves arrive one at a time in order. Panics if the tree is full.
+    pub fn append(&mut self, leaf: F) -> Position {
+        assert!(self.next_index < self.capacity(), "tree is full");
+        let position = self.next_index;
+        self.set(position, leaf);
+        self.next_index += 1;
+        position
+    }
+
+    /// Return the current Merkle root.
+    pub fn root(&self) -> F {
+        self.layers[self.depth][0]
+    }
+
+    /// Build a membership proof for the leaf currently at `index`.
+    ///
+    /// Only meaningful for binary (`arity = 2`) trees; see
+    /// [`Self::authentication_path`] for the arity-agnostic equivalent.
+    pub fn proof(&self, index: usize) -> MerkleProof<F> {
+        assert_eq!(self.arity, 2, "proof()/verify() assume a binary tree; use authentication_path() for arity > 2");
+        assert!(index < self.capacity(), "leaf index out of range");
+        let mut siblings = Vec::with_capacity(self.depth);
+        let mut idx = index;
+        for level in 0..self.depth {
+            siblings.push(self.layers[level][idx ^ 1]);
+            idx /= 2;
+        }
+        MerkleProof {
+            leaf_index: index,
+            siblings,
+        }
+    }
+
+    /// Verify that `leaf` is included at `proof.leaf_index` under `root`.
+    ///
+    /// Only meaningful for binary (`arity = 2`) trees; see the free function
+    /// [`verify_path`] for the arity-agnostic equivalent.
+    pub fn verify(&self, proof: &MerkleProof<F>, leaf: F, root: F) -> bool {
+        if self.arity != 2 || proof.siblings.len() != self.depth {
+            return false;
+        }
+        let mut current = leaf;
+        let mut idx = proof.leaf_index;
+        for (level, sibling) in proof.siblings.iter().enumerate() {
+            current = if idx % 2 == 0 {
+                compress(&self.params, level, &[current, *sibling])
+            } else {
+                compress(&self.params, level, &[*sibling, current])
+            };
+            idx /= 2;
+        }
+        current == root
+    }
+
+    /// Arity-agnostic authentication path for the leaf at `index`: for every
+    /// layer from the leaves to the root, the full sibling group the leaf's
+    /// node belongs to (excluding the node's own value), in tree order.
+    /// Length is `depth * (arity - 1)`.
+    ///
+    /// Pairs with the free function [`verify_path`], which needs only
+    /// `self.arity` and the tree's Poseidon parameters (not the tree itself)
+    /// to check a path against a root.
+    pub fn authentication_path(&self, index: usize) -> Vec<F> {
+        assert!(index < self.capacity(), "leaf index out of range");
+        let mut path = Vec::with_capacity(self.depth * (self.arity - 1));
+        let mut idx = index;
+        for level in 0..self.depth {
+            let group_start = (idx / self.arity) * self.arity;
+            let offset_in_group = idx % self.arity;
+            for (offset, &value) in self.layers[level][group_start..group_start + self.arity]
+                .iter()
+                .enumerate()
+            {
+                if offset != offset_in_group {
+                    path.push(value);
+                }
+            }
+            idx /= self.arity;
+        }
+        path
+    }
+
+    /// This tree's node arity (children compressed per parent).
+    pub fn arity(&self) -> usize {
+        self.arity
+    }
+}
+
+/// Verify a binary-tree [`MerkleProof`] against `root`, independent of any
+/// particular tree instance — the fixed-arity-2 analogue of [`verify_path`],
+/// for callers that already have a `(leaf_index, siblings)` proof rather
+/// than the arity-agnostic sibling-group path.
+pub fn verify<F: PrimeField + Absorb>(
+    params: &ArkPoseidonConfig<F>,
+    root: F,
+    leaf: F,
+    proof: &MerkleProof<F>,
+) -> bool {
+    let mut current = leaf;
+    let mut idx = proof.leaf_index;
+    for (level, sibling) in proof.siblings.iter().enumerate() {
+        current = if idx % 2 == 0 {
+            compress(params, level, &[current, *sibling])
+        } else {
+            compress(params, level, &[*sibling, current])
+        };
+        idx /= 2;
+    }
+    current == root
+}
+
+/// Verify an arity-agnostic [`PoseidonTree::authentication_path`] against
+/// `root`, independent of any particular tree instance.
+///
+/// `arity` must match the tree the path was generated from; `path.len()`
+/// must be a multiple of `arity - 1` (the tree's depth is inferred from it).
+pub fn verify_path<F: PrimeField + Absorb>(
+    params: &ArkPoseidonConfig<F>,
+    root: F,
+    leaf: F,
+    mut index: usize,
+    arity: usize,
+    path: &[F],
+) -> bool {
+    if arity < 2 || path.len() % (arity - 1) != 0 {
+        return false;
+    }
+    let depth = path.len() / (arity - 1);
+    let mut current = leaf;
+    let mut cursor = 0usize;
+    for level in 0..depth {
+        let offset_in_group = index % arity;
+        let mut children = Vec::with_capacity(arity);
+        let mut sibling_offset = 0usize;
+        for position in 0..arity {
+            if position == offset_in_group {
+                children.push(current);
+            } else {
+                children.push(path[cursor + sibling_offset]);
+                sibling_offset += 1;
+            }
+        }
+        cursor += arity - 1;
+        current = compress(params, level, &children);
+        index /= arity;
+    }
+    current == root
+}
+
+/// Poseidon Merkle tree over the Pallas base field.
+pub type PallasTree = PoseidonTree<ark_pallas::Fq>;
+
+impl PallasTree {
+    /// Create a new Pallas tree of the given depth, using the crate's embedded
+    /// Pallas Poseidon parameters.
+    pub fn new(depth: usize, default_leaf: ark_pallas::Fq) -> Self {
+        PoseidonTree::new_with_params(
+            depth,
+            default_leaf,
+            crate::parameters::clone_parameters(&*crate::parameters::pallas::PALLAS_PARAMS),
+        )
+    }
+
+    /// Build a Pallas tree directly from a full set of `2^depth` leaves.
+    pub fn from_leaves(leaves: Vec<ark_pallas::Fq>) -> Self {
+        PoseidonTree::new_from_leaves(
+            leaves,
+            crate::parameters::clone_parameters(&*crate::parameters::pallas::PALLAS_PARAMS),
+        )
+    }
+}
+
+/// Poseidon Merkle tree over the Vesta base field.
+pub type VestaTree = PoseidonTree<ark_vesta::Fq>;
+
+impl VestaTree {
+    /// Create a new Vesta tree of the given depth, using the crate's embedded
+    /// Vesta Poseidon parameters.
+    pub fn new(depth: usize, default_leaf: ark_vesta::Fq) -> Self {
+        PoseidonTree::new_with_params(
+            depth,
+            default_leaf,
+            crate::parameters::clone_parameters(&*crate::parameters::vesta::VESTA_PARAMS),
+        )
+    }
+
+    /// Build a Vesta tree directly from a full set of `2^depth` leaves.
+    pub fn from_leaves(leaves: Vec<ark_vesta::Fq>) -> Self {
+        PoseidonTree::new_from_leaves(
+            leaves,
+            crate::parameters::clone_parameters(&*crate::parameters::vesta::VESTA_PARAMS),
+        )
+    }
+}
+
+/// Poseidon Merkle tree over the BN254 base field.
+pub type BN254Tree = PoseidonTree<ark_bn254::Fq>;
+
+impl BN254Tree {
+    /// Create a new BN254 tree of the given depth, using the crate's embedded
+    /// BN254 Poseidon parameters.
+    pub fn new(depth: usize, default_leaf: ark_bn254::Fq) -> Self {
+        PoseidonTree::new_with_params(
+            depth,
+            default_leaf,
+            crate::parameters::clone_parameters(&*crate::parameters::bn254::BN254_PARAMS),
+        )
+    }
+
+    /// Build a BN254 tree directly from a full set of `2^depth` leaves.
+    pub fn from_leaves(leaves: Vec<ark_bn254::Fq>) -> Self {
+        PoseidonTree::new_from_leaves(
+            leaves,
+            crate::parameters::clone_parameters(&*crate::parameters::bn254::BN254_PARAMS),
+        )
+    }
+}
+
+/// Poseidon Merkle tree over the BLS12-381 base field.
+pub type BLS12_381Tree = PoseidonTree<ark_bls12_381::Fq>;
+
+impl BLS12_381Tree {
+    /// Create a new BLS12-381 tree of the given depth, using the crate's
+    /// embedded BLS12-381 Poseidon parameters.
+    pub fn new(depth: usize, default_leaf: ark_bls12_381::Fq) -> Self {
+        PoseidonTree::new_with_params(
+            depth,
+            default_leaf,
+            crate::parameters::clone_parameters(&*crate::parameters::bls12_381::BLS12_381_PARAMS),
+        )
+    }
+
+    /// Build a BLS12-381 tree directly from a full set of `2^depth` leaves.
+    pub fn from_leaves(leaves: Vec<ark_bls12_381::Fq>) -> Self {
+        PoseidonTree::new_from_leaves(
+            leaves,
+            crate::parameters::clone_parameters(&*crate::parameters::bls12_381::BLS12_381_PARAMS),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_ff::Zero;
+
+    #[test]
+    fn test_default_tree_is_consistent() {
+        let tree_a = PallasTree::new(3, ark_pallas::Fq::zero());
+        let tree_b = PallasTree::new(3, ark_pallas::Fq::zero());
+        assert_eq!(tree_a.root(), tree_b.root());
+    }
+
+    #[test]
+    fn test_set_changes_root() {
+        let mut tree = PallasTree::new(3, ark_pallas::Fq::zero());
+        let before = tree.root();
+        tree.set(5, ark_pallas::Fq::from(7u64));
+        assert_ne!(tree.root(), before);
+    }
+
+    #[test]
+    fn test_proof_round_trip() {
+        let mut tree = PallasTree::new(4, ark_pallas::Fq::zero());
+        tree.set(9, ark_pallas::Fq::from(123u64));
+        let root = tree.root();
+
+        let proof = tree.proof(9);
+        assert!(tree.verify(&proof, ark_pallas::Fq::from(123u64), root));
+        assert!(!tree.verify(&proof, ark_pallas::Fq::from(124u64), root));
+    }
+
+    #[test]
+    fn test_from_leaves_matches_incremental_construction() {
+        let leaves: Vec<_> = (0..8u64).map(ark_pallas::Fq::from).collect();
+
+        let batch = PallasTree::from_leaves(leaves.clone());
+
+        let mut incremental = PallasTree::new(3, ark_pallas::Fq::zero());
+        for (i, leaf) in leaves.into_iter().enumerate() {
+            incremental.set(i, leaf);
+        }
+
+        assert_eq!(batch.root(), incremental.root());
+    }
+
+    #[test]
+    fn test_node_hash_domain_separated_from_leaf_order() {
+        // Swapping children must change the parent hash (no accidental commutativity).
+        let params = crate::parameters::clone_parameters(&*crate::parameters::pallas::PALLAS_PARAMS);
+        let a = ark_pallas::Fq::from(1u64);
+        let b = ark_pallas::Fq::from(2u64);
+        assert_ne!(compress(&params, 0, &[a, b]), compress(&params, 0, &[b, a]));
+    }
+
+    #[test]
+    fn test_node_hash_domain_separated_from_layer() {
+        // The same children compressed at different layers must not collide.
+        let params = crate::parameters::clone_parameters(&*crate::parameters::pallas::PALLAS_PARAMS);
+        let a = ark_pallas::Fq::from(1u64);
+        let b = ark_pallas::Fq::from(2u64);
+        assert_ne!(compress(&params, 0, &[a, b]), compress(&params, 1, &[a, b]));
+    }
+
+    #[test]
+    fn test_append_fills_slots_in_order_and_matches_set() {
+        let mut appended = PallasTree::new(3, ark_pallas::Fq::zero());
+        let mut set_directly = PallasTree::new(3, ark_pallas::Fq::zero());
+        for i in 0..8u64 {
+            let leaf = ark_pallas::Fq::from(i);
+            let pos = appended.append(leaf);
+            assert_eq!(pos, i as usize);
+            set_directly.set(i as usize, leaf);
+        }
+        assert_eq!(appended.root(), set_directly.root());
+    }
+
+    #[test]
+    #[should_panic(expected = "tree is full")]
+    fn test_append_past_capacity_panics() {
+        let mut tree = PallasTree::new(1, ark_pallas::Fq::zero());
+        tree.append(ark_pallas::Fq::from(1u64));
+        tree.append(ark_pallas::Fq::from(2u64));
+        tree.append(ark_pallas::Fq::from(3u64));
+    }
+
+    #[test]
+    fn test_authentication_path_round_trip() {
+        let mut tree = PallasTree::new(4, ark_pallas::Fq::zero());
+        tree.set(9, ark_pallas::Fq::from(123u64));
+        let root = tree.root();
+
+        let path = tree.authentication_path(9);
+        let params = crate::parameters::clone_parameters(&*crate::parameters::pallas::PALLAS_PARAMS);
+        assert!(verify_path(
+            &params,
+            root,
+            ark_pallas::Fq::from(123u64),
+            9,
+            tree.arity(),
+            &path,
+        ));
+        assert!(!verify_path(
+            &params,
+            root,
+            ark_pallas::Fq::from(124u64),
+            9,
+            tree.arity(),
+            &path,
+        ));
+    }
+
+    #[test]
+    fn test_free_verify_matches_method_verify() {
+        let mut tree = PallasTree::new(4, ark_pallas::Fq::zero());
+        tree.set(9, ark_pallas::Fq::from(123u64));
+        let root = tree.root();
+        let proof = tree.proof(9);
+        let params = crate::parameters::clone_parameters(&*crate::parameters::pallas::PALLAS_PARAMS);
+
+        assert!(verify(&params, root, ark_pallas::Fq::from(123u64), &proof));
+        assert!(!verify(&params, root, ark_pallas::Fq::from(124u64), &proof));
+    }
+
+    #[test]
+    fn test_ternary_tree_authentication_path_round_trip() {
+        let leaves: Vec<_> = (0..9u64).map(ark_pallas::Fq::from).collect();
+        let params = crate::parameters::clone_parameters(&*crate::parameters::pallas::PALLAS_PARAMS);
+        let tree = PoseidonTree::new_from_leaves_with_arity(leaves, 3, params.clone());
+
+        let path = tree.authentication_path(5);
+        assert!(verify_path(
+            &params,
+            tree.root(),
+            ark_pallas::Fq::from(5u64),
+            5,
+            3,
+            &path,
+        ));
+    }
+
+    #[test]
+    fn test_bn254_tree_proof_round_trip() {
+        let mut tree = BN254Tree::new(4, ark_bn254::Fq::zero());
+        tree.set(9, ark_bn254::Fq::from(123u64));
+        let root = tree.root();
+
+        let proof = tree.proof(9);
+        assert!(tree.verify(&proof, ark_bn254::Fq::from(123u64), root));
+        assert!(!tree.verify(&proof, ark_bn254::Fq::from(124u64), root));
+    }
+
+    #[test]
+    fn test_bls12_381_tree_from_leaves_matches_incremental_construction() {
+        let leaves: Vec<_> = (0..8u64).map(ark_bls12_381::Fq::from).collect();
+
+        let batch = BLS12_381Tree::from_leaves(leaves.clone());
+
+        let mut incremental = BLS12_381Tree::new(3, ark_bls12_381::Fq::zero());
+        for (i, leaf) in leaves.into_iter().enumerate() {
+            incremental.set(i, leaf);
+        }
+
+        assert_eq!(batch.root(), incremental.root());
+    }
+}
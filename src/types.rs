@@ -7,7 +7,8 @@
 use crate::hasher::{FieldInput, MultiFieldHasherV1};
 use crate::parameters::*;
 use crate::primitive::PackingConfig;
-use ark_ff::PrimeField;
+use ark_crypto_primitives::sponge::{CryptographicSponge, FieldBasedCryptographicSponge};
+use ark_ff::{BigInteger, PrimeField};
 use zeroize::ZeroizeOnDrop;
 
 /// Trait for curve-specific Poseidon hashers with primitive type support.
@@ -33,6 +34,8 @@ where
     fn reset_hasher(&mut self);
     #[doc(hidden)]
     fn get_element_count(&self) -> usize;
+    #[doc(hidden)]
+    fn squeeze_result(&mut self, n: usize) -> Vec<F>;
 
     /// Update the hasher with any compatible input.
     /// This accepts field elements, curve points, primitives, or any type with a From implementation.
@@ -45,6 +48,12 @@ where
         self.digest_result()
     }
 
+    /// Like [`Self::digest`], but wrapped in [`crate::digest_output::PoseidonDigest`]
+    /// for its byte views, hex round-tripping, and constant-time equality.
+    fn digest_wrapped(&mut self) -> crate::digest_output::PoseidonDigest<F> {
+        crate::digest_output::PoseidonDigest::from_field(self.digest())
+    }
+
     /// Consume the hasher and return the final hash result.
     /// Equivalent to `digest()` but takes ownership, ensuring the hasher cannot be reused.
     fn finalize(mut self) -> F
@@ -64,38 +73,131 @@ where
     fn element_count(&self) -> usize {
         self.get_element_count()
     }
+
+    /// Squeeze `n` output elements in XOF/PRNG-seed-expander style,
+    /// continuing from whatever has been absorbed so far; the output is
+    /// deterministic across runs. The `MultiFieldHasher`-backed hashers
+    /// absorb a per-call output tag before each squeeze (see
+    /// [`crate::hasher::MultiFieldHasher::squeeze_native_field_elements`]),
+    /// so for them `squeeze(1)` does not return [`Self::digest`]'s value,
+    /// and `squeeze(n)` followed by `squeeze(m)` differs from a single
+    /// `squeeze(n + m)`.
+    fn squeeze(&mut self, n: usize) -> Vec<F> {
+        self.squeeze_result(n)
+    }
+
+    /// Hash `inputs` in one shot: equivalent to constructing a fresh
+    /// hasher, absorbing every element in order, and digesting. Useful for
+    /// generic code (Merkle trees, signature verification) that only needs
+    /// a plain field-element hash and doesn't want to name a concrete
+    /// curve hasher type.
+    fn hash(&self, inputs: &[F]) -> F
+    where
+        Self: Sized,
+        F: Into<I>,
+    {
+        let mut hasher = Self::new();
+        for &x in inputs {
+            hasher.update(x);
+        }
+        hasher.digest()
+    }
+
+    /// Hash a single field element; equivalent to `self.hash(&[x])`.
+    fn hash_single(&self, x: F) -> F
+    where
+        Self: Sized,
+        F: Into<I>,
+    {
+        self.hash(&[x])
+    }
+
+    /// The digest of an empty input (no elements absorbed) — a stable
+    /// sentinel usable as the default/empty leaf value in a Merkle tree
+    /// built on this hasher.
+    fn get_genesis(&self) -> F
+    where
+        Self: Sized,
+    {
+        Self::new().digest()
+    }
+
+    /// Whether `x`'s big-integer representation is already the canonical,
+    /// fully-reduced (`< modulus`) form. Every safely-constructed `F` value
+    /// already satisfies this by the field type's own invariants; this
+    /// exists for callers re-validating field elements that crossed a
+    /// non-Rust boundary (e.g. decoded from raw bytes) before absorbing them.
+    fn is_element_size_valid(&self, x: &F) -> bool {
+        x.into_bigint() < F::MODULUS
+    }
+}
+
+/// Sponge domain-separation mode for `with_domain` on the curve-specific
+/// hashers (e.g. [`PallasHasher::with_domain`]), selecting how absorption is
+/// separated by message length so that messages of different declared
+/// lengths can never produce colliding digests.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Domain {
+    /// The message length is fixed and known up front as exactly `n` field
+    /// elements. Absorbing past `n` elements is a programming error and
+    /// panics, so outputs for different declared lengths can never collide.
+    /// See also `hash_fixed` for a one-shot, const-generic equivalent when
+    /// `n` is known at compile time.
+    ConstantLength(usize),
+    /// The message length is not known up front. This is the default mode
+    /// used by `new()`.
+    VariableLength,
 }
 
 // Macro to define curve-specific hasher types and impls
 macro_rules! define_curve_hasher {
     (
         $Hasher:ident,
+        input = $Input:ident,
         fq = $fq:path,
         fr = $fr:path,
         affine = $aff:path,
-        params = $params:path
+        params = $params:path,
+        repr_len = $repr_len:expr
     ) => {
+        /// Input type accepted by [`$Hasher::update`].
+        pub type $Input = FieldInput<$fq, $fr, $aff>;
+
         #[derive(ZeroizeOnDrop)]
         pub struct $Hasher {
             inner: MultiFieldHasherV1<$fq, $fr, $aff>,
+            /// Declared element cap under [`Domain::ConstantLength`], enforced
+            /// by [`PoseidonHasher::update_field_input`]. `None` under the
+            /// default [`Domain::VariableLength`] mode.
+            #[zeroize(skip)]
+            max_elements: Option<usize>,
         }
 
         impl PoseidonHasher<$fq, FieldInput<$fq, $fr, $aff>> for $Hasher {
             fn new() -> Self {
                 Self {
                     inner: MultiFieldHasherV1::new_from_ref(&$params),
+                    max_elements: None,
                 }
             }
 
             fn new_with_config(config: PackingConfig) -> Self {
                 Self {
                     inner: MultiFieldHasherV1::new_with_config_from_ref(&$params, config),
+                    max_elements: None,
                 }
             }
 
             #[inline]
             fn update_field_input(&mut self, input: FieldInput<$fq, $fr, $aff>) {
-                self.inner.update(input)
+                self.inner.update(input);
+                if let Some(max) = self.max_elements {
+                    assert!(
+                        self.inner.element_count() <= max,
+                        "exceeded declared ConstantLength({}) while absorbing",
+                        max
+                    );
+                }
             }
             #[inline]
             fn digest_result(&mut self) -> $fq {
@@ -109,6 +211,10 @@ macro_rules! define_curve_hasher {
             fn get_element_count(&self) -> usize {
                 self.inner.element_count()
             }
+            #[inline]
+            fn squeeze_result(&mut self, n: usize) -> Vec<$fq> {
+                self.inner.squeeze(n)
+            }
         }
 
         impl Default for $Hasher {
@@ -157,25 +263,263 @@ macro_rules! define_curve_hasher {
                 h
             }
 
+            /// Create a hasher pre-seeded with a single caller-supplied
+            /// field-element tag, absorbed before any other input. See
+            /// [`crate::hasher::MultiFieldHasher::new_with_capacity_tag`]
+            /// for the rationale (this crate's equivalent of a pluggable
+            /// capacity initializer, given its sponge backend is opaque).
+            pub fn new_with_capacity_tag(tag: $fq) -> Self {
+                let mut h = <Self as PoseidonHasher<$fq, FieldInput<$fq, $fr, $aff>>>::new();
+                h.inner.absorb_capacity_tag(tag);
+                h
+            }
+
             // Domain-in-Rate is the default; dedicated constructors removed
+
+            /// Create a keyed hasher for a PRF/MAC construction: `key` is
+            /// absorbed (under its own domain tag, distinct from
+            /// [`Self::new_with_domain`]) before any subsequent `update`, so
+            /// [`Self::mac`] is unpredictable without the key and digests of
+            /// the same message under different keys are unlinkable.
+            pub fn with_key(key: &[$fq]) -> Self {
+                let mut h = <Self as PoseidonHasher<$fq, FieldInput<$fq, $fr, $aff>>>::new();
+                h.inner.absorb_domain(b"POSEIDON_KEYED_MAC");
+                for &k in key {
+                    h.inner.update_base_field(k);
+                }
+                h
+            }
+
+            /// The MAC tag over everything absorbed since [`Self::with_key`].
+            /// An alias for [`Self::digest`] under a keyed hasher.
+            pub fn mac(&self) -> $fq {
+                self.inner.digest()
+            }
+
+            /// Constant-time comparison of [`Self::mac`] against `expected`,
+            /// so callers verifying a MAC tag don't leak timing information
+            /// about where in the tag a mismatch occurs. Built on
+            /// [`crate::ct_eq::ct_eq`], the crate's one constant-time
+            /// comparison routine, rather than a second hand-rolled compare.
+            pub fn verify_mac(&self, expected: $fq) -> bool {
+                crate::ct_eq::ct_eq(&self.mac(), &expected)
+            }
+
+            /// Create a hasher in the given sponge [`Domain`] mode.
+            ///
+            /// [`Domain::ConstantLength(n)`](Domain::ConstantLength) tags the
+            /// stream with a domain separator encoding `n` and rejects (via
+            /// panic) absorption of more than `n` elements, so a declared
+            /// length of `n` can never collide with a declared length of
+            /// `m != n` sharing a common prefix.
+            /// [`Domain::VariableLength`] is exactly the untagged default
+            /// behavior of [`Self::new`].
+            pub fn with_domain(domain: Domain) -> Self {
+                let mut h = <Self as PoseidonHasher<$fq, FieldInput<$fq, $fr, $aff>>>::new();
+                if let Domain::ConstantLength(n) = domain {
+                    h.inner
+                        .absorb_domain(format!("POSEIDON|CONSTANT_LENGTH|{}", n).as_bytes());
+                    h.max_elements = Some(n);
+                }
+                h
+            }
+
+            /// Finalize a hasher created via [`Self::with_domain`] under
+            /// [`Domain::ConstantLength`], returning
+            /// [`HasherError::ConstantLengthUnderfilled`] instead of a
+            /// digest if fewer than the declared element count has been
+            /// absorbed so far. Overfill is instead rejected eagerly (via
+            /// panic) the moment an over-limit `update` call is made, since
+            /// by then the excess element is already unrecoverably part of
+            /// the sponge's absorbed state.
+            ///
+            /// Under [`Domain::VariableLength`] (the default — no declared
+            /// length), this always succeeds and is equivalent to
+            /// [`Self::digest`].
+            pub fn digest_checked(&mut self) -> crate::hasher::HasherResult<$fq> {
+                if let Some(expected) = self.max_elements {
+                    let actual = self.inner.element_count();
+                    if actual != expected {
+                        return Err(crate::hasher::HasherError::ConstantLengthUnderfilled {
+                            expected,
+                            actual,
+                        });
+                    }
+                }
+                Ok(self.digest())
+            }
+
+            /// Constant-length Poseidon hash of exactly `L` base-field elements.
+            ///
+            /// Unlike the streaming `update`/`digest` API, the message length `L` is
+            /// committed into the sponge's capacity lane *before* any input is
+            /// absorbed, so the absorb schedule is fixed and outputs for different
+            /// `L` can never collide (Orchard's `ConstantLength` domain). This mode
+            /// is meant for small, fixed-arity hashes such as 2-to-1 Merkle
+            /// compression, and is independent from (and not interoperable with)
+            /// the streaming hasher's Domain-in-Rate tagging.
+            pub fn hash_fixed<const L: usize>(inputs: [$fq; L]) -> $fq {
+                let mut sponge = crate::ark_poseidon::ArkPoseidonSponge::new(
+                    &crate::parameters::clone_parameters(&$params),
+                );
+                // Capacity lane(s) start at index 0; encode the fixed length there.
+                sponge.state[0] = <$fq>::from(L as u64);
+                sponge.absorb(&inputs.to_vec());
+                sponge.squeeze_native_field_elements(1)[0]
+            }
+
+            /// Hash a batch of independent messages, one [`$Hasher`] per message.
+            ///
+            /// Each message is hashed with a fresh hasher instance (default packing
+            /// config, no domain), in the same order as `inputs`. With the
+            /// `parallel` feature enabled, independent messages are fanned out
+            /// across threads via `rayon`; the Poseidon permutation itself always
+            /// runs single-threaded.
+            pub fn hash_many(inputs: &[Vec<FieldInput<$fq, $fr, $aff>>]) -> Vec<$fq> {
+                fn hash_one(message: &[FieldInput<$fq, $fr, $aff>]) -> $fq {
+                    let mut hasher = $Hasher::new();
+                    for input in message.iter().cloned() {
+                        hasher.update(input);
+                    }
+                    hasher.digest()
+                }
+
+                #[cfg(feature = "parallel")]
+                {
+                    use rayon::prelude::*;
+                    inputs.par_iter().map(|m| hash_one(m)).collect()
+                }
+                #[cfg(not(feature = "parallel"))]
+                {
+                    inputs.iter().map(|m| hash_one(m)).collect()
+                }
+            }
+
+            /// Wrap this hasher as a standard-library [`std::hash::Hasher`],
+            /// so any `#[derive(Hash)]` type can be absorbed via
+            /// `value.hash(&mut h)`. See
+            /// [`crate::std_hasher::PoseidonStdHasher`] for the
+            /// framing/finalization details.
+            pub fn as_std_hasher(self) -> crate::std_hasher::PoseidonStdHasher<$fq, $fr, $aff> {
+                crate::std_hasher::PoseidonStdHasher::new(self.inner)
+            }
+
+            /// Absorb a base field element decoded strictly from its
+            /// canonical little-endian byte representation, rejecting (via
+            /// `Err`) any encoding that is not already reduced (`>= modulus`)
+            /// instead of silently wrapping it. See
+            /// [`crate::hasher::MultiFieldHasher::update_bytes`] for the
+            /// rationale.
+            pub fn update_bytes(&mut self, bytes: &[u8]) -> crate::hasher::HasherResult<()> {
+                self.inner.update_bytes(bytes)
+            }
+
+            /// Absorb `data` with a fixed element count derived from
+            /// `max_len`, matching a ZK circuit that absorbs a statically
+            /// sized array. See
+            /// [`crate::primitive::VarLenBytes`]/[`crate::hasher::MultiFieldHasher::update_var_len_bytes`].
+            pub fn update_var_len_bytes(&mut self, data: &[u8], max_len: usize) {
+                self.inner.update_var_len_bytes(data, max_len)
+            }
+
+            /// Absorb exactly `N` bytes with a fixed element count, matching
+            /// a ZK circuit that absorbs a statically-sized `N`-byte array.
+            /// See [`crate::primitive::FixLenBytes`].
+            pub fn update_fix_len_bytes<const N: usize>(&mut self, data: &[u8; N]) {
+                self.inner.update_fix_len_bytes(data)
+            }
+
+            /// Squeeze `n` field elements in XOF/KDF style, continuing from
+            /// whatever has been absorbed so far. Unlike [`Self::digest`],
+            /// this mutates the hasher's state (each call advances the
+            /// sponge and the output-domain-separation counter), so repeated
+            /// calls yield the next output block rather than the same value;
+            /// see [`crate::hasher::MultiFieldHasher::squeeze`] for the
+            /// per-call domain-separation rule.
+            pub fn squeeze(&mut self, n: usize) -> Vec<$fq> {
+                self.inner.squeeze(n)
+            }
+
+            /// Like [`Self::squeeze`], but writes directly into `out` instead
+            /// of allocating a new `Vec`.
+            pub fn squeeze_into(&mut self, out: &mut [$fq]) {
+                out.copy_from_slice(&self.inner.squeeze(out.len()));
+            }
+
+            /// Squeeze `num_bytes` bytes in XOF/KDF style. See
+            /// [`crate::hasher::MultiFieldHasher::squeeze_bytes`].
+            pub fn squeeze_bytes(&mut self, num_bytes: usize) -> Vec<u8> {
+                self.inner.squeeze_bytes(num_bytes)
+            }
+
+            /// Number of rate lanes this hasher's sponge absorbs/squeezes
+            /// per permutation call. See [`Self::absorb_chunk`].
+            pub fn rate(&self) -> usize {
+                self.inner.rate()
+            }
+
+            /// Absorb one already-packed block of base-field elements,
+            /// streaming precomputed or externally-packed input through the
+            /// permutation one rate-sized block at a time. See
+            /// [`crate::hasher::MultiFieldHasher::absorb_chunk`].
+            pub fn absorb_chunk(&mut self, block: &[$fq], is_final: bool) -> Option<$fq> {
+                self.inner.absorb_chunk(block, is_final)
+            }
+
+            /// Canonical little-endian byte representation of
+            /// [`Self::digest`]'s output.
+            pub fn digest_to_repr(&mut self) -> [u8; $repr_len] {
+                self.inner.digest_to_repr().try_into().expect(
+                    "field digest byte length matches this curve's canonical representation size",
+                )
+            }
+
+            /// Hash `data` by chunking it (`config.parallel_chunk_bytes` per
+            /// chunk) and reducing the per-chunk digests through a balanced
+            /// binary tree — across threads when the `parallel` feature is
+            /// enabled. See [`crate::parallel_hash::digest_parallel`] for the
+            /// shape-determinism guarantee that keeps this reconcilable with
+            /// itself regardless of thread count.
+            pub fn digest_parallel(data: &[u8], config: PackingConfig) -> $fq {
+                crate::parallel_hash::digest_parallel::<$fq, FieldInput<$fq, $fr, $aff>, Self>(
+                    data, config,
+                )
+            }
+
+            /// Hash each of `N` independent inputs, returning one digest per
+            /// lane. Not a performance optimization today — see
+            /// [`crate::batch_hash`]'s module docs for why, and what this
+            /// API shape is actually for.
+            pub fn digest_batch<const N: usize>(
+                inputs: [&[u8]; N],
+                config: PackingConfig,
+            ) -> [$fq; N] {
+                crate::batch_hash::digest_batch::<$fq, FieldInput<$fq, $fr, $aff>, Self, N>(
+                    inputs, config,
+                )
+            }
         }
     };
 }
 
 define_curve_hasher!(
     PallasHasher,
+    input = PallasInput,
     fq = ark_pallas::Fq,
     fr = ark_pallas::Fr,
     affine = ark_pallas::Affine,
-    params = pallas::PALLAS_PARAMS
+    params = pallas::PALLAS_PARAMS,
+    repr_len = 32
 );
 
 define_curve_hasher!(
     VestaHasher,
+    input = VestaInput,
     fq = ark_vesta::Fq,
     fr = ark_vesta::Fr,
     affine = ark_vesta::Affine,
-    params = vesta::VESTA_PARAMS
+    params = vesta::VESTA_PARAMS,
+    repr_len = 32
 );
 
 // Pallas-specific variant-selecting constructors
@@ -185,6 +529,7 @@ impl PallasHasher {
         let params = crate::parameters::pallas::pallas_params_for(variant);
         Self {
             inner: MultiFieldHasherV1::new_from_ref(params),
+            max_elements: None,
         }
     }
 
@@ -196,6 +541,7 @@ impl PallasHasher {
         let params = crate::parameters::pallas::pallas_params_for(variant);
         Self {
             inner: MultiFieldHasherV1::new_with_config_from_ref(params, config),
+            max_elements: None,
         }
     }
 
@@ -207,6 +553,7 @@ impl PallasHasher {
         let params = crate::parameters::pallas::pallas_params_for(variant);
         let mut h = Self {
             inner: MultiFieldHasherV1::new_from_ref(params),
+            max_elements: None,
         };
         h.inner.absorb_domain(domain.as_ref());
         h
@@ -215,28 +562,145 @@ impl PallasHasher {
 
 define_curve_hasher!(
     BN254Hasher,
+    input = BN254Input,
     fq = ark_bn254::Fq,
     fr = ark_bn254::Fr,
     affine = ark_bn254::G1Affine,
-    params = bn254::BN254_PARAMS
+    params = bn254::BN254_PARAMS,
+    repr_len = 32
 );
 
 define_curve_hasher!(
     BLS12_381Hasher,
+    input = BLS12_381Input,
     fq = ark_bls12_381::Fq,
     fr = ark_bls12_381::Fr,
     affine = ark_bls12_381::G1Affine,
-    params = bls12_381::BLS12_381_PARAMS
+    params = bls12_381::BLS12_381_PARAMS,
+    repr_len = 48
 );
 
 define_curve_hasher!(
     BLS12_377Hasher,
+    input = BLS12_377Input,
     fq = ark_bls12_377::Fq,
     fr = ark_bls12_377::Fr,
     affine = ark_bls12_377::G1Affine,
-    params = bls12_377::BLS12_377_PARAMS
+    params = bls12_377::BLS12_377_PARAMS,
+    repr_len = 48
 );
 
+/// Poseidon hasher over BLS12-381's *scalar* field `Fr`, built on
+/// [`crate::parameters::bls12_381::BLS12_381_FR_PARAMS`].
+///
+/// Unlike [`BLS12_381Hasher`] (native over the base field `Fq`, via
+/// [`define_curve_hasher!`]), this one only accepts `Fr` elements directly:
+/// `define_curve_hasher!`'s `FieldInput::CurvePoint` variant needs an
+/// embedded curve whose base field equals the hasher's native field, and
+/// this crate has no such curve over BLS12-381's `Fr` (e.g. no embedded
+/// Jubjub/Bandersnatch), so there's no sensible `affine`/`fr` pair to hand
+/// that macro here.
+#[derive(ZeroizeOnDrop)]
+pub struct BLS12_381FrHasher {
+    #[zeroize(skip)]
+    sponge: crate::ark_poseidon::ArkPoseidonSponge<ark_bls12_381::Fr>,
+    #[zeroize(skip)]
+    element_count: usize,
+}
+
+impl PoseidonHasher<ark_bls12_381::Fr, ark_bls12_381::Fr> for BLS12_381FrHasher {
+    fn new() -> Self {
+        Self {
+            sponge: crate::ark_poseidon::ArkPoseidonSponge::new(&bls12_381::BLS12_381_FR_PARAMS),
+            element_count: 0,
+        }
+    }
+
+    fn new_with_config(_config: PackingConfig) -> Self {
+        // Every input is already a single `Fr` element absorbed directly —
+        // there is no byte-packing buffer for a `PackingConfig` to tune.
+        <Self as PoseidonHasher<_, _>>::new()
+    }
+
+    #[inline]
+    fn update_field_input(&mut self, input: ark_bls12_381::Fr) {
+        self.sponge.absorb(&input);
+        self.element_count += 1;
+    }
+    #[inline]
+    fn digest_result(&mut self) -> ark_bls12_381::Fr {
+        self.sponge.clone().squeeze_native_field_elements(1)[0]
+    }
+    #[inline]
+    fn reset_hasher(&mut self) {
+        *self = <Self as PoseidonHasher<_, _>>::new();
+    }
+    #[inline]
+    fn get_element_count(&self) -> usize {
+        self.element_count
+    }
+    #[inline]
+    fn squeeze_result(&mut self, n: usize) -> Vec<ark_bls12_381::Fr> {
+        self.sponge.squeeze_native_field_elements(n)
+    }
+}
+
+impl Default for BLS12_381FrHasher {
+    fn default() -> Self {
+        <Self as PoseidonHasher<_, _>>::new()
+    }
+}
+
+impl BLS12_381FrHasher {
+    pub fn new() -> Self {
+        <Self as PoseidonHasher<_, _>>::new()
+    }
+}
+
+#[cfg(test)]
+mod bls12_381_fr_hasher_tests {
+    use super::*;
+
+    #[test]
+    fn digest_is_deterministic() {
+        let mut a = BLS12_381FrHasher::new();
+        a.update(ark_bls12_381::Fr::from(1u64));
+        a.update(ark_bls12_381::Fr::from(2u64));
+
+        let mut b = BLS12_381FrHasher::new();
+        b.update(ark_bls12_381::Fr::from(1u64));
+        b.update(ark_bls12_381::Fr::from(2u64));
+
+        assert_eq!(a.digest(), b.digest());
+    }
+
+    #[test]
+    fn different_inputs_diverge() {
+        let mut a = BLS12_381FrHasher::new();
+        a.update(ark_bls12_381::Fr::from(1u64));
+
+        let mut b = BLS12_381FrHasher::new();
+        b.update(ark_bls12_381::Fr::from(2u64));
+
+        assert_ne!(a.digest(), b.digest());
+    }
+
+    #[test]
+    fn reset_clears_element_count_and_matches_a_fresh_hasher() {
+        let mut hasher = BLS12_381FrHasher::new();
+        hasher.update(ark_bls12_381::Fr::from(42u64));
+        assert_eq!(hasher.element_count(), 1);
+
+        hasher.reset();
+        assert_eq!(hasher.element_count(), 0);
+
+        let mut fresh = BLS12_381FrHasher::new();
+        hasher.update(ark_bls12_381::Fr::from(7u64));
+        fresh.update(ark_bls12_381::Fr::from(7u64));
+        assert_eq!(hasher.digest(), fresh.digest());
+    }
+}
+
 // Poseidon2-specific types (explicit algorithm/version in the name)
 pub mod poseidon2 {
     use super::{FieldInput, PoseidonHasher};
@@ -289,6 +753,9 @@ pub mod poseidon2 {
         fn get_element_count(&self) -> usize {
             self.inner.element_count()
         }
+        fn squeeze_result(&mut self, n: usize) -> Vec<ark_pallas::Fq> {
+            self.inner.squeeze(n)
+        }
     }
 
     impl Default for PallasPoseidon2Hasher {
@@ -376,6 +843,50 @@ pub mod poseidon2 {
             let c: ark_pallas::Fq = c.into();
             self.sponge.compress_3(a, b, c)
         }
+
+        /// Compress an arbitrary number of inputs into one field element.
+        /// `inputs.len()` must equal this compressor's rate (3, for the t=4
+        /// parameters this type uses); see [`Self::compress3`] for the
+        /// fixed-arity convenience form.
+        pub fn compress_n(&self, inputs: &[ark_pallas::Fq]) -> ark_pallas::Fq {
+            self.sponge.compress_slice(inputs)
+        }
+    }
+
+    /// Lightweight Poseidon2 (t=3) compression helper for Pallas.
+    ///
+    /// Exposes a simple 2→1 compression using one permutation with the
+    /// capacity lane set to zero. Accepts inputs convertible into Pallas Fq.
+    pub struct PallasPoseidon2CompressT3 {
+        sponge: ArkPoseidon2Sponge<ark_pallas::Fq>,
+    }
+
+    impl PallasPoseidon2CompressT3 {
+        /// Create a new compressor using t=3 Poseidon2 params for Pallas.
+        pub fn new() -> Self {
+            Self {
+                sponge: ArkPoseidon2Sponge::new(&*PALLAS_POSEIDON2_PARAMS),
+            }
+        }
+
+        /// Compress exactly two inputs into one field element.
+        pub fn compress2<A, B>(&self, a: A, b: B) -> ark_pallas::Fq
+        where
+            A: Into<ark_pallas::Fq>,
+            B: Into<ark_pallas::Fq>,
+        {
+            let a: ark_pallas::Fq = a.into();
+            let b: ark_pallas::Fq = b.into();
+            self.sponge.compress_2(a, b)
+        }
+
+        /// Compress an arbitrary number of inputs into one field element.
+        /// `inputs.len()` must equal this compressor's rate (2, for the t=3
+        /// parameters this type uses); see [`Self::compress2`] for the
+        /// fixed-arity convenience form.
+        pub fn compress_n(&self, inputs: &[ark_pallas::Fq]) -> ark_pallas::Fq {
+            self.sponge.compress_slice(inputs)
+        }
     }
 }
 
@@ -383,20 +894,21 @@ pub mod poseidon2 {
 pub mod poseidon2_bn254 {
     use super::{FieldInput, PoseidonHasher};
     use crate::hasher::MultiFieldHasherV2;
-    use crate::parameters::poseidon2_bn254::{
-        BN254_POSEIDON2_PARAMS, BN254_POSEIDON2_PARAMS_T4,
-    };
+    use crate::parameters::poseidon2_bn254::{BN254_POSEIDON2_PARAMS, BN254_POSEIDON2_PARAMS_T4};
     use crate::primitive::PackingConfig;
 
     pub struct BN254Poseidon2Hasher {
         inner: MultiFieldHasherV2<ark_bn254::Fq, ark_bn254::Fr, ark_bn254::G1Affine>,
     }
 
-    impl PoseidonHasher<ark_bn254::Fq, FieldInput<ark_bn254::Fq, ark_bn254::Fr, ark_bn254::G1Affine>>
+    impl
+        PoseidonHasher<ark_bn254::Fq, FieldInput<ark_bn254::Fq, ark_bn254::Fr, ark_bn254::G1Affine>>
         for BN254Poseidon2Hasher
     {
         fn new() -> Self {
-            Self { inner: MultiFieldHasherV2::new_from_ref(&*BN254_POSEIDON2_PARAMS) }
+            Self {
+                inner: MultiFieldHasherV2::new_from_ref(&*BN254_POSEIDON2_PARAMS),
+            }
         }
 
         fn new_with_config(config: PackingConfig) -> Self {
@@ -414,17 +926,30 @@ pub mod poseidon2_bn254 {
         ) {
             self.inner.update(input)
         }
-        fn digest_result(&mut self) -> ark_bn254::Fq { self.inner.digest() }
-        fn reset_hasher(&mut self) { self.inner.reset() }
-        fn get_element_count(&self) -> usize { self.inner.element_count() }
+        fn digest_result(&mut self) -> ark_bn254::Fq {
+            self.inner.digest()
+        }
+        fn reset_hasher(&mut self) {
+            self.inner.reset()
+        }
+        fn get_element_count(&self) -> usize {
+            self.inner.element_count()
+        }
+        fn squeeze_result(&mut self, n: usize) -> Vec<ark_bn254::Fq> {
+            self.inner.squeeze(n)
+        }
     }
 
     impl Default for BN254Poseidon2Hasher {
-        fn default() -> Self { <Self as super::PoseidonHasher<_, _>>::new() }
+        fn default() -> Self {
+            <Self as super::PoseidonHasher<_, _>>::new()
+        }
     }
 
     impl BN254Poseidon2Hasher {
-        pub fn new() -> Self { <Self as super::PoseidonHasher<_, _>>::new() }
+        pub fn new() -> Self {
+            <Self as super::PoseidonHasher<_, _>>::new()
+        }
         pub fn new_with_config(config: PackingConfig) -> Self {
             <Self as super::PoseidonHasher<_, _>>::new_with_config(config)
         }
@@ -440,7 +965,9 @@ pub mod poseidon2_bn254 {
         }
 
         pub fn new_variant_t4() -> Self {
-            Self { inner: MultiFieldHasherV2::new_from_ref(&*BN254_POSEIDON2_PARAMS_T4) }
+            Self {
+                inner: MultiFieldHasherV2::new_from_ref(&*BN254_POSEIDON2_PARAMS_T4),
+            }
         }
         pub fn new_with_config_variant_t4(config: PackingConfig) -> Self {
             Self {
@@ -451,4 +978,474 @@ pub mod poseidon2_bn254 {
             }
         }
     }
+
+    /// Lightweight Poseidon2 (t=4) compression helper for BN254.
+    ///
+    /// Exposes a simple 3-to-1 compression using one permutation with the
+    /// capacity lane set to zero. Accepts inputs convertible into BN254 Fq.
+    pub struct BN254Poseidon2Compress {
+        sponge: crate::ark_poseidon::ArkPoseidon2Sponge<ark_bn254::Fq>,
+    }
+
+    impl BN254Poseidon2Compress {
+        /// Create a new compressor using t=4 Poseidon2 params for BN254.
+        pub fn new() -> Self {
+            Self {
+                sponge: crate::ark_poseidon::ArkPoseidon2Sponge::new(&*BN254_POSEIDON2_PARAMS_T4),
+            }
+        }
+
+        /// Compress exactly three inputs into one field element.
+        pub fn compress3<A, B, C>(&self, a: A, b: B, c: C) -> ark_bn254::Fq
+        where
+            A: Into<ark_bn254::Fq>,
+            B: Into<ark_bn254::Fq>,
+            C: Into<ark_bn254::Fq>,
+        {
+            self.sponge.compress_3(a.into(), b.into(), c.into())
+        }
+
+        /// Compress an arbitrary number of inputs into one field element.
+        /// `inputs.len()` must equal this compressor's rate (3, for the t=4
+        /// parameters this type uses); see [`Self::compress3`] for the
+        /// fixed-arity convenience form.
+        pub fn compress_n(&self, inputs: &[ark_bn254::Fq]) -> ark_bn254::Fq {
+            self.sponge.compress_slice(inputs)
+        }
+    }
+
+    /// Lightweight Poseidon2 (t=3) compression helper for BN254.
+    ///
+    /// Exposes a simple 2-to-1 compression using one permutation with the
+    /// capacity lane set to zero. Accepts inputs convertible into BN254 Fq.
+    pub struct BN254Poseidon2CompressT3 {
+        sponge: crate::ark_poseidon::ArkPoseidon2Sponge<ark_bn254::Fq>,
+    }
+
+    impl BN254Poseidon2CompressT3 {
+        /// Create a new compressor using t=3 Poseidon2 params for BN254.
+        pub fn new() -> Self {
+            Self {
+                sponge: crate::ark_poseidon::ArkPoseidon2Sponge::new(&*BN254_POSEIDON2_PARAMS),
+            }
+        }
+
+        /// Compress exactly two inputs into one field element.
+        pub fn compress2<A, B>(&self, a: A, b: B) -> ark_bn254::Fq
+        where
+            A: Into<ark_bn254::Fq>,
+            B: Into<ark_bn254::Fq>,
+        {
+            self.sponge.compress_2(a.into(), b.into())
+        }
+
+        /// Compress an arbitrary number of inputs into one field element.
+        /// `inputs.len()` must equal this compressor's rate (2, for the t=3
+        /// parameters this type uses); see [`Self::compress2`] for the
+        /// fixed-arity convenience form.
+        pub fn compress_n(&self, inputs: &[ark_bn254::Fq]) -> ark_bn254::Fq {
+            self.sponge.compress_slice(inputs)
+        }
+    }
+}
+
+/// Poseidon2 compression helpers for Vesta (explicit algorithm/version).
+pub mod poseidon2_vesta {
+    use crate::ark_poseidon::ArkPoseidon2Sponge;
+    use crate::parameters::poseidon2_vesta::{VESTA_POSEIDON2_PARAMS, VESTA_POSEIDON2_PARAMS_T4};
+
+    /// Lightweight Poseidon2 (t=4) compression helper for Vesta.
+    ///
+    /// Exposes a simple 3-to-1 compression using one permutation with the
+    /// capacity lane set to zero. Accepts inputs convertible into Vesta Fq.
+    pub struct VestaPoseidon2Compress {
+        sponge: ArkPoseidon2Sponge<ark_vesta::Fq>,
+    }
+
+    impl VestaPoseidon2Compress {
+        /// Create a new compressor using t=4 Poseidon2 params for Vesta.
+        pub fn new() -> Self {
+            Self {
+                sponge: ArkPoseidon2Sponge::new(&*VESTA_POSEIDON2_PARAMS_T4),
+            }
+        }
+
+        /// Compress exactly three inputs into one field element.
+        pub fn compress3<A, B, C>(&self, a: A, b: B, c: C) -> ark_vesta::Fq
+        where
+            A: Into<ark_vesta::Fq>,
+            B: Into<ark_vesta::Fq>,
+            C: Into<ark_vesta::Fq>,
+        {
+            self.sponge.compress_3(a.into(), b.into(), c.into())
+        }
+
+        /// Compress an arbitrary number of inputs into one field element.
+        /// `inputs.len()` must equal this compressor's rate (3, for the t=4
+        /// parameters this type uses); see [`Self::compress3`] for the
+        /// fixed-arity convenience form.
+        pub fn compress_n(&self, inputs: &[ark_vesta::Fq]) -> ark_vesta::Fq {
+            self.sponge.compress_slice(inputs)
+        }
+    }
+
+    /// Lightweight Poseidon2 (t=3) compression helper for Vesta.
+    ///
+    /// Exposes a simple 2-to-1 compression using one permutation with the
+    /// capacity lane set to zero. Accepts inputs convertible into Vesta Fq.
+    pub struct VestaPoseidon2CompressT3 {
+        sponge: ArkPoseidon2Sponge<ark_vesta::Fq>,
+    }
+
+    impl VestaPoseidon2CompressT3 {
+        /// Create a new compressor using t=3 Poseidon2 params for Vesta.
+        pub fn new() -> Self {
+            Self {
+                sponge: ArkPoseidon2Sponge::new(&*VESTA_POSEIDON2_PARAMS),
+            }
+        }
+
+        /// Compress exactly two inputs into one field element.
+        pub fn compress2<A, B>(&self, a: A, b: B) -> ark_vesta::Fq
+        where
+            A: Into<ark_vesta::Fq>,
+            B: Into<ark_vesta::Fq>,
+        {
+            self.sponge.compress_2(a.into(), b.into())
+        }
+
+        /// Compress an arbitrary number of inputs into one field element.
+        /// `inputs.len()` must equal this compressor's rate (2, for the t=3
+        /// parameters this type uses); see [`Self::compress2`] for the
+        /// fixed-arity convenience form.
+        pub fn compress_n(&self, inputs: &[ark_vesta::Fq]) -> ark_vesta::Fq {
+            self.sponge.compress_slice(inputs)
+        }
+    }
+}
+
+/// Poseidon2 compression helpers for BLS12-381 (explicit algorithm/version).
+pub mod poseidon2_bls12_381 {
+    use crate::ark_poseidon::ArkPoseidon2Sponge;
+    use crate::parameters::poseidon2_bls12_381::{
+        BLS12_381_POSEIDON2_PARAMS, BLS12_381_POSEIDON2_PARAMS_T4,
+    };
+
+    /// Lightweight Poseidon2 (t=4) compression helper for BLS12-381.
+    ///
+    /// Exposes a simple 3-to-1 compression using one permutation with the
+    /// capacity lane set to zero. Accepts inputs convertible into BLS12-381 Fq.
+    pub struct BLS12_381Poseidon2Compress {
+        sponge: ArkPoseidon2Sponge<ark_bls12_381::Fq>,
+    }
+
+    impl BLS12_381Poseidon2Compress {
+        /// Create a new compressor using t=4 Poseidon2 params for BLS12-381.
+        pub fn new() -> Self {
+            Self {
+                sponge: ArkPoseidon2Sponge::new(&*BLS12_381_POSEIDON2_PARAMS_T4),
+            }
+        }
+
+        /// Compress exactly three inputs into one field element.
+        pub fn compress3<A, B, C>(&self, a: A, b: B, c: C) -> ark_bls12_381::Fq
+        where
+            A: Into<ark_bls12_381::Fq>,
+            B: Into<ark_bls12_381::Fq>,
+            C: Into<ark_bls12_381::Fq>,
+        {
+            self.sponge.compress_3(a.into(), b.into(), c.into())
+        }
+
+        /// Compress an arbitrary number of inputs into one field element.
+        /// `inputs.len()` must equal this compressor's rate (3, for the t=4
+        /// parameters this type uses); see [`Self::compress3`] for the
+        /// fixed-arity convenience form.
+        pub fn compress_n(&self, inputs: &[ark_bls12_381::Fq]) -> ark_bls12_381::Fq {
+            self.sponge.compress_slice(inputs)
+        }
+    }
+
+    /// Lightweight Poseidon2 (t=3) compression helper for BLS12-381.
+    ///
+    /// Exposes a simple 2-to-1 compression using one permutation with the
+    /// capacity lane set to zero. Accepts inputs convertible into BLS12-381 Fq.
+    pub struct BLS12_381Poseidon2CompressT3 {
+        sponge: ArkPoseidon2Sponge<ark_bls12_381::Fq>,
+    }
+
+    impl BLS12_381Poseidon2CompressT3 {
+        /// Create a new compressor using t=3 Poseidon2 params for BLS12-381.
+        pub fn new() -> Self {
+            Self {
+                sponge: ArkPoseidon2Sponge::new(&*BLS12_381_POSEIDON2_PARAMS),
+            }
+        }
+
+        /// Compress exactly two inputs into one field element.
+        pub fn compress2<A, B>(&self, a: A, b: B) -> ark_bls12_381::Fq
+        where
+            A: Into<ark_bls12_381::Fq>,
+            B: Into<ark_bls12_381::Fq>,
+        {
+            self.sponge.compress_2(a.into(), b.into())
+        }
+
+        /// Compress an arbitrary number of inputs into one field element.
+        /// `inputs.len()` must equal this compressor's rate (2, for the t=3
+        /// parameters this type uses); see [`Self::compress2`] for the
+        /// fixed-arity convenience form.
+        pub fn compress_n(&self, inputs: &[ark_bls12_381::Fq]) -> ark_bls12_381::Fq {
+            self.sponge.compress_slice(inputs)
+        }
+    }
+}
+
+/// Poseidon2 compression helpers for BLS12-377 (explicit algorithm/version).
+pub mod poseidon2_bls12_377 {
+    use crate::ark_poseidon::ArkPoseidon2Sponge;
+    use crate::parameters::poseidon2_bls12_377::{
+        BLS12_377_POSEIDON2_PARAMS, BLS12_377_POSEIDON2_PARAMS_T4,
+    };
+
+    /// Lightweight Poseidon2 (t=4) compression helper for BLS12-377.
+    ///
+    /// Exposes a simple 3-to-1 compression using one permutation with the
+    /// capacity lane set to zero. Accepts inputs convertible into BLS12-377 Fq.
+    pub struct BLS12_377Poseidon2Compress {
+        sponge: ArkPoseidon2Sponge<ark_bls12_377::Fq>,
+    }
+
+    impl BLS12_377Poseidon2Compress {
+        /// Create a new compressor using t=4 Poseidon2 params for BLS12-377.
+        pub fn new() -> Self {
+            Self {
+                sponge: ArkPoseidon2Sponge::new(&*BLS12_377_POSEIDON2_PARAMS_T4),
+            }
+        }
+
+        /// Compress exactly three inputs into one field element.
+        pub fn compress3<A, B, C>(&self, a: A, b: B, c: C) -> ark_bls12_377::Fq
+        where
+            A: Into<ark_bls12_377::Fq>,
+            B: Into<ark_bls12_377::Fq>,
+            C: Into<ark_bls12_377::Fq>,
+        {
+            self.sponge.compress_3(a.into(), b.into(), c.into())
+        }
+
+        /// Compress an arbitrary number of inputs into one field element.
+        /// `inputs.len()` must equal this compressor's rate (3, for the t=4
+        /// parameters this type uses); see [`Self::compress3`] for the
+        /// fixed-arity convenience form.
+        pub fn compress_n(&self, inputs: &[ark_bls12_377::Fq]) -> ark_bls12_377::Fq {
+            self.sponge.compress_slice(inputs)
+        }
+    }
+
+    /// Lightweight Poseidon2 (t=3) compression helper for BLS12-377.
+    ///
+    /// Exposes a simple 2-to-1 compression using one permutation with the
+    /// capacity lane set to zero. Accepts inputs convertible into BLS12-377 Fq.
+    pub struct BLS12_377Poseidon2CompressT3 {
+        sponge: ArkPoseidon2Sponge<ark_bls12_377::Fq>,
+    }
+
+    impl BLS12_377Poseidon2CompressT3 {
+        /// Create a new compressor using t=3 Poseidon2 params for BLS12-377.
+        pub fn new() -> Self {
+            Self {
+                sponge: ArkPoseidon2Sponge::new(&*BLS12_377_POSEIDON2_PARAMS),
+            }
+        }
+
+        /// Compress exactly two inputs into one field element.
+        pub fn compress2<A, B>(&self, a: A, b: B) -> ark_bls12_377::Fq
+        where
+            A: Into<ark_bls12_377::Fq>,
+            B: Into<ark_bls12_377::Fq>,
+        {
+            self.sponge.compress_2(a.into(), b.into())
+        }
+
+        /// Compress an arbitrary number of inputs into one field element.
+        /// `inputs.len()` must equal this compressor's rate (2, for the t=3
+        /// parameters this type uses); see [`Self::compress2`] for the
+        /// fixed-arity convenience form.
+        pub fn compress_n(&self, inputs: &[ark_bls12_377::Fq]) -> ark_bls12_377::Fq {
+            self.sponge.compress_slice(inputs)
+        }
+    }
+}
+
+/// Selects which Poseidon permutation a `*AnyHasher` (e.g. [`PallasAnyHasher`])
+/// uses at construction time.
+///
+/// The classic and Poseidon2 hashers are distinct concrete types — they're
+/// generic over different `Sp` sponge backends in
+/// [`crate::hasher::MultiFieldHasher`] — so picking between them from a
+/// runtime config (rather than naming a concrete type at the call site)
+/// needs an enum-dispatch sum type, not just another [`PoseidonHasher`]
+/// method.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PoseidonPermutation {
+    /// The classic Poseidon permutation (arbitrary width, external MDS).
+    Classic,
+    /// The Poseidon2 permutation (fixed t=4 or t=3, internal + external rounds).
+    Poseidon2,
+}
+
+// Generates a `$AnyHasher` enum wrapping a curve's classic hasher and its
+// Poseidon2 counterpart, implementing `PoseidonHasher` by dispatching every
+// hidden hook to whichever variant is active. Mirrors the wrapper-over-
+// existing-primitive pattern used by `PoseidonTranscript` and the
+// `*Poseidon2Compress*` types elsewhere in this module.
+macro_rules! define_any_hasher {
+    (
+        $AnyHasher:ident,
+        classic = $Classic:ident,
+        poseidon2 = $Poseidon2:path,
+        fq = $fq:path,
+        input = $Input:ident
+    ) => {
+        /// Streaming hasher whose permutation backend (classic Poseidon vs
+        /// Poseidon2) is selected at construction via
+        /// [`PoseidonPermutation`], rather than fixed by the type.
+        pub enum $AnyHasher {
+            /// Backed by the classic Poseidon permutation.
+            Classic($Classic),
+            /// Backed by the Poseidon2 permutation.
+            Poseidon2($Poseidon2),
+        }
+
+        impl $AnyHasher {
+            /// Create a new hasher backed by `permutation`, with default packing.
+            pub fn new_with_permutation(permutation: PoseidonPermutation) -> Self {
+                match permutation {
+                    PoseidonPermutation::Classic => Self::Classic($Classic::new()),
+                    PoseidonPermutation::Poseidon2 => Self::Poseidon2(<$Poseidon2>::new()),
+                }
+            }
+
+            /// Create a new hasher backed by `permutation`, pre-seeded with `domain`.
+            pub fn new_with_domain_and_permutation(
+                domain: impl AsRef<[u8]>,
+                permutation: PoseidonPermutation,
+            ) -> Self {
+                match permutation {
+                    PoseidonPermutation::Classic => {
+                        Self::Classic($Classic::new_with_domain(domain))
+                    }
+                    PoseidonPermutation::Poseidon2 => {
+                        Self::Poseidon2(<$Poseidon2>::new_with_domain(domain))
+                    }
+                }
+            }
+        }
+
+        impl PoseidonHasher<$fq, $Input> for $AnyHasher {
+            fn new() -> Self {
+                Self::new_with_permutation(PoseidonPermutation::Classic)
+            }
+            fn new_with_config(config: PackingConfig) -> Self {
+                Self::Classic($Classic::new_with_config(config))
+            }
+            #[inline]
+            fn update_field_input(&mut self, input: $Input) {
+                match self {
+                    Self::Classic(h) => h.update_field_input(input),
+                    Self::Poseidon2(h) => h.update_field_input(input),
+                }
+            }
+            #[inline]
+            fn digest_result(&mut self) -> $fq {
+                match self {
+                    Self::Classic(h) => h.digest_result(),
+                    Self::Poseidon2(h) => h.digest_result(),
+                }
+            }
+            #[inline]
+            fn reset_hasher(&mut self) {
+                match self {
+                    Self::Classic(h) => h.reset_hasher(),
+                    Self::Poseidon2(h) => h.reset_hasher(),
+                }
+            }
+            #[inline]
+            fn get_element_count(&self) -> usize {
+                match self {
+                    Self::Classic(h) => h.get_element_count(),
+                    Self::Poseidon2(h) => h.get_element_count(),
+                }
+            }
+            #[inline]
+            fn squeeze_result(&mut self, n: usize) -> Vec<$fq> {
+                match self {
+                    Self::Classic(h) => h.squeeze_result(n),
+                    Self::Poseidon2(h) => h.squeeze_result(n),
+                }
+            }
+        }
+    };
+}
+
+define_any_hasher!(
+    PallasAnyHasher,
+    classic = PallasHasher,
+    poseidon2 = poseidon2::PallasPoseidon2Hasher,
+    fq = ark_pallas::Fq,
+    input = PallasInput
+);
+
+define_any_hasher!(
+    BN254AnyHasher,
+    classic = BN254Hasher,
+    poseidon2 = poseidon2_bn254::BN254Poseidon2Hasher,
+    fq = ark_bn254::Fq,
+    input = BN254Input
+);
+
+#[cfg(test)]
+mod any_hasher_tests {
+    use super::*;
+
+    #[test]
+    fn test_poseidon2_digest_differs_from_classic_for_same_input() {
+        let mut classic = PallasAnyHasher::new_with_permutation(PoseidonPermutation::Classic);
+        let mut poseidon2 = PallasAnyHasher::new_with_permutation(PoseidonPermutation::Poseidon2);
+        classic.update(ark_pallas::Fq::from(42u64));
+        poseidon2.update(ark_pallas::Fq::from(42u64));
+        assert_ne!(classic.digest(), poseidon2.digest());
+    }
+
+    #[test]
+    fn test_same_permutation_is_deterministic_across_instances() {
+        let mut a = PallasAnyHasher::new_with_domain_and_permutation(
+            "ANY_HASHER",
+            PoseidonPermutation::Poseidon2,
+        );
+        let mut b = PallasAnyHasher::new_with_domain_and_permutation(
+            "ANY_HASHER",
+            PoseidonPermutation::Poseidon2,
+        );
+        a.update(ark_pallas::Fq::from(7u64));
+        b.update(ark_pallas::Fq::from(7u64));
+        assert_eq!(a.digest(), b.digest());
+    }
+
+    #[test]
+    fn test_bn254_any_hasher_matches_its_concrete_backends() {
+        let mut any_classic = BN254AnyHasher::new_with_permutation(PoseidonPermutation::Classic);
+        let mut classic = BN254Hasher::new();
+        any_classic.update(ark_bn254::Fq::from(9u64));
+        classic.update(ark_bn254::Fq::from(9u64));
+        assert_eq!(any_classic.digest(), classic.digest());
+
+        let mut any_poseidon2 =
+            BN254AnyHasher::new_with_permutation(PoseidonPermutation::Poseidon2);
+        let mut poseidon2 = poseidon2_bn254::BN254Poseidon2Hasher::new();
+        any_poseidon2.update(ark_bn254::Fq::from(9u64));
+        poseidon2.update(ark_bn254::Fq::from(9u64));
+        assert_eq!(any_poseidon2.digest(), poseidon2.digest());
+    }
 }
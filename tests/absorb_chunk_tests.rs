@@ -0,0 +1,83 @@
+//! Tests for the curve-hasher `absorb_chunk` streaming, rate-block-aligned
+//! absorption API.
+
+use poseidon_hash::{PallasHasher, PoseidonHasher};
+
+#[test]
+fn test_absorb_chunk_rejects_undersized_non_final_block() {
+    let mut hasher = PallasHasher::new();
+    let rate = hasher.rate();
+    let short_block = vec![ark_pallas::Fq::from(1u64); rate - 1];
+
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        hasher.absorb_chunk(&short_block, false);
+    }));
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_absorb_chunk_rejects_oversized_block() {
+    let mut hasher = PallasHasher::new();
+    let rate = hasher.rate();
+    let long_block = vec![ark_pallas::Fq::from(1u64); rate + 1];
+
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        hasher.absorb_chunk(&long_block, true);
+    }));
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_absorb_chunk_returns_digest_only_on_final_block() {
+    let mut hasher = PallasHasher::new();
+    let rate = hasher.rate();
+    let block = vec![ark_pallas::Fq::from(42u64); rate];
+
+    assert!(hasher.absorb_chunk(&block, false).is_none());
+    let digest = hasher.absorb_chunk(&[], true);
+    assert!(digest.is_some());
+}
+
+#[test]
+fn test_absorb_chunk_permits_short_final_block() {
+    let mut hasher = PallasHasher::new();
+    let rate = hasher.rate();
+    let full_block = vec![ark_pallas::Fq::from(7u64); rate];
+    let short_final = vec![ark_pallas::Fq::from(9u64); rate - 1];
+
+    hasher.absorb_chunk(&full_block, false);
+    let digest = hasher.absorb_chunk(&short_final, true);
+    assert!(digest.is_some());
+}
+
+#[test]
+fn test_absorb_chunk_is_deterministic() {
+    let rate = PallasHasher::new().rate();
+    let block = vec![ark_pallas::Fq::from(5u64); rate];
+
+    let mut a = PallasHasher::new();
+    let mut b = PallasHasher::new();
+    a.absorb_chunk(&block, false);
+    b.absorb_chunk(&block, false);
+
+    let digest_a = a.absorb_chunk(&[ark_pallas::Fq::from(1u64)], true);
+    let digest_b = b.absorb_chunk(&[ark_pallas::Fq::from(1u64)], true);
+    assert_eq!(digest_a, digest_b);
+}
+
+#[test]
+fn test_absorb_chunk_sequence_differs_from_single_combined_chunk() {
+    let rate = PallasHasher::new().rate();
+
+    let mut streamed = PallasHasher::new();
+    let block = vec![ark_pallas::Fq::from(3u64); rate];
+    streamed.absorb_chunk(&block, false);
+    let streamed_digest = streamed.absorb_chunk(&[ark_pallas::Fq::from(11u64)], true);
+
+    // A differently-shaped absorption schedule for related data should not
+    // collide with the two-block stream above.
+    let mut single = PallasHasher::new();
+    let single_digest = single.absorb_chunk(&[ark_pallas::Fq::from(11u64)], true);
+
+    assert_ne!(streamed_digest, single_digest);
+}
@@ -0,0 +1,124 @@
+//! Differential tests checking that the streaming `update`/`squeeze` path and
+//! the rate-aligned `absorb_chunk` path are two equivalent views onto the
+//! same underlying absorption loop (see `MultiFieldHasher::absorb_chunk`'s
+//! doc comment), across interleaved absorb/squeeze schedules and at the
+//! block-alignment boundary lengths (0, 1, rate-1, rate, rate+1, 2*rate)
+//! where a state-management regression is most likely to surface.
+
+use poseidon_hash::{PallasHasher, PoseidonHasher};
+
+fn elements(n: usize, offset: u64) -> Vec<ark_pallas::Fq> {
+    (0..n)
+        .map(|i| ark_pallas::Fq::from(i as u64 + offset))
+        .collect()
+}
+
+/// Absorb one element at a time, the same per-element loop `absorb_chunk`
+/// runs internally on a whole block at once.
+fn absorb_streamed(hasher: &mut PallasHasher, input: &[ark_pallas::Fq]) {
+    for &x in input {
+        hasher.update(x);
+    }
+}
+
+/// Absorb `input` via `absorb_chunk`, splitting it into `rate`-sized blocks
+/// with a (possibly empty) final short block, returning the digest produced
+/// by the final call.
+fn absorb_chunked(hasher: &mut PallasHasher, input: &[ark_pallas::Fq]) -> ark_pallas::Fq {
+    let rate = hasher.rate();
+    let mut offset = 0;
+    while input.len() - offset >= rate {
+        hasher.absorb_chunk(&input[offset..offset + rate], false);
+        offset += rate;
+    }
+    hasher
+        .absorb_chunk(&input[offset..], true)
+        .expect("final absorb_chunk call always returns a digest")
+}
+
+/// Absorb `input` (a multiple of `rate` long) via `absorb_chunk`, without
+/// finalizing, so a caller can keep streaming or squeeze mid-stream.
+fn absorb_full_blocks(hasher: &mut PallasHasher, input: &[ark_pallas::Fq]) {
+    let rate = hasher.rate();
+    assert_eq!(input.len() % rate, 0, "test input must be block-aligned");
+    for block in input.chunks(rate) {
+        hasher.absorb_chunk(block, false);
+    }
+}
+
+#[test]
+fn test_streamed_and_chunked_absorption_agree_at_boundary_lengths() {
+    let rate = PallasHasher::new().rate();
+
+    for &len in &[0, 1, rate - 1, rate, rate + 1, 2 * rate] {
+        let input = elements(len, 1);
+
+        let mut streamed = PallasHasher::new();
+        absorb_streamed(&mut streamed, &input);
+        let streamed_digest = streamed.squeeze(1)[0];
+
+        let mut chunked = PallasHasher::new();
+        let chunked_digest = absorb_chunked(&mut chunked, &input);
+
+        assert_eq!(
+            streamed_digest, chunked_digest,
+            "streamed vs. chunked absorption diverged at length {len}"
+        );
+    }
+}
+
+#[test]
+fn test_interleaved_intermediate_squeezes_agree_across_both_paths() {
+    let rate = PallasHasher::new().rate();
+
+    for &len in &[0, rate, 2 * rate] {
+        let first_half = elements(len, 1);
+        let second_half = elements(len, 1_000);
+
+        let mut streamed = PallasHasher::new();
+        absorb_streamed(&mut streamed, &first_half);
+        let streamed_mid = streamed.squeeze(2);
+        absorb_streamed(&mut streamed, &second_half);
+        let streamed_final = streamed.squeeze(1)[0];
+
+        let mut chunked = PallasHasher::new();
+        absorb_full_blocks(&mut chunked, &first_half);
+        let chunked_mid = chunked.squeeze(2);
+        absorb_full_blocks(&mut chunked, &second_half);
+        let chunked_final = chunked.squeeze(1)[0];
+
+        assert_eq!(
+            streamed_mid, chunked_mid,
+            "mid-stream squeeze diverged at length {len}"
+        );
+        assert_eq!(
+            streamed_final, chunked_final,
+            "final squeeze diverged at length {len}"
+        );
+    }
+}
+
+#[test]
+fn test_chunked_absorption_is_sensitive_to_every_boundary_length() {
+    let rate = PallasHasher::new().rate();
+    let lengths = [0, 1, rate - 1, rate, rate + 1, 2 * rate];
+
+    let digests: Vec<ark_pallas::Fq> = lengths
+        .iter()
+        .map(|&len| {
+            let input = elements(len, 1);
+            let mut hasher = PallasHasher::new();
+            absorb_chunked(&mut hasher, &input)
+        })
+        .collect();
+
+    for i in 0..digests.len() {
+        for j in (i + 1)..digests.len() {
+            assert_ne!(
+                digests[i], digests[j],
+                "lengths {} and {} collided",
+                lengths[i], lengths[j]
+            );
+        }
+    }
+}
@@ -0,0 +1,62 @@
+//! Tests for the fixed-element-count `VarLenBytes`/`FixLenBytes` encodings
+//! used to match a ZK circuit that can only absorb statically-sized arrays.
+
+use poseidon_hash::{FixLenBytes, PallasHasher, PoseidonHasher, VarLenBytes};
+
+#[test]
+fn test_var_len_element_count_is_independent_of_actual_length() {
+    let encoder = VarLenBytes::new(64);
+    let short = encoder.encode::<ark_pallas::Fq>(b"hi");
+    let long = encoder.encode::<ark_pallas::Fq>(&[7u8; 64]);
+
+    assert_eq!(short.len(), long.len());
+    assert_eq!(short.len(), encoder.element_count::<ark_pallas::Fq>());
+}
+
+#[test]
+fn test_var_len_encoding_disambiguates_shared_prefix_by_length() {
+    let encoder = VarLenBytes::new(8);
+    let a = encoder.encode::<ark_pallas::Fq>(b"ab");
+    let b = encoder.encode::<ark_pallas::Fq>(b"abc");
+
+    assert_ne!(a, b, "same prefix but different declared length must differ");
+}
+
+#[test]
+#[should_panic(expected = "exceeds max_len")]
+fn test_var_len_encoding_rejects_oversized_input() {
+    let encoder = VarLenBytes::new(4);
+    encoder.encode::<ark_pallas::Fq>(b"toolong!");
+}
+
+#[test]
+fn test_fix_len_encoding_is_deterministic() {
+    let data = [1u8, 2, 3, 4];
+    let a = FixLenBytes::<4>::encode::<ark_pallas::Fq>(&data);
+    let b = FixLenBytes::<4>::encode::<ark_pallas::Fq>(&data);
+
+    assert_eq!(a, b);
+    assert_eq!(a.len(), FixLenBytes::<4>::element_count::<ark_pallas::Fq>());
+}
+
+#[test]
+fn test_hasher_var_len_bytes_is_deterministic_and_length_sensitive() {
+    let mut a = PallasHasher::new();
+    a.update_var_len_bytes(b"ab", 8);
+    let mut b = PallasHasher::new();
+    b.update_var_len_bytes(b"ab", 8);
+    assert_eq!(a.digest(), b.digest());
+
+    let mut c = PallasHasher::new();
+    c.update_var_len_bytes(b"abc", 8);
+    assert_ne!(a.digest(), c.digest());
+}
+
+#[test]
+fn test_hasher_fix_len_bytes_is_deterministic() {
+    let mut a = PallasHasher::new();
+    a.update_fix_len_bytes(&[9u8, 9, 9, 9]);
+    let mut b = PallasHasher::new();
+    b.update_fix_len_bytes(&[9u8, 9, 9, 9]);
+    assert_eq!(a.digest(), b.digest());
+}
@@ -1,5 +1,5 @@
 use poseidon_hash::PoseidonHasher;
-use poseidon_hash::types::PallasHasher;
+use poseidon_hash::types::{Domain, PallasHasher};
 
 #[test]
 fn test_different_domains_produce_different_hashes() {
@@ -25,3 +25,101 @@ fn test_same_domain_same_inputs_equal() {
 
     assert_eq!(h1.digest(), h2.digest());
 }
+
+#[test]
+fn test_constant_length_differs_from_variable_length() {
+    let mut fixed = PallasHasher::with_domain(Domain::ConstantLength(2));
+    let mut variable = PallasHasher::with_domain(Domain::VariableLength);
+
+    fixed.update(1u64);
+    fixed.update(2u64);
+    variable.update(1u64);
+    variable.update(2u64);
+
+    assert_ne!(fixed.digest(), variable.digest());
+}
+
+#[test]
+fn test_constant_length_differs_by_declared_length() {
+    let mut declared_two = PallasHasher::with_domain(Domain::ConstantLength(2));
+    let mut declared_three = PallasHasher::with_domain(Domain::ConstantLength(3));
+
+    declared_two.update(1u64);
+    declared_two.update(2u64);
+    declared_three.update(1u64);
+    declared_three.update(2u64);
+
+    assert_ne!(
+        declared_two.digest(),
+        declared_three.digest(),
+        "a shared prefix under different declared lengths must not collide"
+    );
+}
+
+#[test]
+fn test_constant_length_same_inputs_equal() {
+    let mut h1 = PallasHasher::with_domain(Domain::ConstantLength(2));
+    let mut h2 = PallasHasher::with_domain(Domain::ConstantLength(2));
+
+    h1.update(7u64);
+    h1.update(8u64);
+    h2.update(7u64);
+    h2.update(8u64);
+
+    assert_eq!(h1.digest(), h2.digest());
+}
+
+#[test]
+#[should_panic(expected = "exceeded declared ConstantLength")]
+fn test_constant_length_panics_past_declared_cap() {
+    let mut h = PallasHasher::with_domain(Domain::ConstantLength(1));
+    h.update(1u64);
+    h.update(2u64);
+}
+
+#[test]
+fn test_digest_checked_errors_on_underfill() {
+    let mut h = PallasHasher::with_domain(Domain::ConstantLength(2));
+    h.update(1u64);
+
+    assert!(h.digest_checked().is_err());
+}
+
+#[test]
+fn test_digest_checked_succeeds_once_fully_filled() {
+    let mut h = PallasHasher::with_domain(Domain::ConstantLength(2));
+    h.update(1u64);
+    h.update(2u64);
+
+    assert!(h.digest_checked().is_ok());
+}
+
+#[test]
+fn test_digest_checked_always_succeeds_under_variable_length() {
+    let mut h = PallasHasher::with_domain(Domain::VariableLength);
+    h.update(1u64);
+
+    assert!(h.digest_checked().is_ok());
+}
+
+#[test]
+fn test_capacity_tag_diverges_from_untagged_hasher() {
+    let mut tagged = PallasHasher::new_with_capacity_tag(ark_pallas::Fq::from(7u64));
+    let mut plain = PallasHasher::new();
+
+    tagged.update(1u64);
+    plain.update(1u64);
+
+    assert_ne!(tagged.digest(), plain.digest());
+}
+
+#[test]
+fn test_capacity_tag_same_tag_same_inputs_equal() {
+    let mut h1 = PallasHasher::new_with_capacity_tag(ark_pallas::Fq::from(7u64));
+    let mut h2 = PallasHasher::new_with_capacity_tag(ark_pallas::Fq::from(7u64));
+
+    h1.update(1u64);
+    h2.update(1u64);
+
+    assert_eq!(h1.digest(), h2.digest());
+}
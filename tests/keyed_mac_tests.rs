@@ -0,0 +1,46 @@
+//! Tests for the keyed Poseidon PRF/MAC construction (`with_key`/`mac`/`verify_mac`).
+
+use poseidon_hash::PallasHasher;
+
+#[test]
+fn test_different_keys_produce_different_macs() {
+    let mut a = PallasHasher::with_key(&[ark_pallas::Fq::from(1u64)]);
+    let mut b = PallasHasher::with_key(&[ark_pallas::Fq::from(2u64)]);
+
+    a.update(42u64);
+    b.update(42u64);
+
+    assert_ne!(a.mac(), b.mac());
+}
+
+#[test]
+fn test_same_key_same_message_equal_mac() {
+    let mut a = PallasHasher::with_key(&[ark_pallas::Fq::from(7u64)]);
+    let mut b = PallasHasher::with_key(&[ark_pallas::Fq::from(7u64)]);
+
+    a.update(99u64);
+    b.update(99u64);
+
+    assert_eq!(a.mac(), b.mac());
+}
+
+#[test]
+fn test_keyed_mac_differs_from_unkeyed_digest() {
+    let mut keyed = PallasHasher::with_key(&[ark_pallas::Fq::from(7u64)]);
+    let mut plain = PallasHasher::new();
+
+    keyed.update(99u64);
+    plain.update(99u64);
+
+    assert_ne!(keyed.mac(), plain.digest());
+}
+
+#[test]
+fn test_verify_mac_accepts_correct_and_rejects_incorrect() {
+    let mut hasher = PallasHasher::with_key(&[ark_pallas::Fq::from(3u64)]);
+    hasher.update(1234u64);
+    let tag = hasher.mac();
+
+    assert!(hasher.verify_mac(tag));
+    assert!(!hasher.verify_mac(tag + ark_pallas::Fq::from(1u64)));
+}
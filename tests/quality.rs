@@ -0,0 +1,143 @@
+//! Avalanche / hash-quality regression tests for `PallasHasher`/`BN254Hasher`.
+//!
+//! Inspired by ahash's `hash_quality_test`: empirically checks the Strict
+//! Avalanche Criterion (SAC) — flipping any single input bit should flip
+//! each output bit with probability ≈0.5 — and a Bit-Independence
+//! Criterion (BIC) check that pairs of output-bit flips aren't correlated
+//! with each other. Neither property is exercised by the crate's
+//! correctness tests (fixed vectors, determinism), so this is purely a
+//! regression guard: a future parameter or permutation change that
+//! degrades mixing would pass every other test while failing these.
+
+use ark_ff::{BigInteger, PrimeField};
+use poseidon_hash::parameters::poseidon_quality_check;
+use poseidon_hash::{BN254Hasher, PallasHasher, PoseidonHasher};
+
+const NUM_SAMPLES: usize = 64;
+const NUM_INPUT_BITS: usize = 64;
+const SAC_TOLERANCE: f64 = 0.15;
+const BIC_TOLERANCE: f64 = 0.15;
+
+fn pallas_digest_bits(input: u64) -> Vec<bool> {
+    let mut hasher = PallasHasher::new();
+    hasher.update(ark_pallas::Fr::from(input));
+    hasher.digest().into_bigint().to_bits_le()
+}
+
+fn bn254_digest_bits(input: u64) -> Vec<bool> {
+    let mut hasher = BN254Hasher::new();
+    hasher.update(ark_bn254::Fr::from(input));
+    hasher.digest().into_bigint().to_bits_le()
+}
+
+/// A small, fast, non-cryptographic PRNG (xorshift64) — see also the
+/// identical helper in `tests/sidechannel.rs`; duplicated rather than
+/// shared since each file under `tests/` is an independent compilation
+/// unit with no shared `tests/common` module in this crate.
+struct Xorshift64(u64);
+
+impl Xorshift64 {
+    fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+}
+
+/// For `NUM_SAMPLES` random base inputs, flip each of the low
+/// `NUM_INPUT_BITS` input bits in turn and record, for every output bit,
+/// how often it flips (SAC) and, for a fixed-stride sample of output-bit
+/// pairs, how often they flip together (BIC — checking every pair would be
+/// quadratic in the output width for no real gain in sensitivity).
+fn check_avalanche_and_bic(digest: impl Fn(u64) -> Vec<bool>, name: &str) {
+    let mut rng = Xorshift64(0xD1B5_4A32_D192_ED03);
+    let out_bits = digest(0).len();
+    assert!(out_bits > 0, "{}: digest produced no output bits", name);
+
+    let mut single_flip_counts = vec![0u32; out_bits];
+    // BIC co-flip counts for the sampled pairs (j, partner_of(j)).
+    let mut co_flip_counts = vec![0u32; out_bits];
+    let partner_of = |j: usize| (j + 37) % out_bits;
+    let mut trials = 0u32;
+
+    for _ in 0..NUM_SAMPLES {
+        let base = rng.next_u64();
+        let base_bits = digest(base);
+        for i in 0..NUM_INPUT_BITS {
+            let flipped_bits = digest(base ^ (1u64 << i));
+            trials += 1;
+
+            let flips: Vec<bool> = (0..out_bits)
+                .map(|j| base_bits[j] != flipped_bits[j])
+                .collect();
+            for (j, &flipped) in flips.iter().enumerate() {
+                if flipped {
+                    single_flip_counts[j] += 1;
+                    if flips[partner_of(j)] {
+                        co_flip_counts[j] += 1;
+                    }
+                }
+            }
+        }
+    }
+
+    let mut max_sac_dev = 0.0f64;
+    for &count in &single_flip_counts {
+        let p = count as f64 / trials as f64;
+        max_sac_dev = max_sac_dev.max((p - 0.5).abs());
+    }
+    assert!(
+        max_sac_dev <= SAC_TOLERANCE,
+        "{}: SAC deviation {:.3} exceeds tolerance {:.3}",
+        name,
+        max_sac_dev,
+        SAC_TOLERANCE
+    );
+
+    let mut max_bic_dev = 0.0f64;
+    for j in 0..out_bits {
+        let k = partner_of(j);
+        let pj = single_flip_counts[j] as f64 / trials as f64;
+        let pk = single_flip_counts[k] as f64 / trials as f64;
+        let pjk = co_flip_counts[j] as f64 / trials as f64;
+        max_bic_dev = max_bic_dev.max((pjk - pj * pk).abs());
+    }
+    assert!(
+        max_bic_dev <= BIC_TOLERANCE,
+        "{}: BIC deviation {:.3} exceeds tolerance {:.3}",
+        name,
+        max_bic_dev,
+        BIC_TOLERANCE
+    );
+}
+
+#[test]
+fn pallas_hasher_passes_avalanche_and_bic_checks() {
+    check_avalanche_and_bic(pallas_digest_bits, "PallasHasher");
+}
+
+#[test]
+fn bn254_hasher_passes_avalanche_and_bic_checks() {
+    check_avalanche_and_bic(bn254_digest_bits, "BN254Hasher");
+}
+
+#[test]
+fn pallas_params_pass_poseidon_quality_check() {
+    let report = poseidon_quality_check(&poseidon_hash::parameters::pallas::PALLAS_PARAMS, 64);
+    assert!(
+        report.is_healthy(),
+        "Pallas params failed poseidon_quality_check: {:?}",
+        report
+    );
+}
+
+#[test]
+fn bn254_params_pass_poseidon_quality_check() {
+    let report = poseidon_quality_check(&poseidon_hash::parameters::bn254::BN254_PARAMS, 64);
+    assert!(
+        report.is_healthy(),
+        "BN254 params failed poseidon_quality_check: {:?}",
+        report
+    );
+}
@@ -59,39 +59,10 @@ fn test_field_conversion_overflow_protection() {
     hasher.update(FieldInput::ScalarField(large_scalar));
 }
 
-/// Basic timing consistency test for side-channel detection.
-#[test]
-#[ignore = "Timing-based; environment dependent"]
-fn test_basic_timing_consistency() {
-    use std::time::Instant;
-
-    let test_cases = vec![
-        ark_pallas::Fr::from(1u64),
-        ark_pallas::Fr::from(u64::MAX),
-        ark_pallas::Fr::from_le_bytes_mod_order(&[1u8; 32]),
-        ark_pallas::Fr::from_le_bytes_mod_order(&[255u8; 32]),
-    ];
-
-    let mut timings = Vec::new();
-
-    for test_case in test_cases {
-        let mut hasher = PallasHasher::new();
-
-        let start = Instant::now();
-        hasher.update(test_case);
-        let _hash = hasher.digest();
-        let duration = start.elapsed();
-
-        timings.push(duration);
-    }
-
-    let max_time = timings.iter().max().unwrap();
-    let min_time = timings.iter().min().unwrap();
-
-    let variance_ratio = max_time.as_nanos() as f64 / min_time.as_nanos() as f64;
-
-    assert!(variance_ratio < 10.0, "Extreme timing variance detected");
-}
+// The old `test_basic_timing_consistency` (a crude max/min timing ratio
+// over four inputs) was too noisy to be a meaningful constant-time check
+// and was always `#[ignore]`d. It has been replaced by the dudect-style
+// Welch's-t leakage harness in `tests/sidechannel.rs`.
 
 /// Validates hash determinism to ensure no undefined behavior.
 #[test]
@@ -20,27 +20,26 @@ fn test_timing_consistency_field_elements() {
         ark_pallas::Fr::from_le_bytes_mod_order(&[255u8; 32]),
         ark_pallas::Fr::from_le_bytes_mod_order(&[0xAA; 32]),
     ];
-    
-    let mut timings = Vec::new();
+
+    let mut timings = vec![Vec::new(); test_cases.len()];
     const NUM_ROUNDS: usize = 100;
-    
-    for test_case in &test_cases {
-        let mut round_timings = Vec::new();
-        
-        for _ in 0..NUM_ROUNDS {
+
+    // Interleave rounds across classes (rather than measuring one class fully
+    // before moving to the next) so environmental drift affects every class
+    // equally; see `analyze_timing_consistency`.
+    for _ in 0..NUM_ROUNDS {
+        for (i, test_case) in test_cases.iter().enumerate() {
             let mut hasher = PallasHasher::new();
-            
+
             let start = Instant::now();
             hasher.update(PallasInput::ScalarField(*test_case));
             let _hash = hasher.digest();
             let elapsed = start.elapsed();
-            
-            round_timings.push(elapsed);
+
+            timings[i].push(elapsed);
         }
-        
-        timings.push(round_timings);
     }
-    
+
     analyze_timing_consistency(&timings, "field_elements");
 }
 
@@ -50,27 +49,23 @@ fn test_timing_consistency_field_elements() {
 #[ignore = "Strict timing test - run with --ignored flag"]
 fn test_timing_consistency_input_sizes() {
     let input_sizes = vec![1, 10, 100, 1000, 10000];
-    let mut timings = Vec::new();
+    let test_data: Vec<Vec<u8>> = input_sizes.iter().map(|&size| vec![0x42u8; size]).collect();
+    let mut timings = vec![Vec::new(); test_data.len()];
     const NUM_ROUNDS: usize = 50;
-    
-    for &size in &input_sizes {
-        let mut round_timings = Vec::new();
-        let test_data = vec![0x42u8; size];
-        
-        for _ in 0..NUM_ROUNDS {
+
+    for _ in 0..NUM_ROUNDS {
+        for (i, data) in test_data.iter().enumerate() {
             let mut hasher = PallasHasher::new();
-            
+
             let start = Instant::now();
-            hasher.update(test_data.clone());
+            hasher.update(data.clone());
             let _hash = hasher.digest();
             let elapsed = start.elapsed();
-            
-            round_timings.push(elapsed);
+
+            timings[i].push(elapsed);
         }
-        
-        timings.push(round_timings);
     }
-    
+
     analyze_timing_consistency(&timings, "input_sizes");
 }
 
@@ -79,34 +74,30 @@ fn test_timing_consistency_input_sizes() {
 #[test]
 #[ignore = "Strict timing test - run with --ignored flag"]
 fn test_timing_consistency_data_patterns() {
-    let patterns = vec![
+    let patterns: Vec<Vec<u8>> = vec![
         vec![0u8; 1000],
         vec![0xFFu8; 1000],
         vec![0x55u8; 1000],
         vec![0xAAu8; 1000],
         (0..1000).map(|i| (i % 256) as u8).collect(),
     ];
-    
-    let mut timings = Vec::new();
+
+    let mut timings = vec![Vec::new(); patterns.len()];
     const NUM_ROUNDS: usize = 50;
-    
-    for pattern_data in &patterns {
-        let mut round_timings = Vec::new();
-        
-        for _ in 0..NUM_ROUNDS {
+
+    for _ in 0..NUM_ROUNDS {
+        for (i, pattern_data) in patterns.iter().enumerate() {
             let mut hasher = PallasHasher::new();
-            
+
             let start = Instant::now();
             hasher.update(pattern_data.clone());
             let _hash = hasher.digest();
             let elapsed = start.elapsed();
-            
-            round_timings.push(elapsed);
+
+            timings[i].push(elapsed);
         }
-        
-        timings.push(round_timings);
     }
-    
+
     analyze_timing_consistency(&timings, "data_patterns");
 }
 
@@ -124,27 +115,23 @@ fn test_field_conversion_timing() {
         ark_pallas::Fr::from_le_bytes_mod_order(&[255u8; 32]),
     ];
     
-    let mut timings = Vec::new();
+    let mut timings = vec![Vec::new(); test_scalars.len()];
     const NUM_ROUNDS: usize = 100;
-    
-    for test_scalar in &test_scalars {
-        let mut round_timings = Vec::new();
-        
-        for _ in 0..NUM_ROUNDS {
-            let mut hasher: MultiFieldHasher<ark_pallas::Fq, ark_pallas::Fr, ark_pallas::Affine> = 
+
+    for _ in 0..NUM_ROUNDS {
+        for (i, test_scalar) in test_scalars.iter().enumerate() {
+            let mut hasher: MultiFieldHasher<ark_pallas::Fq, ark_pallas::Fr, ark_pallas::Affine> =
                 MultiFieldHasher::new_from_ref(&*PALLAS_PARAMS);
-            
+
             let start = Instant::now();
             hasher.update(FieldInput::ScalarField(*test_scalar));
             let _hash = hasher.digest();
             let elapsed = start.elapsed();
-            
-            round_timings.push(elapsed);
+
+            timings[i].push(elapsed);
         }
-        
-        timings.push(round_timings);
     }
-    
+
     analyze_timing_consistency(&timings, "field_conversion");
 }
 
@@ -153,39 +140,29 @@ fn test_field_conversion_timing() {
 #[ignore = "Strict timing test - run with --ignored flag"]
 fn test_cross_curve_timing_consistency() {
     let test_data = vec![0x42u8; 1000];
-    let mut all_timings = Vec::new();
+    let mut all_timings = vec![Vec::new(); 3];
     const NUM_ROUNDS: usize = 50;
-    
-    let mut pallas_timings = Vec::new();
+
     for _ in 0..NUM_ROUNDS {
-        let mut hasher = PallasHasher::new();
+        let mut pallas_hasher = PallasHasher::new();
         let start = Instant::now();
-        hasher.update(test_data.clone());
-        let _hash = hasher.digest();
-        pallas_timings.push(start.elapsed());
-    }
-    all_timings.push(pallas_timings);
-    
-    let mut bn254_timings = Vec::new();
-    for _ in 0..NUM_ROUNDS {
-        let mut hasher = BN254Hasher::new();
+        pallas_hasher.update(test_data.clone());
+        let _hash = pallas_hasher.digest();
+        all_timings[0].push(start.elapsed());
+
+        let mut bn254_hasher = BN254Hasher::new();
         let start = Instant::now();
-        hasher.update(test_data.clone());
-        let _hash = hasher.digest();
-        bn254_timings.push(start.elapsed());
-    }
-    all_timings.push(bn254_timings);
-    
-    let mut bls381_timings = Vec::new();
-    for _ in 0..NUM_ROUNDS {
-        let mut hasher = BLS12_381Hasher::new();
+        bn254_hasher.update(test_data.clone());
+        let _hash = bn254_hasher.digest();
+        all_timings[1].push(start.elapsed());
+
+        let mut bls381_hasher = BLS12_381Hasher::new();
         let start = Instant::now();
-        hasher.update(test_data.clone());
-        let _hash = hasher.digest();
-        bls381_timings.push(start.elapsed());
+        bls381_hasher.update(test_data.clone());
+        let _hash = bls381_hasher.digest();
+        all_timings[2].push(start.elapsed());
     }
-    all_timings.push(bls381_timings);
-    
+
     analyze_timing_consistency(&all_timings, "cross_curve");
 }
 
@@ -277,26 +254,22 @@ fn test_memory_access_patterns() {
         create_sparse_pattern(1000),
     ];
     
-    let mut timings = Vec::new();
+    let mut timings = vec![Vec::new(); test_cases.len()];
     const NUM_ROUNDS: usize = 50;
-    
-    for test_data in &test_cases {
-        let mut round_timings = Vec::new();
-        
-        for _ in 0..NUM_ROUNDS {
+
+    for _ in 0..NUM_ROUNDS {
+        for (i, test_data) in test_cases.iter().enumerate() {
             let mut hasher = PallasHasher::new();
-            
+
             let start = Instant::now();
             hasher.update(test_data.clone());
             let _hash = hasher.digest();
             let elapsed = start.elapsed();
-            
-            round_timings.push(elapsed);
+
+            timings[i].push(elapsed);
         }
-        
-        timings.push(round_timings);
     }
-    
+
     analyze_timing_consistency(&timings, "memory_access_patterns");
 }
 
@@ -311,54 +284,153 @@ fn average_duration(durations: &[Duration]) -> Duration {
     Duration::from_nanos((total_nanos / durations.len() as u128) as u64)
 }
 
-fn standard_deviation_duration(durations: &[Duration]) -> f64 {
-    if durations.len() < 2 {
+/// Streaming (count, mean, M2) accumulator for one timing class, updated
+/// sample-by-sample via Welford's algorithm so classes of unbounded size
+/// never need to be materialized twice.
+#[derive(Default, Clone, Copy)]
+struct WelfordStats {
+    count: u64,
+    mean: f64,
+    m2: f64,
+}
+
+impl WelfordStats {
+    fn update(&mut self, x: f64) {
+        self.count += 1;
+        let delta = x - self.mean;
+        self.mean += delta / self.count as f64;
+        let delta2 = x - self.mean;
+        self.m2 += delta * delta2;
+    }
+
+    fn variance(&self) -> f64 {
+        if self.count < 2 {
+            0.0
+        } else {
+            self.m2 / (self.count - 1) as f64
+        }
+    }
+}
+
+/// Welch's two-sample t-statistic: `(mean_a - mean_b) / sqrt(var_a/n_a + var_b/n_b)`.
+fn welch_t(a: &WelfordStats, b: &WelfordStats) -> f64 {
+    let denom = (a.variance() / a.count as f64 + b.variance() / b.count as f64).sqrt();
+    if denom == 0.0 {
+        0.0
+    } else {
+        (a.mean - b.mean) / denom
+    }
+}
+
+/// The conventional dudect leakage threshold: a |t| beyond this is taken as
+/// evidence of a timing difference between classes, rather than noise.
+const LEAK_THRESHOLD: f64 = 4.5;
+
+/// Crop samples above each of these percentiles before computing the
+/// t-statistic, and keep the worst (largest |t|) across crops — heavy-tailed
+/// outliers (scheduler preemption, page faults) can otherwise wash out a
+/// real leak at the uncropped percentile, as dudect also accounts for.
+const CROP_PERCENTILES: [f64; 10] = [0.10, 0.20, 0.30, 0.40, 0.50, 0.60, 0.70, 0.80, 0.90, 1.00];
+
+/// A dudect-style statistical leakage detector comparing two interleaved
+/// measurement classes (conventionally a *fixed* input held constant across
+/// measurements, and a *random* input varying on each measurement). Samples
+/// should be recorded in the same interleaved order they were measured in,
+/// so environmental drift (thermal throttling, scheduler noise) affects both
+/// classes equally instead of biasing whichever class was measured later.
+struct LeakageTest {
+    fixed: Vec<f64>,
+    random: Vec<f64>,
+}
+
+impl LeakageTest {
+    fn new() -> Self {
+        Self {
+            fixed: Vec::new(),
+            random: Vec::new(),
+        }
+    }
+
+    fn record_fixed(&mut self, sample: Duration) {
+        self.fixed.push(sample.as_nanos() as f64);
+    }
+
+    fn record_random(&mut self, sample: Duration) {
+        self.random.push(sample.as_nanos() as f64);
+    }
+
+    /// The worst-case (largest) |t| across every crop threshold in
+    /// [`CROP_PERCENTILES`]. Values above [`LEAK_THRESHOLD`] indicate a
+    /// statistically significant timing difference between the two classes.
+    fn max_abs_t(&self) -> f64 {
+        let mut all_sorted: Vec<f64> = self.fixed.iter().chain(self.random.iter()).copied().collect();
+        all_sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+        let mut max_abs_t = 0.0f64;
+        for &p in &CROP_PERCENTILES {
+            let cutoff = percentile(&all_sorted, p);
+            let fixed_stats = stats_below_cutoff(&self.fixed, cutoff);
+            let random_stats = stats_below_cutoff(&self.random, cutoff);
+            if fixed_stats.count < 2 || random_stats.count < 2 {
+                continue;
+            }
+            max_abs_t = max_abs_t.max(welch_t(&fixed_stats, &random_stats).abs());
+        }
+        max_abs_t
+    }
+
+    /// Asserts [`Self::max_abs_t`] stays within [`LEAK_THRESHOLD`].
+    fn assert_no_leak(&self, test_name: &str) {
+        let max_abs_t = self.max_abs_t();
+        assert!(
+            max_abs_t <= LEAK_THRESHOLD,
+            "possible timing side channel in {}: max |t| = {:.2} (threshold {:.1})",
+            test_name,
+            max_abs_t,
+            LEAK_THRESHOLD
+        );
+    }
+}
+
+/// Fold every sample `<= cutoff_nanos` into a fresh [`WelfordStats`].
+fn stats_below_cutoff(samples: &[f64], cutoff_nanos: f64) -> WelfordStats {
+    let mut stats = WelfordStats::default();
+    for &s in samples.iter().filter(|&&s| s <= cutoff_nanos) {
+        stats.update(s);
+    }
+    stats
+}
+
+/// The `p`-th percentile (`0.0..=1.0`) of `sorted_samples`.
+fn percentile(sorted_samples: &[f64], p: f64) -> f64 {
+    if sorted_samples.is_empty() {
         return 0.0;
     }
-    
-    let avg = average_duration(durations);
-    let avg_nanos = avg.as_nanos() as f64;
-    
-    let variance: f64 = durations
-        .iter()
-        .map(|d| {
-            let diff = d.as_nanos() as f64 - avg_nanos;
-            diff * diff
-        })
-        .sum::<f64>() / (durations.len() - 1) as f64;
-    
-    variance.sqrt()
+    let idx = (((sorted_samples.len() - 1) as f64) * p).round() as usize;
+    sorted_samples[idx]
 }
 
+/// Dudect-style leakage verdict across N interleaved measurement classes.
+///
+/// The tests in this module don't always measure a strict binary
+/// fixed-vs-random pair (e.g. `cross_curve` compares three curves), so this
+/// treats `all_timings[0]` as the "fixed" baseline class and every other
+/// class as a "random" comparison class against it, taking the worst |t|
+/// across all such pairs and all [`CROP_PERCENTILES`] crop thresholds. This
+/// replaces the previous coefficient-of-variation/min-max-ratio heuristic,
+/// which was both too weak to catch a real leak and too noisy to avoid false
+/// positives, with [`LeakageTest`]'s statistically grounded Welch's t-test.
 fn analyze_timing_consistency(all_timings: &[Vec<Duration>], test_name: &str) {
-    let mut max_coefficient_of_variation = 0.0f64;
-    
-    for timings in all_timings.iter() {
-        let avg = average_duration(timings);
-        let std_dev = standard_deviation_duration(timings);
-        
-        let cv = if avg.as_nanos() > 0 {
-            std_dev / avg.as_nanos() as f64
-        } else {
-            0.0
-        };
-        
-        max_coefficient_of_variation = max_coefficient_of_variation.max(cv);
-    }
-    
-    if all_timings.len() > 1 {
-        let avg_times: Vec<Duration> = all_timings.iter().map(|t| average_duration(t)).collect();
-        let min_avg = avg_times.iter().min();
-        let max_avg = avg_times.iter().max();
-        
-        let ratio = max_avg.unwrap().as_nanos() as f64 / min_avg.unwrap().as_nanos() as f64;
-        
-        assert!(ratio < 5.0, 
-                "High timing variance in {}: {:.2}x difference", test_name, ratio);
+    for other in all_timings.iter().skip(1) {
+        let mut test = LeakageTest::new();
+        for &sample in &all_timings[0] {
+            test.record_fixed(sample);
+        }
+        for &sample in other {
+            test.record_random(sample);
+        }
+        test.assert_no_leak(test_name);
     }
-    
-    assert!(max_coefficient_of_variation < 0.5, 
-            "High timing variability in {}: CV={:.3}", test_name, max_coefficient_of_variation);
 }
 
 fn create_sparse_pattern(size: usize) -> Vec<u8> {
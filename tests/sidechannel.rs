@@ -0,0 +1,152 @@
+//! Statistical constant-time verification (dudect-style) for
+//! `PallasHasher::update`/`digest`.
+//!
+//! Collects timings for two interleaved input classes — a fixed constant
+//! input and uniformly random inputs — discards a warmup prefix, and
+//! computes the Welch two-sample t-statistic across several upper-
+//! percentile cutoffs (cropping tail outliers, as dudect does), taking the
+//! worst (largest |t|) across cutoffs. A |t| above the threshold after a
+//! minimum sample count indicates a timing difference between the two
+//! classes large enough to be a potential side channel (e.g. from
+//! data-dependent branching in `PackingBuffer`), rather than noise.
+//!
+//! Replaces the old `test_basic_timing_consistency` in
+//! `tests/security_tests.rs`, which compared only the max/min of four
+//! single-shot timings and was too noisy to assert on reliably.
+
+use poseidon_hash::{PallasHasher, PoseidonHasher};
+use std::time::Instant;
+
+const WARMUP_ITERS: usize = 1_000;
+const MEASURED_ITERS: usize = 20_000;
+const MIN_SAMPLES: u64 = 2_000;
+const LEAK_THRESHOLD: f64 = 4.5;
+// Crop tail outliers at each of these upper percentiles before computing
+// the t-statistic, and take the worst case; dudect does the same since a
+// leak can be washed out by noise at the full (uncropped) percentile.
+const PERCENTILE_CUTOFFS: [f64; 3] = [1.0, 0.95, 0.90];
+
+/// Online (count, mean, M2) accumulator for one timing class, updated
+/// sample-by-sample via Welford's algorithm.
+#[derive(Default, Clone, Copy)]
+struct WelchStats {
+    count: u64,
+    mean: f64,
+    m2: f64,
+}
+
+impl WelchStats {
+    fn update(&mut self, x: f64) {
+        self.count += 1;
+        let delta = x - self.mean;
+        self.mean += delta / self.count as f64;
+        let delta2 = x - self.mean;
+        self.m2 += delta * delta2;
+    }
+
+    fn variance(&self) -> f64 {
+        if self.count < 2 {
+            0.0
+        } else {
+            self.m2 / (self.count - 1) as f64
+        }
+    }
+}
+
+/// Welch's two-sample t-statistic: `(μ₁−μ₂)/sqrt(s₁²/n₁ + s₂²/n₂)`.
+fn welch_t(a: &WelchStats, b: &WelchStats) -> f64 {
+    let denom = (a.variance() / a.count as f64 + b.variance() / b.count as f64).sqrt();
+    if denom == 0.0 {
+        0.0
+    } else {
+        (a.mean - b.mean) / denom
+    }
+}
+
+/// Fold every sample `<= cutoff_nanos` into a fresh [`WelchStats`].
+fn stats_below_cutoff(samples: &[f64], cutoff_nanos: f64) -> WelchStats {
+    let mut stats = WelchStats::default();
+    for &s in samples.iter().filter(|&&s| s <= cutoff_nanos) {
+        stats.update(s);
+    }
+    stats
+}
+
+/// The `p`-th percentile (`0.0..=1.0`) of `sorted_samples`.
+fn percentile(sorted_samples: &[f64], p: f64) -> f64 {
+    if sorted_samples.is_empty() {
+        return 0.0;
+    }
+    let idx = (((sorted_samples.len() - 1) as f64) * p).round() as usize;
+    sorted_samples[idx]
+}
+
+/// One timing measurement of `PallasHasher::update`/`digest` over `input`,
+/// in nanoseconds.
+fn time_once(input: ark_pallas::Fr) -> f64 {
+    let mut hasher = PallasHasher::new();
+    let start = Instant::now();
+    hasher.update(input);
+    let _ = hasher.digest();
+    start.elapsed().as_nanos() as f64
+}
+
+/// A small, fast, non-cryptographic PRNG (xorshift64) — plenty for
+/// generating non-adversarial random-class inputs without pulling in a
+/// `rand` dependency just for this test.
+struct Xorshift64(u64);
+
+impl Xorshift64 {
+    fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+}
+
+#[test]
+#[ignore = "Timing-based; environment dependent — run explicitly with --ignored"]
+fn pallas_hasher_update_digest_is_constant_time() {
+    let fixed_input = ark_pallas::Fr::from(0x4242_4242_4242_4242u64);
+    let mut rng = Xorshift64(0x9E3779B97F4A7C15);
+
+    // Warm up (JIT/caches/branch predictor) without recording timings.
+    for _ in 0..WARMUP_ITERS {
+        let _ = time_once(fixed_input);
+        let _ = time_once(ark_pallas::Fr::from(rng.next_u64()));
+    }
+
+    // Interleave the two classes per iteration to cancel clock drift.
+    let mut fixed_samples = Vec::with_capacity(MEASURED_ITERS);
+    let mut random_samples = Vec::with_capacity(MEASURED_ITERS);
+    for _ in 0..MEASURED_ITERS {
+        fixed_samples.push(time_once(fixed_input));
+        random_samples.push(time_once(ark_pallas::Fr::from(rng.next_u64())));
+    }
+
+    let mut all_sorted: Vec<f64> = fixed_samples
+        .iter()
+        .chain(random_samples.iter())
+        .copied()
+        .collect();
+    all_sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+    let mut max_abs_t = 0.0f64;
+    for &p in &PERCENTILE_CUTOFFS {
+        let cutoff = percentile(&all_sorted, p);
+        let fixed_stats = stats_below_cutoff(&fixed_samples, cutoff);
+        let random_stats = stats_below_cutoff(&random_samples, cutoff);
+        if fixed_stats.count < MIN_SAMPLES || random_stats.count < MIN_SAMPLES {
+            continue;
+        }
+        max_abs_t = max_abs_t.max(welch_t(&fixed_stats, &random_stats).abs());
+    }
+
+    assert!(
+        max_abs_t <= LEAK_THRESHOLD,
+        "possible timing side channel in PallasHasher::update/digest: max |t| = {:.2} (threshold {:.1})",
+        max_abs_t,
+        LEAK_THRESHOLD
+    );
+}
@@ -0,0 +1,87 @@
+//! Tests for the curve-hasher `squeeze`/`squeeze_into`/`squeeze_bytes` XOF API.
+
+use poseidon_hash::{BN254Hasher, PallasHasher, PoseidonHasher};
+
+#[test]
+fn test_squeeze_returns_requested_element_count() {
+    let mut hasher = PallasHasher::new();
+    hasher.update(42u64);
+    let out = hasher.squeeze(3);
+    assert_eq!(out.len(), 3);
+}
+
+#[test]
+fn test_squeeze_is_deterministic() {
+    let mut a = PallasHasher::new();
+    let mut b = PallasHasher::new();
+    a.update(7u64);
+    b.update(7u64);
+
+    assert_eq!(a.squeeze(4), b.squeeze(4));
+}
+
+#[test]
+fn test_squeeze_n_then_m_differs_from_squeeze_n_plus_m() {
+    let mut split = PallasHasher::new();
+    split.update(7u64);
+    let mut combined = split.squeeze(2);
+    combined.extend(split.squeeze(3));
+
+    let mut single = PallasHasher::new();
+    single.update(7u64);
+    let one_shot = single.squeeze(5);
+
+    assert_ne!(combined, one_shot);
+}
+
+#[test]
+fn test_squeeze_into_matches_squeeze() {
+    let mut a = PallasHasher::new();
+    let mut b = PallasHasher::new();
+    a.update(99u64);
+    b.update(99u64);
+
+    let via_vec = a.squeeze(3);
+    let mut via_slice = vec![ark_pallas::Fq::from(0u64); 3];
+    b.squeeze_into(&mut via_slice);
+
+    assert_eq!(via_vec, via_slice);
+}
+
+#[test]
+fn test_squeeze_bytes_respects_requested_length() {
+    let mut hasher = BN254Hasher::new();
+    hasher.update("xof");
+    let bytes = hasher.squeeze_bytes(50);
+    assert_eq!(bytes.len(), 50);
+}
+
+#[test]
+fn test_trait_squeeze_one_matches_digest() {
+    // `PoseidonHasher::squeeze` is a default trait method, not just the
+    // inherent `$Hasher::squeeze`; confirm the two agree at n=1 with digest.
+    let mut a = PallasHasher::new();
+    a.update(5u64);
+    let digest = a.digest();
+
+    let mut b = PallasHasher::new();
+    b.update(5u64);
+    let squeezed = PoseidonHasher::squeeze(&mut b, 1);
+
+    assert_eq!(squeezed, vec![digest]);
+}
+
+#[test]
+fn test_trait_squeeze_works_on_hashers_without_an_inherent_squeeze() {
+    // `PallasPoseidon2Hasher` has no inherent `squeeze` method (unlike the
+    // `define_curve_hasher!`-generated types above); the trait default
+    // method must still work for it.
+    use poseidon_hash::types::poseidon2::PallasPoseidon2Hasher;
+
+    let mut a = PallasPoseidon2Hasher::new();
+    let mut b = PallasPoseidon2Hasher::new();
+    a.update(ark_pallas::Fq::from(11u64));
+    b.update(ark_pallas::Fq::from(11u64));
+
+    assert_eq!(a.squeeze(3), b.squeeze(3));
+}
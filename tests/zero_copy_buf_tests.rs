@@ -0,0 +1,56 @@
+//! Tests for zero-copy `bytes::Buf`/`bytes::Bytes` ingestion.
+
+use bytes::Bytes;
+use poseidon_hash::primitive::{PackingBuffer, PackingConfig};
+
+#[test]
+fn test_push_buf_matches_push_bytes_byte_efficient() {
+    let data: Vec<u8> = (0..97u8).collect();
+
+    let mut via_buf = PackingBuffer::new::<ark_pallas::Fq>(PackingConfig::default());
+    let direct: Vec<ark_pallas::Fq> = via_buf.push_buf(Bytes::from(data.clone()));
+    let mut tail = via_buf.flush_remaining::<ark_pallas::Fq>();
+    let mut via_buf_elements = direct;
+    via_buf_elements.append(&mut tail);
+
+    let mut via_bytes = PackingBuffer::new::<ark_pallas::Fq>(PackingConfig::default());
+    via_bytes.push_bytes(&data);
+    let mut via_bytes_elements = via_bytes.extract_field_elements::<ark_pallas::Fq>();
+    via_bytes_elements.append(&mut via_bytes.flush_remaining::<ark_pallas::Fq>());
+
+    assert_eq!(via_buf_elements, via_bytes_elements);
+}
+
+#[test]
+fn test_push_buf_circuit_friendly_one_element_per_byte() {
+    let config = PackingConfig {
+        mode: poseidon_hash::primitive::PackingMode::CircuitFriendly,
+        ..Default::default()
+    };
+    let mut buffer = PackingBuffer::new::<ark_pallas::Fq>(config);
+    let elements: Vec<ark_pallas::Fq> = buffer.push_buf(Bytes::from_static(&[1, 2, 3]));
+
+    assert_eq!(elements.len(), 3);
+    assert_eq!(elements[0], ark_pallas::Fq::from(1u64));
+    assert_eq!(elements[2], ark_pallas::Fq::from(3u64));
+}
+
+#[test]
+fn test_bytes_into_primitive_input_matches_vec_u8() {
+    use poseidon_hash::primitive::PrimitiveInput;
+
+    let from_vec: PrimitiveInput = vec![1u8, 2, 3].into();
+    let from_bytes: PrimitiveInput = Bytes::from_static(&[1, 2, 3]).into();
+
+    assert_eq!(from_vec.tag, from_bytes.tag);
+    assert_eq!(from_vec.bytes, from_bytes.bytes);
+}
+
+#[test]
+fn test_push_buf_leaves_short_tail_queued() {
+    let mut buffer = PackingBuffer::new::<ark_pallas::Fq>(PackingConfig::default());
+
+    let elements: Vec<ark_pallas::Fq> = buffer.push_buf(Bytes::from_static(b"ab"));
+    assert!(elements.is_empty());
+    assert_eq!(buffer.len(), 2);
+}